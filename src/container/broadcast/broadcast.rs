@@ -14,7 +14,7 @@ use uuid::Uuid;
 use wasm_bindgen::JsValue;
 use crate::container::broadcast::store::{MonitorChange, MonitorSchema, Store};
 use crate::kernel::plc::operations::unit::test::{UnitTest, UnitTestStatus, UnitTestUpdateStatus};
-use crate::kernel::registry::Kernel;
+use crate::kernel::registry::{get_string, Kernel};
 
 pub struct Broadcast {
     #[cfg(target_arch = "wasm32")]
@@ -35,6 +35,8 @@ pub struct Broadcast {
     breakpoints: Rc<RefCell<HashSet<u32>>>,
 
     stack: Rc<RefCell<Stack>>,
+
+    cycle: Rc<RefCell<u64>>,
 }
 
 
@@ -51,6 +53,8 @@ impl Broadcast {
             breakpoints: Rc::new(RefCell::new(HashSet::new())),
 
             stack: Rc::new(RefCell::new(Stack::new())),
+
+            cycle: Rc::new(RefCell::new(0)),
         }
     }
 
@@ -75,6 +79,8 @@ impl Broadcast {
             breakpoints: Rc::new(RefCell::new(HashSet::new())),
             
             stack: Rc::new(RefCell::new(Stack::new())),
+
+            cycle: Rc::new(RefCell::new(0)),
         }
     }
     #[cfg(target_arch = "wasm32")]
@@ -116,19 +122,17 @@ impl Broadcast {
 
     pub fn add_message(&self, message: &str) {
         self.store.borrow_mut().add_message(message);
-        println!("{} \n", message);
+        eprintln!("{} \n", message);
     }
 
     pub fn add_warning(&self, warning: &str) {
         self.store.borrow_mut().add_warning(warning);
-        println!("WARNING: {} \n", warning);
+        eprintln!("WARNING: {} \n", warning);
     }
 
     pub fn add_error(&self, error: &Stop) {
         self.store.borrow_mut().add_error(error);
-        println!("ERROR: {} \n", error);
-        #[cfg(not(target_arch = "wasm32"))]
-        panic!("{}", error)
+        eprintln!("ERROR: {} \n", error);
     }
 
     /*pub fn add_breakpoint_status(&self, breakpoint: &BreakPointUpdateStatus) {
@@ -198,7 +202,16 @@ impl Broadcast {
 
     pub fn add_unit_test_status(&self, status: &UnitTestUpdateStatus) {
         self.store.borrow_mut().add_unit_test_status(status);
-        self.unit_tests.borrow_mut().get_mut(&status.get_id()).unwrap().set_status(status.get_status());
+        let block = self
+            .stack
+            .borrow_mut()
+            .get_current_section()
+            .map(|section| get_string(section.borrow().get_name()));
+        self.unit_tests
+            .borrow_mut()
+            .get_mut(&status.get_id())
+            .unwrap()
+            .update(status, self.get_cycle(), block);
     }
 
     pub fn clear_unit_tests(&self) {
@@ -206,7 +219,7 @@ impl Broadcast {
     }
 
     pub fn reset_unit_tests(&self) {
-        self.unit_tests.borrow_mut().iter_mut().for_each(|a| a.1.set_status(UnitTestStatus::Unreached))
+        self.unit_tests.borrow_mut().iter_mut().for_each(|a| a.1.reset())
     }
 
     pub fn clear_breakpoints(&self) {
@@ -228,4 +241,22 @@ impl Broadcast {
     pub fn reset_cycle_stack(&self) {
         self.stack.borrow_mut().clear();
     }
+
+    /// Starts a new cycle and returns its number
+    pub fn next_cycle(&self) -> u64 {
+        *self.cycle.borrow_mut().deref_mut() += 1;
+        self.get_cycle()
+    }
+
+    pub fn get_cycle(&self) -> u64 {
+        *self.cycle.borrow().deref()
+    }
+
+    pub fn set_cycle(&self, cycle: u64) {
+        *self.cycle.borrow_mut().deref_mut() = cycle;
+    }
+
+    pub fn reset_cycle(&self) {
+        *self.cycle.borrow_mut().deref_mut() = 0;
+    }
 }
@@ -1,4 +1,5 @@
 ﻿pub mod broadcast;
 pub mod macros;
 pub mod stack;
-pub mod store;
\ No newline at end of file
+pub mod store;
+pub mod report;
\ No newline at end of file
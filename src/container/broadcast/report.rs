@@ -0,0 +1,135 @@
+use crate::kernel::plc::operations::unit::test::{UnitTest, UnitTestStatus};
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::wasm_bindgen;
+
+#[derive(Deserialize, Serialize, Clone, Copy)]
+#[wasm_bindgen]
+pub enum ReportFormat {
+    Junit,
+    Tap,
+}
+
+/// Serializes the unit tests of a simulation in the given format.
+pub fn build_report(format: ReportFormat, suite: &str, tests: &[UnitTest]) -> String {
+    let mut tests = tests.to_vec();
+    tests.sort_by_key(|t| t.get_id());
+    match format {
+        ReportFormat::Junit => junit_report(suite, &tests),
+        ReportFormat::Tap => tap_report(&tests),
+    }
+}
+
+fn fail_message(test: &UnitTest) -> String {
+    match test.get_status() {
+        UnitTestStatus::Unreached => "Unit test was never reached".into(),
+        _ => test.get_fail_message().unwrap_or_else(|| "Unit test failed".into()),
+    }
+}
+
+/// Escapes the XML special characters, drops ANSI colour sequences and replaces the other
+/// characters XML 1.0 forbids, such as the C0 controls, with U+FFFD.
+fn escape_xml(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '&' => escaped += "&amp;",
+            '<' => escaped += "&lt;",
+            '>' => escaped += "&gt;",
+            '"' => escaped += "&quot;",
+            '\'' => escaped += "&apos;",
+            // CSI sequence, e.g. `\x1b[31m`, ends with a byte in @..~
+            '\u{1b}' if chars.clone().next() == Some('[') => {
+                chars.by_ref().skip(1).find(|c| ('@'..='~').contains(c));
+            }
+            '\t' | '\n' | '\r' => escaped.push(c),
+            c if c < ' ' || c == '\u{FFFE}' || c == '\u{FFFF}' => escaped.push(char::REPLACEMENT_CHARACTER),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Double-quoted YAML scalar, control characters are escaped.
+fn quote_yaml(value: &str) -> String {
+    let mut quoted = String::from('"');
+    value.chars().for_each(|c| match c {
+        '"' => quoted += "\\\"",
+        '\\' => quoted += "\\\\",
+        '\n' => quoted += "\\n",
+        '\r' => quoted += "\\r",
+        '\t' => quoted += "\\t",
+        c if c.is_control() || c == '\u{2028}' || c == '\u{2029}' => quoted += &format!("\\u{:04X}", c as u32),
+        c => quoted.push(c),
+    });
+    quoted.push('"');
+    quoted
+}
+
+fn junit_report(suite: &str, tests: &[UnitTest]) -> String {
+    // An unreached test fails, as in TAP and for the exit code of the CLI
+    let failures = tests
+        .iter()
+        .filter(|t| !matches!(t.get_status(), UnitTestStatus::Succeed))
+        .count();
+
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    xml += &format!(
+        "<testsuites>\n  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\">\n",
+        escape_xml(suite),
+        tests.len(),
+        failures
+    );
+
+    tests.iter().for_each(|t| {
+        xml += &format!(
+            "    <testcase name=\"{}\" classname=\"{}\">\n",
+            escape_xml(&t.get_description()),
+            escape_xml(&t.get_block().unwrap_or_else(|| suite.to_string()))
+        );
+        xml += "      <properties>\n";
+        xml += &format!("        <property name=\"id\" value=\"{}\"/>\n", t.get_id());
+        if let Some(cycle) = t.get_cycle() {
+            xml += &format!("        <property name=\"cycle\" value=\"{}\"/>\n", cycle);
+        }
+        xml += "      </properties>\n";
+        match t.get_status() {
+            UnitTestStatus::Succeed => {}
+            _ => xml += &format!(
+                "      <failure message=\"{}\"/>\n",
+                escape_xml(&fail_message(t))
+            ),
+        }
+        xml += "    </testcase>\n";
+    });
+
+    xml += "  </testsuite>\n</testsuites>\n";
+    xml
+}
+
+fn tap_report(tests: &[UnitTest]) -> String {
+    let mut tap = format!("TAP version 13\n1..{}\n", tests.len());
+
+    tests.iter().enumerate().for_each(|(i, t)| {
+        // '#' starts a directive in TAP
+        let description = t.get_description().replace('#', "\\#");
+        match t.get_status() {
+            UnitTestStatus::Succeed => tap += &format!("ok {} - {}\n", i + 1, description),
+            _ => tap += &format!("not ok {} - {}\n", i + 1, description),
+        }
+        tap += "  ---\n";
+        tap += &format!("  id: {}\n", t.get_id());
+        if let Some(block) = t.get_block() {
+            tap += &format!("  block: {}\n", quote_yaml(&block));
+        }
+        if let Some(cycle) = t.get_cycle() {
+            tap += &format!("  cycle: {}\n", cycle);
+        }
+        if !matches!(t.get_status(), UnitTestStatus::Succeed) {
+            tap += &format!("  message: {}\n", quote_yaml(&fail_message(t)));
+        }
+        tap += "  ...\n";
+    });
+
+    tap
+}
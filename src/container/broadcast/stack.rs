@@ -77,6 +77,10 @@ impl Section {
         }
     }
 
+    pub fn get_name(&self) -> usize {
+        self.name
+    }
+
     pub fn insert_log(&mut self, log: &str) {
         //println!("INSERT Log -> '{}' to {}", &log, self.name);
         self.content.0.push(SectionOrLog::Log(log.into()));
@@ -177,12 +177,21 @@ impl Monitoring {
 impl Store {
     pub fn build_monitor(&mut self, kernel: &Kernel) {
         #[cfg(target_arch = "wasm32")]
-        kernel.monitor_raw_pointers
-            .borrow()
-            .iter()
-            .for_each(|(id , ptr)| {
-                self.monitoring.push(Monitoring { id: *id, value: unsafe { (**ptr).get_value() } } );
-            });
+        {
+            let enums = kernel.monitor_enums.borrow();
+            kernel.monitor_raw_pointers
+                .borrow()
+                .iter()
+                .for_each(|(id , ptr)| {
+                    let value = unsafe { (**ptr).get_value() };
+                    // Enumerated variables are shown by their symbol
+                    let value = match (enums.get(id), value.as_f64()) {
+                        (Some(plc_enum), Some(a)) => plc_enum.name_of(a as i64).map(JsValue::from_str).unwrap_or(value),
+                        _ => value,
+                    };
+                    self.monitoring.push(Monitoring { id: *id, value } );
+                });
+        }
     }
     
     pub fn move_and_reset(&mut self) -> Store {
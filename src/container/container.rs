@@ -6,10 +6,8 @@ use crate::container::simulation::simulation::Simulation;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use core::fmt::{Display, Formatter};
-use core::sync::atomic::{AtomicUsize, Ordering};
-use std::sync::{Arc, Mutex};
+use std::rc::Rc;
 
-use once_cell::sync::Lazy;
 use wasm_bindgen::prelude::wasm_bindgen;
 
 use ansi_term::Colour::{Blue, Green, Purple, Yellow, Red};
@@ -29,39 +27,21 @@ use wasm_bindgen::JsValue;
 use crate::js::typed_array::shiftLeft;
 use crate::{error, key_reader};
 use crate::container::error::error::Stop;
-use crate::parser::main::exclude::{parse_type_aliases, parse_return_operations, parse_exclude_sections, parse_exclude_types, parse_filter_operations};
+use crate::parser::main::exclude::{parse_enums, parse_type_aliases, parse_return_operations, parse_exclude_sections, parse_exclude_types, parse_filter_operations, parse_overflow, parse_float_exceptions};
+use crate::kernel::plc::operations::unit::test::UnitTest;
+use crate::container::broadcast::report::{build_report, ReportFormat};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::kernel::rust::float_exception::FloatException;
+use crate::container::state::{with_state, ContainerState};
+use crate::container::simulation::force::build_write;
+use crate::kernel::arch::snapshot::snapshot::{restore_snapshot, take_retained_snapshot, take_snapshot};
+use crate::kernel::registry::convert_string_path_to_usize;
+use crate::kernel::plc::operations::operations::RuntimeOperationTrait;
+use crate::kernel::plc::types::primitives::date::plc_date::{parse_date_time, DT_EPOCH, LDT_RANGE};
 use crate::container::simulation::pause::{enableBreakpoint, pause_simulation, disableBreakpoint};
 
-pub static DELAYED_TIMERS: Lazy<Arc<Mutex<HashMap<u32, Duration>>>> =
-    Lazy::new(|| Arc::new(Mutex::new(HashMap::new())));
-
-pub static COUNTER: AtomicUsize = AtomicUsize::new(1);
-pub static BACKUP_COUNTER: AtomicUsize = AtomicUsize::new(1);
-
 pub fn get_id() -> usize {
-    COUNTER.fetch_add(1, Ordering::Relaxed)
-}
-
-pub fn reset_counter_to_backup() {
-    COUNTER.store(BACKUP_COUNTER.load(Ordering::Relaxed), Ordering::Relaxed);
-}
-
-pub fn reset_counter_to_default() {
-    COUNTER.store(1_usize, Ordering::Relaxed);
-}
-
-pub fn set_counter_backup() {
-    BACKUP_COUNTER.store(COUNTER.load(Ordering::Relaxed), Ordering::Relaxed)
-}
-
-pub static IS_RUNNING: Lazy<Arc<Mutex<bool>>> = Lazy::new(|| Arc::new(Mutex::new(false)));
-
-pub static CONTAINER_PARAMS: Lazy<Arc<Mutex<ContainerParams>>> = Lazy::new(|| Arc::new(Mutex::new(ContainerParams::default())));
-
-
-#[wasm_bindgen]
-pub fn is_running() -> bool {
-    *IS_RUNNING.lock().unwrap()
+    with_state(|state| state.next_id())
 }
 
 
@@ -70,6 +50,8 @@ pub struct Container {
     registry: Kernel,
     channel: Broadcast,
     id: Uuid,
+    state: Rc<ContainerState>,
+    params: ContainerParams,
     #[cfg(target_arch = "wasm32")]
     pause_int32: js_sys::Int32Array,
     #[cfg(target_arch = "wasm32")]
@@ -100,6 +82,8 @@ impl Container {
             registry: Kernel::default(),
             channel: Broadcast::new(&id),
             id,
+            state: Rc::new(ContainerState::default()),
+            params: params.unwrap_or_default(),
         }
     }
 
@@ -110,31 +94,23 @@ impl Container {
             registry: Kernel::default(),
             channel: Broadcast::new(&id, &pause_sab, &command_lock_sab),
             id,
+            state: Rc::new(ContainerState::default()),
+            params: params.unwrap_or_default(),
             pause_int32: js_sys::Int32Array::new(&pause_sab),
             command_lock_int32: js_sys::Int32Array::new(&command_lock_sab),
             runtime_commands_sab: None,
         }
     }
-}
 
-pub const FOUR_MS: Duration = Duration::from_millis(4);
-pub const FIVE_MS: Duration = Duration::from_millis(5);
-pub const THOUSAND_MS: Duration = Duration::from_millis(100);
-
-#[wasm_bindgen]
-impl Container {
-    pub fn get_id(&self) -> String {
-        self.id.to_string()
-    }
-
-    pub fn load_server_params(&mut self, json: &str) {
+    fn try_load_server_params(&mut self, json: &str) -> Result<(), Stop> {
         let json: HashMap<String, Value> = match serde_json::from_str(json) {
             Ok(a) => a,
             Err(a) => {
-                self.channel.add_error(&error!(format!("Invalid container params"), format!("Parse container params")));
+                let e = error!(format!("Invalid container params"), format!("Parse container params"));
+                self.channel.add_error(&e);
                 self.registry.clear_all(&self.channel);
                 self.channel.move_and_publish();
-                return;
+                return Err(e);
             }
         };
 
@@ -144,16 +120,30 @@ impl Container {
                 stopOn? => as_u64,
                 stopAfter? => as_u64,
                 microTaskFlush? => as_u64,
+                cycleTime? => as_u64,
+                realTime? => as_bool,
+                startTime?,
+                timeZone? => as_i64,
             }
         );
 
+        let startTime = match startTime {
+            Some(a) => parse_date_time(a, 0, LDT_RANGE)
+                .map_err(|e| e.add_sim_trace("Parse container params -> startTime"))? as i64,
+            None => DT_EPOCH as i64,
+        };
+
         let params = ContainerParams {
             stopOn: StopOn::from(stopOn.unwrap_or(0)),
             stopAfter: stopAfter.unwrap_or_default(),
-            microTaskFlush: Some(microTaskFlush.unwrap_or(1000))
+            microTaskFlush: Some(microTaskFlush.unwrap_or(1000)),
+            cycleTime: cycleTime.unwrap_or(5),
+            realTime: realTime.unwrap_or(true),
+            startTime,
+            timeZone: timeZone.unwrap_or_default(),
         };
 
-        let current_params = CONTAINER_PARAMS.lock().unwrap().clone();
+        let current_params = self.params.clone();
 
         if current_params.stopOn != params.stopOn {
             self.channel.add_message(&format!(
@@ -179,25 +169,60 @@ impl Container {
             ));
         }
 
-        (*CONTAINER_PARAMS.lock().unwrap()) = params;
+        if current_params.cycleTime != params.cycleTime {
+            self.channel.add_message(&format!(
+                "[Parameter changed] CycleTime {} ms -> {} ms",
+                &Yellow.paint(format!("{}", current_params.cycleTime)),
+                &Blue.paint(format!("{}", params.cycleTime))
+            ));
+        }
+
+        if current_params.realTime != params.realTime {
+            self.channel.add_message(&format!(
+                "[Parameter changed] RealTime {} -> {}",
+                &Yellow.paint(format!("{}", current_params.realTime)),
+                &Blue.paint(format!("{}", params.realTime))
+            ));
+        }
+
+        if current_params.startTime != params.startTime {
+            self.channel.add_message(&format!(
+                "[Parameter changed] StartTime {} ns -> {} ns",
+                &Yellow.paint(format!("{}", current_params.startTime)),
+                &Blue.paint(format!("{}", params.startTime))
+            ));
+        }
+
+        if current_params.timeZone != params.timeZone {
+            self.channel.add_message(&format!(
+                "[Parameter changed] TimeZone {} min -> {} min",
+                &Yellow.paint(format!("{}", current_params.timeZone)),
+                &Blue.paint(format!("{}", params.timeZone))
+            ));
+        }
+
+        self.params = params;
         self.channel.move_and_publish();
+        Ok(())
     }
 
-    pub fn load_provider(&mut self, data: &str) -> ParseStatus {
+    fn try_load_provider(&mut self, data: &str) -> Result<ParseStatus, Stop> {
+        let _state = self.state.enter();
         let mut status = ParseStatus::Empty;
         if !self.registry.provider.is_empty() {
             self.channel.add_warning("A provider has already been loaded, clean the provider first before uploading a new one".into());
             self.channel.move_and_publish();
-            return status;
+            return Ok(status);
         };
 
         let json: HashMap<String, Value> = match serde_json::from_str(data) {
             Ok(a) => a,
             Err(_) => {
-                self.channel.add_error(&error!(format!("Invalid provider data"), format!("Parse provider")));
+                let e = error!(format!("Invalid provider data"), format!("Parse provider"));
+                self.channel.add_error(&e);
                 self.registry.clear_all(&self.channel);
                 self.channel.move_and_publish();
-                return status;
+                return Err(e);
             }
         };
 
@@ -210,6 +235,9 @@ impl Container {
                 exclude_sections? => as_object,
                 filter_operations? => as_object,
                 override_return? => as_object,
+                enums? => as_object,
+                overflow? => as_object,
+                float_exceptions? => as_str,
             }
         );
 
@@ -218,7 +246,10 @@ impl Container {
             parse_filter_operations(filter_operations, &mut self.registry)?;
             parse_exclude_sections(exclude_sections, &mut self.registry)?;
             parse_type_aliases(type_aliases, &mut self.registry)?;
+            parse_enums(enums, &mut self.registry, false)?;
             parse_return_operations(override_return, &mut self.registry)?;
+            parse_overflow(overflow, &mut self.registry)?;
+            parse_float_exceptions(float_exceptions, &mut self.registry)?;
             Ok(())
         })() {
             Ok(_) => {},
@@ -226,7 +257,7 @@ impl Container {
                 self.channel.add_error(&e);
                 self.registry.clear_all(&self.channel);
                 self.channel.move_and_publish();
-                return status;
+                return Err(e);
             }
         }
 
@@ -242,7 +273,7 @@ impl Container {
                 self.channel.add_error(&e);
                 self.registry.clear_all(&self.channel);
                 self.channel.move_and_publish();
-                return status;
+                return Err(e);
             }
         }
 
@@ -253,7 +284,7 @@ impl Container {
                 self.channel.add_error(&e);
                 self.registry.clear_all(&self.channel);
                 self.channel.move_and_publish();
-                return status;
+                return Err(e);
             }
         };
 
@@ -264,7 +295,7 @@ impl Container {
                 self.channel.add_error(&e);
                 self.registry.clear_all(&self.channel);
                 self.channel.move_and_publish();
-                return status;
+                return Err(e);
             }
         };
         self.channel.add_message(&Green.paint("Parsing done").to_string());
@@ -272,11 +303,12 @@ impl Container {
         self.registry.swap_pointers_collector_to_resources();
         self.channel.set_parse_provider_status(&status);
         self.channel.move_and_publish();
-        set_counter_backup();
-        status
+        self.state.set_counter_backup();
+        Ok(status)
     }
 
-    pub fn load_program(&mut self, data: &str) -> ParseStatus {
+    fn try_load_program(&mut self, data: &str) -> Result<ParseStatus, Stop> {
+        let _state = self.state.enter();
         let mut status = ParseStatus::Empty;
         if self.registry.provider.is_empty() {
             self.channel.add_warning("No provider found".into());
@@ -285,16 +317,17 @@ impl Container {
         if !self.registry.program.is_empty() {
             self.channel.add_warning("A program has already been loaded, clean the program first before uploading a new one".into());
             self.channel.move_and_publish();
-            return status;
+            return Ok(status);
         };
 
         let json: HashMap<String, Value> = match serde_json::from_str(data) {
             Ok(a) => a,
             Err(_) => {
-                self.channel.add_error(&error!(format!("Invalid user program data"), format!("Parse user program")));
+                let e = error!(format!("Invalid user program data"), format!("Parse user program"));
+                self.channel.add_error(&e);
                 self.registry.clear_all(&self.channel);
                 self.channel.move_and_publish();
-                return status;
+                return Err(e);
             }
         };
 
@@ -303,10 +336,12 @@ impl Container {
             json {
                 monitor? => as_array,
                 signature? => as_str,
+                enums? => as_object,
             }
         );
 
-        match parse_program(&json, &mut self.registry, &self.channel) {
+        match parse_enums(enums, &mut self.registry, true)
+            .and_then(|_| parse_program(&json, &mut self.registry, &self.channel)) {
             Ok(_r) => self.channel.add_message(
                 &format!(
                     "Found {} blocks in program",
@@ -317,8 +352,8 @@ impl Container {
                 self.channel.add_error(&e);
                 self.registry.clear_program(&self.channel);
                 self.channel.move_and_publish();
-                reset_counter_to_backup();
-                return status;
+                self.state.reset_counter_to_backup();
+                return Err(e);
             }
         }
 
@@ -329,8 +364,8 @@ impl Container {
                 self.channel.add_error(&e);
                 self.registry.clear_program(&self.channel);
                 self.channel.move_and_publish();
-                reset_counter_to_backup();
-                return status;
+                self.state.reset_counter_to_backup();
+                return Err(e);
             }
         };
 
@@ -341,8 +376,8 @@ impl Container {
                 self.channel.add_error(&e);
                 self.registry.clear_program(&self.channel);
                 self.channel.move_and_publish();
-                reset_counter_to_backup();
-                return status;
+                self.state.reset_counter_to_backup();
+                return Err(e);
             }
         };
 
@@ -377,42 +412,25 @@ impl Container {
             self.runtime_commands_sab = Some(sab);
             self.channel.set_runtime_commands_sab(&self.runtime_commands_sab.as_ref().unwrap());
         }
-        status
-    }
-
-    #[cfg(target_arch = "wasm32")]
-    pub fn get_runtime_command_sab(&self) -> Option<SharedArrayBuffer> {
-        self.runtime_commands_sab.as_ref().cloned()
+        Ok(status)
     }
 
-    #[cfg(target_arch = "wasm32")]
-    pub fn add_plugin(&mut self, name: &str, interval: u32) -> JsValue {
-        self.channel.get_dispatcher().add_plugin(interval, name)
-    }
-
-    pub async fn start(&mut self, entry: &str) {
-        self.channel
-            .set_simulation_status(&SimulationStatus::Start);
-        self.channel.publish();
-        *IS_RUNNING.lock().unwrap() = true;
+    async fn try_start(&mut self, entry: &str) -> Result<(), Stop> {
+        self.begin_simulation(entry);
 
-        let params = &CONTAINER_PARAMS.lock().unwrap().clone();
-        let mut sim = Simulation::new(&self.registry, &self.channel, &params);
-        self.channel.add_message(
-            &format!("--- Starting simulation with [{}] ---",
-                     &Purple.paint((&entry).to_string()
-                     )));
+        let params = &self.params.clone();
 
         let mut eventLoopLastRefreshInterval = Instant::now();
+        let mut result = Ok(());
         let stopAfter = match params.stopAfter {
             0 => None,
             _ => Some(Duration::from_millis(params.stopAfter))
         };
-        let simulationStartInstant = Instant::now();
+        let cycleTime = Duration::from_millis(params.cycleTime);
 
         loop {
             if let Some(a) = stopAfter {
-                if simulationStartInstant.elapsed() > a {
+                if self.registry.clock.now() > a {
                     self.channel.add_message(&format!(
                         "Simulation stopped: {}",
                         Blue.paint(&format!("Reached {:?}", a)))
@@ -431,7 +449,7 @@ impl Container {
             }
 
             #[cfg(target_arch = "wasm32")]
-                let mut must_stop = read_sab_commands(&self.channel);
+                let mut must_stop = read_sab_commands(&self.channel, &self.state);
             #[cfg(target_arch = "wasm32")]
             if must_stop {
                 self.channel.add_message(&format!(
@@ -442,35 +460,23 @@ impl Container {
 
             let earlier = Instant::now();
 
-            match sim.start(entry).await {
-                Ok(should_continue) => match should_continue {
-                    true => {
-                        self.channel.build_monitor(&self.registry);
-                        self.channel.move_and_publish();
-                    },
-                    false => {
-                        self.channel.build_monitor(&self.registry);
-                        break; 
-                    }
-                },
-                Err(ref e) => {
-                    self.channel.add_message(&format!(
-                        "Simulation stopped: {}", &Red.paint("Error")
-                    ));
-                    self.channel.add_error(e);
-                    //self.channel.move_and_publish();
+            match self.run_cycle(entry) {
+                Ok(true) => {}
+                Ok(false) => break,
+                Err(e) => {
+                    result = Err(e);
                     break;
                 }
             }
 
-            let elapsed = earlier.duration_since(Instant::now());
+            let elapsed = Instant::now().duration_since(earlier);
 
             #[cfg(not(target_arch = "wasm32"))]
-            if elapsed < FIVE_MS {
-                spin_sleep::sleep(FIVE_MS - elapsed);
+            if params.realTime && elapsed < cycleTime {
+                spin_sleep::sleep(cycleTime - elapsed);
                 self.channel.add_message(&format!(
                     "--- Cycle [Slowed] ~{} ---",
-                    Blue.paint(&format!("{:?}", (elapsed + (FIVE_MS + elapsed))))
+                    Blue.paint(format!("{:?}", (elapsed + (cycleTime + elapsed))))
                 ));
             } else {
                 self.channel.add_message(&format!(
@@ -481,12 +487,12 @@ impl Container {
 
             #[cfg(target_arch = "wasm32")]
             #[cfg(not(feature = "node"))]
-            if elapsed < FIVE_MS {
-                gloo_timers::future::sleep((FIVE_MS - elapsed)).await;
+            if params.realTime && elapsed < cycleTime {
+                gloo_timers::future::sleep((cycleTime - elapsed)).await;
                 eventLoopLastRefreshInterval = Instant::now();
                 self.channel.add_message(&format!(
                     "--- Cycle [Slowed] ~{} ---",
-                    Blue.paint(&format!("{:?}", (elapsed + (FIVE_MS + elapsed))))
+                    Blue.paint(&format!("{:?}", (elapsed + (cycleTime + elapsed))))
                 ));
             } else {
                 self.channel.add_message(&format!(
@@ -497,12 +503,12 @@ impl Container {
 
             #[cfg(target_arch = "wasm32")]
             #[cfg(feature = "node")]
-            if elapsed < FIVE_MS {
-                gloo_timers::future::sleep((FIVE_MS - elapsed)).await;
+            if params.realTime && elapsed < cycleTime {
+                gloo_timers::future::sleep((cycleTime - elapsed)).await;
                 eventLoopLastRefreshInterval = Instant::now();
                 self.channel.add_message(&format!(
                     "--- Cycle [Slowed] ~{} ---",
-                    Blue.paint(&format!("{:?}", (elapsed + (FIVE_MS + FOUR_MS + elapsed))))
+                    Blue.paint(&format!("{:?}", (elapsed + (cycleTime + FOUR_MS + elapsed))))
                 ));
             } else {
                 self.channel.add_message(&format!(
@@ -512,13 +518,302 @@ impl Container {
             };
         }
 
-        *IS_RUNNING.lock().unwrap() = false;
+        self.end_simulation()?;
+        result
+    }
+
+    fn begin_simulation(&self, entry: &str) {
+        let _state = self.state.enter();
+        self.channel
+            .set_simulation_status(&SimulationStatus::Start);
+        self.channel.publish();
+        self.channel.reset_unit_tests();
+        self.channel.reset_cycle();
+        self.state.set_running(true);
+        self.registry.clock.set_system_clock(self.params.startTime as i128, self.params.timeZone as i128 * 60_000_000_000);
+
+        self.channel.add_message(
+            &format!("--- Starting simulation with [{}] ---",
+                     &Purple.paint((&entry).to_string()
+                     )));
+    }
+
+    fn end_simulation(&mut self) -> Result<(), Stop> {
+        let _state = self.state.enter();
+        self.state.set_running(false);
         self.channel.add_message(&Purple.paint("--- End of simulation ---").to_string());
 
         self.channel.push_cycle_stack();
         self.channel.set_simulation_status(&SimulationStatus::Stop);
         self.channel.move_and_publish();
-        self.registry.reset_all(&self.channel)
+        self.reset_memory(true)
+    }
+
+    /// Reinitializes the kernel memory, retained variables survive unless it is a cold reset
+    fn reset_memory(&mut self, keep_retained: bool) -> Result<(), Stop> {
+        let retained = match keep_retained {
+            true => Some(take_retained_snapshot(&self.registry, &self.channel)?),
+            false => None,
+        };
+        self.registry.reset_all(&self.channel)?;
+        match retained {
+            Some(a) => restore_snapshot(&self.registry, &a, &self.channel),
+            None => Ok(()),
+        }
+    }
+
+    /// Runs a single scan cycle and publishes the monitor.
+    /// Returns false when the stop condition of the simulation has been reached.
+    ///
+    /// The state is only entered for the cycle, never across an await, so other
+    /// containers can run while this one sleeps.
+    fn run_cycle(&self, entry: &str) -> Result<bool, Stop> {
+        let _state = self.state.enter();
+        let mut sim = Simulation::new(&self.registry, &self.channel, &self.params);
+        match sim.start(entry) {
+            Ok(should_continue) => {
+                self.channel.build_monitor(&self.registry);
+                if should_continue {
+                    self.channel.move_and_publish();
+                }
+                Ok(should_continue)
+            }
+            Err(e) => {
+                self.channel.add_message(&format!(
+                    "Simulation stopped: {}", &Red.paint("Error")
+                ));
+                self.channel.add_error(&e);
+                Err(e)
+            }
+        }
+    }
+
+    /// Runs cycles until the predicate holds, the stop condition is reached or max_cycles ran.
+    /// The simulation is left running so it can be resumed or inspected, unless a cycle fails.
+    pub async fn run_until<F: FnMut(&Container) -> bool>(&mut self, entry: &str, max_cycles: u32, mut predicate: F) -> Result<RunUntil, Stop> {
+        if !self.state.is_running() {
+            self.begin_simulation(entry);
+        }
+
+        for _ in 0..max_cycles {
+            if !self.step_cycle(entry)? {
+                return Ok(RunUntil::Stopped);
+            }
+            if predicate(self) {
+                return Ok(RunUntil::Reached);
+            }
+        }
+        Ok(RunUntil::MaxCycles)
+    }
+
+    /// Runs a cycle of step / run_until, the simulation ends if it fails
+    fn step_cycle(&mut self, entry: &str) -> Result<bool, Stop> {
+        match self.run_cycle(entry) {
+            Err(e) => {
+                let _ = self.end_simulation();
+                Err(e)
+            }
+            result => result,
+        }
+    }
+
+    /// Unit tests of the loaded program with the statuses of the last simulation
+    pub fn get_unit_tests(&self) -> Vec<UnitTest> {
+        self.channel.get_unit_tests()
+    }
+
+    /// NaN, infinite and subnormal values which appeared in the last cycle, see `float_exceptions`
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn get_float_report(&self) -> Vec<FloatException> {
+        self.registry.floats.get_last_report()
+    }
+}
+
+pub const FOUR_MS: Duration = Duration::from_millis(4);
+pub const FIVE_MS: Duration = Duration::from_millis(5);
+pub const THOUSAND_MS: Duration = Duration::from_millis(100);
+
+#[wasm_bindgen]
+impl Container {
+    pub fn get_id(&self) -> String {
+        self.id.to_string()
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.state.is_running()
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn load_server_params(&mut self, json: &str) -> Result<(), Stop> {
+        self.try_load_server_params(json)
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    pub fn load_server_params(&mut self, json: &str) {
+        let _ = self.try_load_server_params(json);
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn load_provider(&mut self, data: &str) -> Result<ParseStatus, Stop> {
+        self.try_load_provider(data)
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    pub fn load_provider(&mut self, data: &str) -> ParseStatus {
+        self.try_load_provider(data).unwrap_or_default()
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn load_program(&mut self, data: &str) -> Result<ParseStatus, Stop> {
+        self.try_load_program(data)
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    pub fn load_program(&mut self, data: &str) -> ParseStatus {
+        self.try_load_program(data).unwrap_or_default()
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    pub fn get_runtime_command_sab(&self) -> Option<SharedArrayBuffer> {
+        self.runtime_commands_sab.as_ref().cloned()
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    pub fn add_plugin(&mut self, name: &str, interval: u32) -> JsValue {
+        self.channel.get_dispatcher().add_plugin(interval, name)
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn start(&mut self, entry: &str) -> Result<(), Stop> {
+        self.try_start(entry).await
+    }
+
+    // Errors are already published through the store on wasm
+    #[cfg(target_arch = "wasm32")]
+    pub async fn start(&mut self, entry: &str) {
+        let _ = self.try_start(entry).await;
+    }
+
+    /// Runs exactly n cycles and gives the control back with the simulation state intact.
+    /// Returns false if the stop condition of the simulation has been reached.
+    pub async fn step(&mut self, entry: &str, cycles: u32) -> Result<bool, Stop> {
+        if !self.state.is_running() {
+            self.begin_simulation(entry);
+        }
+
+        for _ in 0..cycles {
+            if !self.step_cycle(entry)? {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    #[wasm_bindgen(js_name = run_until)]
+    pub async fn run_until_js(&mut self, entry: &str, max_cycles: u32, predicate: js_sys::Function) -> Result<RunUntil, Stop> {
+        self.run_until(entry, max_cycles, |_| {
+            predicate
+                .call0(&JsValue::NULL)
+                .map(|result| result.is_truthy())
+                .unwrap_or(true)
+        }).await
+    }
+
+    /// Ends a simulation driven by step / run_until and resets the kernel memory
+    pub fn stop(&mut self) -> Result<(), Stop> {
+        let _state = self.state.enter();
+        if !self.state.is_running() {
+            return Ok(());
+        }
+        self.end_simulation()
+    }
+
+    /// Number of cycles run by the current or last simulation
+    pub fn get_cycle(&self) -> u64 {
+        self.channel.get_cycle()
+    }
+
+    /// Simulated time of the current simulation in ms
+    pub fn get_simulated_time(&self) -> u64 {
+        self.registry.clock.now().as_millis() as u64
+    }
+
+    /// Writes a JSON constant into the variable at path, the program may overwrite it
+    pub fn write(&mut self, path: Vec<String>, value: &str) -> Result<(), Stop> {
+        let _state = self.state.enter();
+        build_write(&path, value, &self.registry)
+            .and_then(|(_, operation)| operation.with_void(&self.channel))
+            .inspect_err(|e| self.channel.add_error(e))
+    }
+
+    /// Forces the variable at path to a JSON constant until it is unforced,
+    /// writes of the program are overridden at the start and the end of every cycle
+    pub fn force(&mut self, path: Vec<String>, value: &str) -> Result<(), Stop> {
+        let _state = self.state.enter();
+        build_write(&path, value, &self.registry)
+            .and_then(|(full_path, operation)| {
+                operation.with_void(&self.channel)?;
+                self.registry.forces.insert(full_path, operation);
+                Ok(())
+            })
+            .inspect_err(|e| self.channel.add_error(e))
+    }
+
+    /// Releases a forced variable, returns false if it was not forced
+    pub fn unforce(&mut self, path: Vec<String>) -> bool {
+        let _state = self.state.enter();
+        self.registry.forces.remove(&convert_string_path_to_usize(&path))
+    }
+
+    pub fn unforce_all(&mut self) {
+        self.registry.forces.clear()
+    }
+
+    pub fn is_forced(&self, path: Vec<String>) -> bool {
+        let _state = self.state.enter();
+        self.registry.forces.is_forced(&convert_string_path_to_usize(&path))
+    }
+
+    /// Restarts the plc: the simulation is stopped, retained variables keep their value
+    /// and everything else is reinitialized
+    pub fn warm_restart(&mut self) -> Result<(), Stop> {
+        let _state = self.state.enter();
+        if self.state.is_running() {
+            self.end_simulation()?;
+        }
+        self.reset_memory(true)?;
+        self.channel.add_message(&Purple.paint("--- Warm restart ---").to_string());
+        self.channel.move_and_publish();
+        Ok(())
+    }
+
+    /// Restarts the plc with every variable reinitialized, retained ones included
+    pub fn cold_restart(&mut self) -> Result<(), Stop> {
+        let _state = self.state.enter();
+        if self.state.is_running() {
+            self.end_simulation()?;
+        }
+        self.reset_memory(false)?;
+        self.channel.add_message(&Purple.paint("--- Cold restart ---").to_string());
+        self.channel.move_and_publish();
+        Ok(())
+    }
+
+    /// Serializes the memory of every Db, the timers and edge states,
+    /// the simulated time and the cycle number to JSON
+    pub fn snapshot(&self) -> Result<String, Stop> {
+        let _state = self.state.enter();
+        take_snapshot(&self.registry, &self.channel).map(|a| a.to_string())
+    }
+
+    /// Restores a snapshot taken with snapshot on the same program
+    pub fn restore(&mut self, data: &str) -> Result<(), Stop> {
+        let _state = self.state.enter();
+        serde_json::from_str::<Value>(data)
+            .map_err(|_| error!(format!("Invalid snapshot data"), format!("Restore snapshot")))
+            .and_then(|snapshot| restore_snapshot(&self.registry, &snapshot, &self.channel))
+            .inspect_err(|e| self.channel.add_error(e))
     }
 
     #[cfg(target_arch = "wasm32")]
@@ -528,7 +823,25 @@ impl Container {
 
     #[cfg(target_arch = "wasm32")]
     pub fn read_sab_commands(&self) {
-        read_sab_commands(&self.channel);
+        read_sab_commands(&self.channel, &self.state);
+    }
+
+    /// Serializes the unit tests of the last simulation to JUnit XML or TAP
+    pub fn get_unit_tests_report(&self, format: ReportFormat, suite: &str) -> String {
+        build_report(format, suite, &self.channel.get_unit_tests())
+    }
+
+    /// NaN, infinite and subnormal values which appeared in the last cycle, serialized like
+    /// `FloatException`
+    #[cfg(target_arch = "wasm32")]
+    pub fn get_float_report(&self) -> JsValue {
+        serde_wasm_bindgen::to_value(&self.registry.floats.get_last_report()).unwrap()
+    }
+
+    /// Ids of the integer operations which wrapped or saturated their result in the last cycle,
+    /// see `overflow`
+    pub fn get_overflow_report(&self) -> Vec<u32> {
+        self.registry.overflows.get_last_report()
     }
 
     pub fn disable_breakpoint(&self, data: u32) {
@@ -550,24 +863,27 @@ impl Container {
     }
 
     pub fn clear_program(&mut self) {
+        let _state = self.state.enter();
         self.registry.clear_program(&self.channel);
         self.channel.set_simulation_status(&SimulationStatus::Unavailable);
         self.channel.add_message(&Yellow.paint("--- Program reset ---").to_string());
         self.channel.move_and_publish();
-        reset_counter_to_backup();
+        self.state.reset_counter_to_backup();
     }
 
     pub fn clear_provider(&mut self) {
+        let _state = self.state.enter();
         self.registry.clear_all(&self.channel);
         self.channel.set_simulation_status(&SimulationStatus::Unavailable);
         self.channel.add_message(&Yellow.paint("--- Full reset ---").to_string());
         self.channel.move_and_publish();
-        reset_counter_to_default();
+        self.state.reset_counter_to_default();
     }
 }
 
 #[cfg(target_arch = "wasm32")]
-pub fn read_sab_commands(channel: &Broadcast) -> bool {
+pub fn read_sab_commands(channel: &Broadcast, state: &Rc<ContainerState>) -> bool {
+    let _state = state.enter();
     let mut must_stop = false;
     js_sys::Atomics::store(&channel.get_command_lock_int32(), 0, 1).unwrap();
     if let Some(a) = channel.get_runtime_commands_sab() {
@@ -603,13 +919,13 @@ pub fn read_sab_commands(channel: &Broadcast) -> bool {
                     channel.publish();
                 }
                 5 => { // 5 Enable breakpoint
-                    if (is_running()) {
+                    if (state.is_running()) {
                         enableBreakpoint(channel, window[1] as u32);
                         channel.publish();
                     }
                 }
                 6 => { // 6 Disable breakpoint
-                    if (is_running()) {
+                    if (state.is_running()) {
                         disableBreakpoint(channel, window[1] as u32);
                         channel.publish();
                     }
@@ -672,6 +988,18 @@ pub enum StopOn {
 
 impl Discriminant for StopOn {}
 
+/// Why `run_until` gave the control back
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[wasm_bindgen]
+pub enum RunUntil {
+    /// The predicate holds
+    Reached,
+    /// max_cycles ran and the predicate never held
+    MaxCycles,
+    /// The stop condition of the simulation (stopOn / stopAfter) has been reached first
+    Stopped,
+}
+
 impl From<u64> for StopOn {
     fn from(value: u64) -> Self {
         match value {
@@ -701,6 +1029,7 @@ impl Discriminant for MonitorFormat{}
 
 #[derive(Deserialize, Serialize, Clone, Tsify)]
 #[wasm_bindgen(skip_typescript)]
+#[serde(default)]
 pub struct ContainerParams {
     #[tsify(optional)]
     pub stopOn: StopOn,
@@ -708,6 +1037,18 @@ pub struct ContainerParams {
     pub stopAfter: u64,
     #[tsify(optional)]
     pub microTaskFlush: Option<u64>,
+    /// Simulated time added to the clock at the end of every cycle, in ms
+    #[tsify(optional)]
+    pub cycleTime: u64,
+    /// Paces the cycles on the host clock, disable it to run as fast as possible
+    #[tsify(optional)]
+    pub realTime: bool,
+    /// System time at the start of the simulation, in ns since 1970-01-01 (UTC)
+    #[tsify(optional)]
+    pub startTime: i64,
+    /// Offset of the local time read by RD_LOC_T, in minutes
+    #[tsify(optional)]
+    pub timeZone: i64,
 }
 
 impl Default for ContainerParams {
@@ -716,6 +1057,10 @@ impl Default for ContainerParams {
             stopOn: StopOn::UnitTestsPassed,
             stopAfter: 0,
             microTaskFlush: Some(1000),
+            cycleTime: 5,
+            realTime: true,
+            startTime: DT_EPOCH as i64,
+            timeZone: 0,
         }
     }
 }
@@ -2,3 +2,4 @@
 pub mod broadcast;
 pub mod error;
 pub mod simulation;
+pub mod state;
\ No newline at end of file
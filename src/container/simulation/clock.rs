@@ -0,0 +1,95 @@
+use core::cell::RefCell;
+use core::ops::DerefMut;
+use core::time::Duration;
+use std::rc::Rc;
+
+/// Simulated cost of a single loop iteration, used by the For / While watchdogs.
+pub const LOOP_ITERATION_COST: Duration = Duration::from_micros(1);
+
+#[derive(Default)]
+struct ClockState {
+    // Simulated time at the beginning of the current cycle
+    cycle_start: Duration,
+    // Simulated time consumed inside the current cycle
+    in_cycle: Duration,
+    // System time at the start of the simulation, in ns since 1970-01-01 (UTC)
+    system_origin: i128,
+    // Offset of the local time, in ns
+    time_zone: i128,
+}
+
+/// Deterministic time source of a simulation.
+///
+/// The clock never reads the host time: it only moves when a cycle ends or when
+/// an operation charges some time to the current cycle, so the same program
+/// always produces the same result whatever the machine load.
+#[derive(Clone, Default)]
+pub struct SimClock(Rc<RefCell<ClockState>>);
+
+impl SimClock {
+    /// Current simulated time since the start of the simulation.
+    pub fn now(&self) -> Duration {
+        let state = self.0.borrow();
+        state.cycle_start + state.in_cycle
+    }
+
+    /// Simulated time elapsed since `earlier`.
+    pub fn elapsed_since(&self, earlier: Duration) -> Duration {
+        self.now().saturating_sub(earlier)
+    }
+
+    /// Charges some simulated time to the current cycle.
+    pub fn charge(&self, duration: Duration) {
+        self.0.borrow_mut().in_cycle += duration;
+    }
+
+    /// Closes the current cycle. The clock moves by the cycle time, or by the time
+    /// charged during the cycle if the cycle took longer.
+    pub fn end_cycle(&self, cycle_time: Duration) {
+        let mut state = self.0.borrow_mut();
+        let cycle = cycle_time.max(state.in_cycle);
+        state.cycle_start += cycle;
+        state.in_cycle = Duration::ZERO;
+    }
+
+    /// Moves the clock to the beginning of a cycle starting at `now`, the system time
+    /// moves with it.
+    pub fn set(&self, now: Duration) {
+        let mut state = self.0.borrow_mut();
+        state.cycle_start = now;
+        state.in_cycle = Duration::ZERO;
+    }
+
+    /// Sets the system time at the start of the simulation and the local time zone.
+    pub fn set_system_clock(&self, start: i128, time_zone: i128) {
+        let mut state = self.0.borrow_mut();
+        state.system_origin = start;
+        state.time_zone = time_zone;
+    }
+
+    /// System time at the start of the simulation and local time zone, see [`SimClock::set_system_clock`].
+    pub fn system_clock(&self) -> (i128, i128) {
+        let state = self.0.borrow();
+        (state.system_origin, state.time_zone)
+    }
+
+    /// Current system time (UTC), in ns since 1970-01-01.
+    pub fn system_time(&self) -> i128 {
+        self.0.borrow().system_origin + self.now().as_nanos() as i128
+    }
+
+    /// Current local time, in ns since 1970-01-01.
+    pub fn local_time(&self) -> i128 {
+        self.system_time() + self.0.borrow().time_zone
+    }
+
+    /// Sets the current system time (UTC), the simulated time keeps running from there.
+    pub fn set_system_time(&self, ns: i128) {
+        let now = self.now().as_nanos() as i128;
+        self.0.borrow_mut().system_origin = ns - now;
+    }
+
+    pub fn reset(&self) {
+        *self.0.borrow_mut().deref_mut() = ClockState::default();
+    }
+}
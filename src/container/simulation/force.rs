@@ -0,0 +1,113 @@
+use crate::container::broadcast::broadcast::Broadcast;
+use crate::container::error::error::Stop;
+use crate::error;
+use crate::kernel::arch::any::any_type::AnyRefType;
+use crate::kernel::arch::local::pointer::LocalPointer;
+use crate::kernel::arch::local::r#type::LocalType;
+use crate::kernel::plc::operations::operations::{RunTimeOperation, RuntimeOperationTrait};
+use crate::kernel::plc::types::primitives::traits::family_traits::IsFamily;
+use crate::kernel::registry::{convert_string_path_to_usize, get_string_id, GlobalOrLocal, Kernel};
+use crate::kernel::rust::enumeration::box_set_plc_enum;
+use crate::kernel::rust::set::box_set_plc_primitive;
+use crate::parser::local_type::constant_type::parse_constant_type;
+use core::cell::RefCell;
+use core::ops::Deref;
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use std::rc::Rc;
+
+/// Values written from outside of the program, like a TIA Portal force table.
+///
+/// A forced value is applied at the beginning of every cycle, before the entry OB
+/// runs, and once again at the end of the cycle, so whatever the program writes
+/// in between never reaches the monitor nor the next cycle.
+#[derive(Clone, Default)]
+pub struct ForceTable(Rc<RefCell<HashMap<Vec<usize>, RunTimeOperation>>>);
+
+impl ForceTable {
+    pub fn insert(&self, path: Vec<usize>, operation: RunTimeOperation) {
+        self.0.borrow_mut().insert(path, operation);
+    }
+
+    pub fn remove(&self, path: &[usize]) -> bool {
+        self.0.borrow_mut().remove(path).is_some()
+    }
+
+    pub fn is_forced(&self, path: &[usize]) -> bool {
+        self.0.borrow().contains_key(path)
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.borrow().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.borrow().is_empty()
+    }
+
+    pub fn clear(&self) {
+        self.0.borrow_mut().clear()
+    }
+
+    /// Writes all the forced values.
+    pub fn apply(&self, channel: &Broadcast) -> Result<(), Stop> {
+        self.0
+            .borrow()
+            .values()
+            .try_for_each(|operation| operation.with_void(channel))
+    }
+}
+
+/// Builds the operation writing a JSON constant into the primitive found at `path`.
+///
+/// The path starts with the name of a global DB, e.g. ["Data", "motor", "speed"].
+/// The constant uses the program format ({"ty": "Int", "src": {"value": 5}}), the `id` of
+/// the source is optional and the `Implicit` type takes the type of the target.
+pub fn build_write(
+    path: &[String],
+    value: &str,
+    registry: &Kernel,
+) -> Result<(Vec<usize>, RunTimeOperation), Stop> {
+    if path.is_empty() {
+        return Err(error!(format!("Empty path"), format!("Write value")));
+    }
+    let display = path.join(".");
+    let full_path = convert_string_path_to_usize(&path.to_vec());
+
+    let target = match registry.get_and_find_nested(&full_path) {
+        Some(GlobalOrLocal::Local(a)) => a,
+        Some(GlobalOrLocal::Global(_)) => {
+            return Err(error!(format!("'{}' is a block, expected a variable", display), format!("Write value")))
+        }
+        None => return Err(error!(format!("Could not find '{}'", display), format!("Write value"))),
+    };
+
+    if target.is_complex() {
+        return Err(error!(format!("'{}' is not a primitive", display), format!("Write value")));
+    }
+
+    if target.is_read_only() {
+        return Err(error!(format!("Attempt to change the constant value '{}'", display), format!("Write value")));
+    }
+
+    let mut json: Map<String, Value> = serde_json::from_str(value)
+        .map_err(|_| error!(format!("Invalid constant '{}'", value), format!("Write value")))?;
+
+    if let Some(Value::Object(src)) = json.get_mut("src") {
+        src.entry("id").or_insert(Value::from(get_string_id()));
+    }
+
+    let force_type = target.as_ref().borrow().deref().clone();
+    let constant = parse_constant_type(&json, registry, Some(force_type))
+        .map_err(|e| e.add_sim_trace(&format!("Write value of '{}'", display)))?;
+    registry.check_excluded_type(&constant)?;
+
+    let constant = LocalPointer::new(LocalType::from(constant));
+    let operation = match registry.get_enum_of(&target) {
+        Some(plc_enum) => box_set_plc_enum(&target, &AnyRefType::Local(constant), plc_enum, 0, registry),
+        None => box_set_plc_primitive(&target, &constant, 0, true, registry),
+    }
+    .map_err(|e| e.add_sim_trace(&format!("Write value of '{}'", display)))?;
+
+    Ok((full_path, operation))
+}
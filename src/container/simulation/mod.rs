@@ -1,2 +1,5 @@
 ﻿pub mod simulation;
-pub mod pause;
\ No newline at end of file
+pub mod pause;
+pub mod clock;
+pub mod force;
+pub mod scheduler;
\ No newline at end of file
@@ -1,17 +1,15 @@
-use web_time::Instant;
 use ansi_term::Color::Yellow;
 use ansi_term::Colour::Green;
 use wasm_bindgen::UnwrapThrowExt;
 use crate::container::broadcast::broadcast::Broadcast;
 #[cfg(target_arch = "wasm32")]
 use crate::container::container::{read_sab_commands};
-use crate::container::container::{DELAYED_TIMERS, SimulationStatus};
+use crate::container::container::SimulationStatus;
 use crate::container::error::error::Stop;
 
 pub fn pause_simulation(channel: &Broadcast, id: Option<u32>) -> Result<(), Stop> {
     channel.add_message(
         &Yellow.paint("[Pause] Simulation paused").to_string());
-    let earlier = Instant::now();
 
     channel.push_cycle_stack();
     channel.set_simulation_status(&SimulationStatus::Pause);
@@ -27,12 +25,6 @@ pub fn pause_simulation(channel: &Broadcast, id: Option<u32>) -> Result<(), Stop
         channel.publish();
         js_sys::Atomics::wait(&channel.get_pause_int32(), 0, 1).unwrap_throw();
 
-        (*DELAYED_TIMERS.lock().unwrap())
-            .iter_mut()
-            .for_each(|(_ptr, dur)| {
-                *dur += Instant::now().duration_since(earlier);
-            });
-
         channel.add_message(&Green.paint("[Pause] Simulation resumed").to_string());
         channel.set_simulation_status(&SimulationStatus::Start);
         if let Some(id) = id {
@@ -0,0 +1,78 @@
+use crate::container::error::error::Stop;
+use crate::error;
+use crate::kernel::arch::snapshot::snapshot::InternalState;
+use crate::kernel::registry::{get_or_insert_global_string, get_string};
+use core::cell::RefCell;
+use core::ops::DerefMut;
+use core::time::Duration;
+use serde_json::{json, Map, Value};
+use std::collections::HashMap;
+use std::rc::Rc;
+
+#[derive(Default)]
+struct SchedulerState {
+    // Startup Obs already executed
+    started: bool,
+    // Simulated time of the next execution of each cyclic Ob
+    next_run: HashMap<usize, Duration>,
+}
+
+/// Keeps track of the startup and cyclic interrupt Obs between the cycles of a simulation.
+#[derive(Clone, Default)]
+pub struct ObScheduler(Rc<RefCell<SchedulerState>>);
+
+impl ObScheduler {
+    /// Returns true only for the first cycle of the simulation.
+    pub fn take_startup(&self) -> bool {
+        let mut state = self.0.borrow_mut();
+        !core::mem::replace(&mut state.started, true)
+    }
+
+    /// Number of times a cyclic Ob has to run at `now`, one per elapsed interval.
+    /// The first execution happens once the first interval elapsed.
+    pub fn due_runs(&self, ob: usize, interval: Duration, now: Duration) -> u32 {
+        let mut state = self.0.borrow_mut();
+        let next = state.next_run.entry(ob).or_insert(interval);
+        let mut runs = 0;
+        while *next <= now {
+            *next += interval;
+            runs += 1;
+        }
+        runs
+    }
+}
+
+/// Saved with the snapshots, the Obs are stored by name and the times in ns.
+impl InternalState for ObScheduler {
+    fn save(&self) -> Value {
+        let state = self.0.borrow();
+        let next_run = state
+            .next_run
+            .iter()
+            .map(|(ob, next)| (get_string(*ob), Value::from(next.as_nanos() as u64)))
+            .collect::<Map<String, Value>>();
+        json!({ "started": state.started, "next_run": next_run })
+    }
+
+    fn restore(&self, value: &Value) -> Result<(), Stop> {
+        let started = value["started"]
+            .as_bool()
+            .ok_or_else(|| error!(format!("Expected a bool, got {}", value["started"])))?;
+        let next_run = value["next_run"]
+            .as_object()
+            .ok_or_else(|| error!(format!("Expected an object, got {}", value["next_run"])))?
+            .iter()
+            .map(|(ob, next)| {
+                next.as_u64()
+                    .map(|a| (get_or_insert_global_string(ob), Duration::from_nanos(a)))
+                    .ok_or_else(|| error!(format!("Invalid next run {} of {}", next, ob)))
+            })
+            .collect::<Result<HashMap<usize, Duration>, Stop>>()?;
+        *self.0.borrow_mut().deref_mut() = SchedulerState { started, next_run };
+        Ok(())
+    }
+
+    fn reset(&self) {
+        *self.0.borrow_mut().deref_mut() = SchedulerState::default();
+    }
+}
@@ -2,9 +2,13 @@
 use crate::kernel::registry::{get_or_insert_global_string, get_string, Kernel};
 use crate::container::broadcast::broadcast::Broadcast;
 use crate::container::error::error::Stop;
-use crate::container::container::{CONTAINER_PARAMS, ContainerParams, StopOn};
+use crate::container::container::{ContainerParams, StopOn};
 use crate::{error};
-use core::ops::DerefMut;
+use core::cmp::Reverse;
+use core::ops::{Deref, DerefMut};
+use crate::kernel::arch::global::pointer::GlobalPointer;
+use crate::kernel::plc::pou::ob::ObKind;
+use core::time::Duration;
 use ansi_term::Colour::{Blue, Green, Purple};
 use crate::kernel::plc::operations::unit::test::UnitTestStatus;
 
@@ -28,9 +32,64 @@ impl<'a> Simulation<'a> {
     }
 
 
-    pub async fn start(&mut self, entry: &str) -> Result<bool, Stop> {
+    fn execute_ob(&self, pointer: &GlobalPointer) -> Result<(), Stop> {
+        match pointer.as_ref().borrow_mut().deref_mut() {
+            GlobalType::Ob(ref mut ob) => ob.execute(self.channel),
+            _ => Ok(()),
+        }
+    }
+
+    /// Runs the startup Obs in the first cycle, then the cyclic interrupt Obs whose interval
+    /// elapsed, before the entry Ob. Each one is traced in its own section.
+    fn run_scheduled_obs(&self, entry: usize) -> Result<(), Stop> {
+        let now = self.registry.clock.now();
+        let startup = self.registry.scheduler.take_startup();
+
+        let mut scheduled = self
+            .registry
+            .get_obs()
+            .into_iter()
+            .filter(|(name, _)| *name != entry)
+            .filter_map(|(name, pointer)| {
+                let (kind, priority) = match pointer.as_ref().borrow().deref() {
+                    GlobalType::Ob(ob) => (ob.get_kind(), ob.get_priority()),
+                    _ => return None,
+                };
+                Some((name, pointer, kind, priority))
+            })
+            .collect::<Vec<_>>();
+
+        // Startup first, then by descending priority
+        scheduled.sort_by_key(|(name, _, kind, priority)| {
+            (*kind != ObKind::Startup, Reverse(*priority), get_string(*name))
+        });
+
+        scheduled.iter().try_for_each(|(name, pointer, kind, _)| {
+            let runs = match kind {
+                ObKind::Main => 0,
+                ObKind::Startup => startup as u32,
+                ObKind::Cyclic(interval) => self.registry.scheduler.due_runs(*name, *interval, now),
+            };
+            (0..runs).try_for_each(|_| {
+                let index = self
+                    .channel
+                    .get_cycle_stack()
+                    .borrow_mut()
+                    .add_section(*name, "ob");
+                self.execute_ob(pointer)?;
+                self.channel
+                    .get_cycle_stack()
+                    .borrow_mut()
+                    .go_back_to_section(index);
+                Ok(())
+            })
+        })
+    }
+
+    pub fn start(&mut self, entry: &str) -> Result<bool, Stop> {
         self.channel.reset_cycle_stack();
-        
+        self.channel.next_cycle();
+
         let entry = get_or_insert_global_string(&entry.to_string());
 
         let index = self
@@ -45,25 +104,32 @@ impl<'a> Simulation<'a> {
             .get_current_section()
             .unwrap();
         
-        let entry_block = self.registry.get(&entry);
-        if entry_block.is_some() {
-            match entry_block.unwrap().as_ref().borrow_mut().deref_mut() {
-                GlobalType::Ob(ref mut ob) => {
-                    match ob.execute(self.channel) {
-                        Ok(_) => {}
-                        Err(e) => {
-                            self.channel.push_cycle_stack();
-                            self.channel.reset_cycle_stack();
-                            return Err(e);
-                        }
-                    };
-                }
-                _ => return Err(error!(format!("{} is not an OB block!", get_string(entry)))),
-            }
-        } else {
-            return Err(error!(format!("Invalid entry block '{}'", get_string(entry))));
+        self.registry.forces.apply(self.channel)?;
+
+        let entry_block = match self.registry.get(&entry) {
+            None => return Err(error!(format!("Invalid entry block '{}'", get_string(entry)))),
+            Some(a) => a,
+        };
+        match entry_block.as_ref().borrow().deref() {
+            GlobalType::Ob(ob) => if ob.get_kind() != ObKind::Main {
+                return Err(error!(format!("{} is a {:?} OB, it can not be the entry", get_string(entry), ob.get_kind())))
+            },
+            _ => return Err(error!(format!("{} is not an OB block!", get_string(entry)))),
+        }
+
+        if let Err(e) = self
+            .run_scheduled_obs(entry)
+            .and_then(|_| self.execute_ob(&entry_block))
+        {
+            self.channel.push_cycle_stack();
+            self.channel.reset_cycle_stack();
+            return Err(e);
         }
 
+        self.registry.forces.apply(self.channel)?;
+        self.registry.floats.end_cycle(self.channel);
+        self.registry.overflows.end_cycle();
+
         self.channel.add_message(&Purple.paint("--- End of Cycle ---").to_string());
         curr_section
             .borrow_mut()
@@ -79,6 +145,10 @@ impl<'a> Simulation<'a> {
             .borrow_mut()
             .reset_temp(self.channel)?;
 
+        self.registry
+            .clock
+            .end_cycle(Duration::from_millis(self.params.cycleTime));
+
         let mut unit_tests_done = false;
         match self.params.stopOn {
             StopOn::Infinite => {}
@@ -96,7 +166,7 @@ impl<'a> Simulation<'a> {
             }
         }
         
-        if !unit_tests_done && StopOn::UnitTestsPassed == (*CONTAINER_PARAMS.lock().unwrap()).stopOn {
+        if !unit_tests_done && StopOn::UnitTestsPassed == self.params.stopOn {
             self.channel.add_message(&format!(
                 "Simulation stopped: {}", &Blue.paint("All unit tests reached"),
             ));
@@ -0,0 +1,95 @@
+use bimap::BiMap;
+use core::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+/// Id counters, interned strings and run flag of a single Container.
+///
+/// Every Container owns its state and enters it while it parses or runs, so the
+/// free functions of the kernel (get_id, get_string ...) always read the state of
+/// the Container currently executing. Code running outside of any Container
+/// (e.g. unit tests building a Kernel by hand) uses a default state per thread.
+pub struct ContainerState {
+    counter: Cell<usize>,
+    backup_counter: Cell<usize>,
+    strings: RefCell<BiMap<String, usize>>,
+    is_running: Cell<bool>,
+}
+
+impl Default for ContainerState {
+    fn default() -> Self {
+        Self {
+            counter: Cell::new(1),
+            backup_counter: Cell::new(1),
+            strings: RefCell::new(BiMap::new()),
+            is_running: Cell::new(false),
+        }
+    }
+}
+
+thread_local! {
+    static CURRENT_STATE: RefCell<Rc<ContainerState>> = RefCell::new(Rc::new(ContainerState::default()));
+}
+
+/// Runs a closure with the state of the Container currently executing.
+pub fn with_state<T>(f: impl FnOnce(&ContainerState) -> T) -> T {
+    CURRENT_STATE.with(|state| f(&state.borrow()))
+}
+
+/// Restores the previously entered state when dropped.
+pub struct StateGuard(Option<Rc<ContainerState>>);
+
+impl Drop for StateGuard {
+    fn drop(&mut self) {
+        if let Some(previous) = self.0.take() {
+            CURRENT_STATE.with(|state| state.replace(previous));
+        }
+    }
+}
+
+impl ContainerState {
+    pub fn enter(self: &Rc<Self>) -> StateGuard {
+        StateGuard(Some(CURRENT_STATE.with(|state| state.replace(self.clone()))))
+    }
+
+    pub fn next_id(&self) -> usize {
+        let id = self.counter.get();
+        self.counter.set(id + 1);
+        id
+    }
+
+    pub fn reset_counter_to_backup(&self) {
+        self.counter.set(self.backup_counter.get());
+    }
+
+    pub fn reset_counter_to_default(&self) {
+        self.counter.set(1);
+    }
+
+    pub fn set_counter_backup(&self) {
+        self.backup_counter.set(self.counter.get());
+    }
+
+    pub fn get_or_insert_string(&self, string: &String) -> usize {
+        let existing = self.strings.borrow().get_by_left(string).copied();
+        match existing {
+            Some(id) => id,
+            None => {
+                let id = self.next_id();
+                self.strings.borrow_mut().insert(string.clone(), id);
+                id
+            }
+        }
+    }
+
+    pub fn get_string(&self, id: usize) -> Option<String> {
+        self.strings.borrow().get_by_right(&id).cloned()
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.is_running.get()
+    }
+
+    pub fn set_running(&self, value: bool) {
+        self.is_running.set(value)
+    }
+}
@@ -21,6 +21,7 @@ use crate::kernel::plc::types::primitives::integers::plc_integer::PlcInteger;
 use crate::kernel::plc::types::primitives::string::plc_string::PlcString;
 use crate::kernel::plc::types::primitives::timers::plc_time::PlcTime;
 use crate::kernel::plc::types::primitives::tod::plc_tod::PlcTod;
+use crate::kernel::plc::types::primitives::date::plc_date::PlcDate;
 use crate::kernel::plc::types::primitives::traits::family_traits::{IsFamily, WithTypeFamily, WithRefFamily, WithMutFamily};
 use crate::kernel::plc::types::primitives::traits::primitive_traits::{Primitive};
 use crate::kernel::plc::types::primitives::traits::meta_data::{MetaData, HeapOrStatic, MaybeHeapOrStatic};
@@ -11,6 +11,7 @@ use crate::kernel::plc::types::primitives::timers::plc_time::PlcTime;
 use crate::kernel::plc::types::primitives::string::_string::plcstr;
 use crate::kernel::plc::types::primitives::string::wstring::plcwstr;
 use crate::kernel::plc::types::primitives::tod::plc_tod::PlcTod;
+use crate::kernel::plc::types::primitives::date::plc_date::PlcDate;
 use crate::kernel::plc::types::complex::array::PlcArray;
 use crate::kernel::plc::types::complex::r#struct::PlcStruct;
 use crate::kernel::plc::types::complex::instance::fb_instance::FbInstance;
@@ -119,7 +120,8 @@ impl_constant_type!(
         PlcBinary,
         PlcTime,
         PlcString,
-        PlcTod
+        PlcTod,
+        PlcDate
     },
     // forbid
     [PlcStruct, PlcArray, FbInstance]
@@ -19,6 +19,7 @@ use crate::kernel::plc::types::primitives::string::plc_string::PlcString;
 use crate::kernel::plc::types::primitives::string::wchar::wchar;
 use crate::kernel::plc::types::primitives::timers::plc_time::PlcTime;
 use crate::kernel::plc::types::primitives::tod::plc_tod::PlcTod;
+use crate::kernel::plc::types::primitives::date::plc_date::PlcDate;
 use crate::kernel::arch::local::r#type::{IntoLocalType, LocalType};
 use camelpaste::paste;
 use fixedstr::str256;
@@ -35,7 +36,8 @@ use crate::kernel::registry::Kernel;
 
 pub struct LocalPointer {
     inner: Rc<RefCell<LocalType>>,
-    read_only: bool
+    read_only: bool,
+    retain: bool
 }
 
 pub struct LocalPointerAndPath(pub (LocalPointer, Vec<usize>));
@@ -44,7 +46,8 @@ impl Clone for LocalPointer {
     fn clone(&self) -> Self {
         Self {
             inner: Rc::clone(&self.inner),
-            read_only: self.read_only
+            read_only: self.read_only,
+            retain: self.retain
         }
     }
 }
@@ -59,7 +62,8 @@ impl From<LocalType> for LocalPointer {
     fn from(value: LocalType) -> Self {
         Self {
             inner: Rc::new(RefCell::new(value)),
-            read_only: false
+            read_only: false,
+            retain: false
         }
     }
 }
@@ -170,7 +174,8 @@ impl LocalPointer {
     pub fn new(a_type: LocalType) -> Self {
         Self {
             inner: Rc::new(RefCell::new(a_type)),
-            read_only: false
+            read_only: false,
+            retain: false
         }
     }
 
@@ -182,6 +187,15 @@ impl LocalPointer {
         self.read_only
     }
 
+    /// Retained variables keep their value through a warm restart
+    pub fn set_retain(&mut self, value: bool) {
+        self.retain = value
+    }
+
+    pub fn is_retain(&self) -> bool {
+        self.retain
+    }
+
     pub fn replace_pointer(&mut self, other: &LocalPointer) {
         self.inner = Rc::clone(&other.inner);
         self.read_only = other.read_only;
@@ -365,7 +379,8 @@ impl_local_pointer!(
     PlcBinary,
     PlcTime,
     PlcString,
-    PlcTod +
+    PlcTod,
+    PlcDate +
 
     PlcStruct,
     PlcArray,
@@ -6,6 +6,10 @@ use crate::kernel::plc::types::complex::instance::fb_instance::FbInstance;
 use crate::kernel::plc::types::complex::r#struct::PlcStruct;
 use crate::kernel::plc::types::primitives::binaries::plc_binary::PlcBinary;
 use crate::kernel::plc::types::primitives::boolean::plc_bool::PlcBool;
+use crate::kernel::plc::types::primitives::boolean::bool::Bool;
+use crate::kernel::plc::types::primitives::binaries::byte::Byte;
+use crate::kernel::plc::types::primitives::binaries::word::Word;
+use crate::kernel::plc::types::primitives::binaries::dword::DWord;
 
 use crate::kernel::plc::types::primitives::floats::plc_float::PlcFloat;
 use crate::kernel::plc::types::primitives::integers::plc_integer::PlcInteger;
@@ -13,6 +17,7 @@ use crate::kernel::plc::types::primitives::string::plc_string::PlcString;
 use crate::kernel::plc::types::primitives::string::wchar::wchar;
 use crate::kernel::plc::types::primitives::timers::plc_time::PlcTime;
 use crate::kernel::plc::types::primitives::tod::plc_tod::PlcTod;
+use crate::kernel::plc::types::primitives::date::plc_date::PlcDate;
 use crate::kernel::arch::constant::r#type::ConstantType;
 use camelpaste::paste;
 use crate::kernel::plc::types::primitives::string::_string::plcstr;
@@ -20,7 +25,7 @@ use crate::kernel::plc::types::primitives::string::wstring::plcwstr;
 use std::borrow::Cow;
 use crate::kernel::registry::Kernel;
 use crate::kernel::plc::types::primitives::traits::family_traits::{IsFamily, WithRefFamily, WithMutFamily, WithTypeFamily};
-use crate::kernel::plc::types::primitives::traits::primitive_traits::{AsMutPrimitive, Primitive};
+use crate::kernel::plc::types::primitives::traits::primitive_traits::{AsMutPrimitive, Primitive, PrimitiveTrait};
 use crate::kernel::plc::types::primitives::traits::meta_data::{MetaData, SetMetaData};
 use crate::kernel::plc::types::primitives::traits::primitive_traits::ToggleMonitor;
 use crate::error;
@@ -35,6 +40,7 @@ impl From<ConstantType> for LocalType {
             ConstantType::PlcTime(a) => Self::PlcTime(a),
             ConstantType::PlcString(a) => Self::PlcString(a),
             ConstantType::PlcTod(a) => Self::PlcTod(a),
+            ConstantType::PlcDate(a) => Self::PlcDate(a),
         }
     }
 }
@@ -46,7 +52,14 @@ pub trait IntoLocalType {
 
 impl IntoLocalType for LocalType {
     fn transform(&self) -> Result<LocalType, Stop> {
-        Ok(self.clone())
+        // An access aliases its variable, a new value is created instead
+        Ok(match self {
+            LocalType::PlcBool(PlcBool::BitAccess(_)) => LocalType::PlcBool(PlcBool::Bool(Bool::new_default(0))),
+            LocalType::PlcBinary(PlcBinary::ByteAccess(_)) => LocalType::PlcBinary(PlcBinary::Byte(Byte::new_default(0))),
+            LocalType::PlcBinary(PlcBinary::WordAccess(_)) => LocalType::PlcBinary(PlcBinary::Word(Word::new_default(0))),
+            LocalType::PlcBinary(PlcBinary::DWordAccess(_)) => LocalType::PlcBinary(PlcBinary::DWord(DWord::new_default(0))),
+            _ => self.clone(),
+        })
     }
 }
 
@@ -151,7 +164,8 @@ impl_local_types!(
     PlcBinary,
     PlcTime,
     PlcString,
-    PlcTod +
+    PlcTod,
+    PlcDate +
 
     PlcStruct,
     PlcArray,
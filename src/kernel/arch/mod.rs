@@ -3,3 +3,4 @@ pub mod global;
 pub mod local;
 pub mod any;
 pub mod reset;
+pub mod snapshot;
@@ -0,0 +1,371 @@
+use crate::container::broadcast::broadcast::Broadcast;
+use crate::container::error::error::Stop;
+use crate::error;
+use crate::kernel::arch::global::r#type::GlobalType;
+use crate::kernel::arch::local::pointer::LocalPointer;
+use crate::kernel::plc::interface::section::Section;
+use crate::kernel::plc::interface::section_interface::SectionInterface;
+use crate::kernel::plc::interface::struct_interface::StructInterface;
+use crate::kernel::plc::types::complex::instance::public::PublicInstanceAccessors;
+use crate::kernel::plc::types::primitives::string::_string::plcstr;
+use crate::kernel::plc::types::primitives::string::wchar::wchar;
+use crate::kernel::plc::types::primitives::string::wstring::plcwstr;
+use crate::kernel::plc::types::primitives::traits::family_traits::IsFamily;
+use crate::kernel::plc::types::primitives::traits::primitive_traits::{AsMutPrimitive, Primitive};
+use crate::kernel::registry::{get_string, Kernel};
+use camelpaste::paste;
+use core::cell::RefCell;
+use core::ops::{Deref, DerefMut};
+use core::str::FromStr;
+use core::time::Duration;
+use fixedstr::str256;
+use serde_json::{json, Map, Value};
+use std::collections::HashMap;
+use std::rc::Rc;
+
+/// Hidden state of an operation, kept outside of the plc memory (timers, edge detections ...).
+pub trait InternalState {
+    fn save(&self) -> Value;
+    fn restore(&self, value: &Value) -> Result<(), Stop>;
+    fn reset(&self);
+}
+
+/// Stored bit of the edge detections
+impl InternalState for RefCell<bool> {
+    fn save(&self) -> Value {
+        Value::from(*self.borrow().deref())
+    }
+
+    fn restore(&self, value: &Value) -> Result<(), Stop> {
+        *self.borrow_mut().deref_mut() = value
+            .as_bool()
+            .ok_or_else(|| error!(format!("Expected a bool, got {}", value)))?;
+        Ok(())
+    }
+
+    fn reset(&self) {
+        *self.borrow_mut().deref_mut() = false;
+    }
+}
+
+/// Internal states registered while building the operations, in build order.
+#[derive(Default)]
+pub struct InternalStates(Vec<(u32, Rc<dyn InternalState>)>);
+
+impl InternalStates {
+    pub fn push(&mut self, id: u32, state: Rc<dyn InternalState>) {
+        self.0.push((id, state));
+    }
+
+    pub fn clear(&mut self) {
+        self.0.clear();
+    }
+
+    pub fn reset(&self) {
+        self.0.iter().for_each(|(_, state)| state.reset());
+    }
+
+    /// States grouped by operation id. An operation built several times (e.g. a timer in a
+    /// Fb called by multiple instances) has one entry per build.
+    fn save(&self) -> Map<String, Value> {
+        let mut states = Map::new();
+        self.0.iter().for_each(|(id, state)| {
+            if let Value::Array(a) = states
+                .entry(id.to_string())
+                .or_insert_with(|| Value::Array(vec![]))
+            {
+                a.push(state.save())
+            }
+        });
+        states
+    }
+
+    fn restore(&self, json: &Map<String, Value>) -> Result<(), Stop> {
+        let mut index: HashMap<u32, usize> = HashMap::new();
+        self.0.iter().try_for_each(|(id, state)| {
+            let n = index.entry(*id).or_insert(0);
+            let value = json
+                .get(&id.to_string())
+                .and_then(|a| a.as_array())
+                .and_then(|a| a.get(*n));
+            *n += 1;
+            match value {
+                Some(value) => state
+                    .restore(value)
+                    .map_err(|e| e.add_sim_trace("Restore internal state").add_id(*id)),
+                None => Ok(()),
+            }
+        })
+    }
+}
+
+macro_rules! impl_primitive_snapshot {
+    ($({ $primitive: ident, $save: expr, $restore: expr }),+) => {
+        paste! {
+            fn save_primitive(pointer: &LocalPointer, channel: &Broadcast) -> Result<Value, Stop> {
+                $(
+                    if pointer.[<is_$primitive>]() {
+                        let save: fn($primitive) -> Value = $save;
+                        return Ok(save(pointer.[<as_$primitive>](channel)?));
+                    }
+                )+
+                Err(error!(format!("Can not save {}", pointer)))
+            }
+
+            fn restore_primitive(pointer: &mut LocalPointer, value: &Value, channel: &Broadcast) -> Result<(), Stop> {
+                $(
+                    if pointer.[<is_$primitive>]() {
+                        let restore: fn(&Value) -> Option<$primitive> = $restore;
+                        let native = restore(value)
+                            .ok_or_else(|| error!(format!("Invalid value {} for {}", value, pointer)))?;
+                        return pointer.[<set_$primitive>](native, channel);
+                    }
+                )+
+                Err(error!(format!("Can not restore {}", pointer)))
+            }
+        }
+    };
+}
+
+fn as_char(value: &Value) -> Option<char> {
+    let mut chars = value.as_str()?.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Some(c),
+        _ => None,
+    }
+}
+
+/// NaN and infinities have no JSON number, they are saved as "NaN", "Inf" and "-Inf"
+fn save_float(value: f64) -> Value {
+    match value {
+        v if v.is_nan() => Value::from("NaN"),
+        f64::INFINITY => Value::from("Inf"),
+        f64::NEG_INFINITY => Value::from("-Inf"),
+        v => Value::from(v),
+    }
+}
+
+/// Null is read as NaN for the snapshots saved before the special values were named
+fn restore_float(value: &Value) -> Option<f64> {
+    match value {
+        Value::Null => Some(f64::NAN),
+        Value::String(v) if v == "NaN" => Some(f64::NAN),
+        Value::String(v) if v == "Inf" => Some(f64::INFINITY),
+        Value::String(v) if v == "-Inf" => Some(f64::NEG_INFINITY),
+        v => v.as_f64(),
+    }
+}
+
+impl_primitive_snapshot!(
+    { bool, Value::from, |v| v.as_bool() },
+    { u8, Value::from, |v| v.as_u64().and_then(|v| v.try_into().ok()) },
+    { u16, Value::from, |v| v.as_u64().and_then(|v| v.try_into().ok()) },
+    { u32, Value::from, |v| v.as_u64().and_then(|v| v.try_into().ok()) },
+    { u64, Value::from, |v| v.as_u64() },
+    { i8, Value::from, |v| v.as_i64().and_then(|v| v.try_into().ok()) },
+    { i16, Value::from, |v| v.as_i64().and_then(|v| v.try_into().ok()) },
+    { i32, Value::from, |v| v.as_i64().and_then(|v| v.try_into().ok()) },
+    { i64, Value::from, |v| v.as_i64() },
+    { f32, |v| save_float(v as f64), |v| restore_float(v).map(|v| v as f32) },
+    { f64, save_float, restore_float },
+    { plcstr, |v| Value::from(v.0.as_str()), |v| str256::from_str(v.as_str()?).ok().map(plcstr) },
+    { char, |v| Value::from(v.to_string()), as_char },
+    { plcwstr, |v| Value::from(v.0.as_str()), |v| str256::from_str(v.as_str()?).ok().map(plcwstr) },
+    { wchar, |v| Value::from(v.to_string()), as_char }
+);
+
+/// Sections holding a value that survives the cycle
+fn is_persistent(section: &Section) -> bool {
+    matches!(section, Section::Input | Section::Output | Section::InOut | Section::Static)
+}
+
+/// Saves the value of a pointer. With `retained_only`, only the retained variables are saved
+/// and None is returned when nothing below this pointer is retained.
+fn save_pointer(pointer: &LocalPointer, retained_only: bool, channel: &Broadcast) -> Result<Option<Value>, Stop> {
+    let retained_only = retained_only && !pointer.is_retain();
+    if pointer.is_plc_struct() {
+        save_struct(pointer.as_plc_struct()?.get_interface(), retained_only, channel)
+    } else if pointer.is_fb_instance() {
+        save_sections(pointer.as_fb_instance()?.get_interface(), retained_only, channel)
+    } else if retained_only {
+        Ok(None)
+    } else if pointer.is_plc_array() {
+        pointer
+            .as_plc_array()?
+            .get_interface()
+            .iter()
+            .map(|a| save_pointer(a, false, channel).map(Option::unwrap_or_default))
+            .collect::<Result<Vec<Value>, Stop>>()
+            .map(|a| Some(Value::Array(a)))
+    } else {
+        save_primitive(pointer, channel).map(Some)
+    }
+}
+
+fn save_struct(interface: &StructInterface, retained_only: bool, channel: &Broadcast) -> Result<Option<Value>, Stop> {
+    let mut values = Map::new();
+    interface.iter().try_for_each(|(name, pointer)| {
+        if let Some(value) = save_pointer(pointer, retained_only, channel)? {
+            values.insert(get_string(*name), value);
+        }
+        Ok::<(), Stop>(())
+    })?;
+    Ok(match retained_only && values.is_empty() {
+        true => None,
+        false => Some(Value::Object(values)),
+    })
+}
+
+fn save_sections(interface: &SectionInterface, retained_only: bool, channel: &Broadcast) -> Result<Option<Value>, Stop> {
+    let mut values = Map::new();
+    interface
+        .iter()
+        .filter(|(section, _)| is_persistent(section))
+        .try_for_each(|(_, fields)| {
+            if let Some(Value::Object(mut a)) = save_struct(fields, retained_only, channel)? {
+                values.append(&mut a);
+            }
+            Ok::<(), Stop>(())
+        })?;
+    Ok(match retained_only && values.is_empty() {
+        true => None,
+        false => Some(Value::Object(values)),
+    })
+}
+
+fn save_memory(kernel: &Kernel, retained_only: bool, channel: &Broadcast) -> Result<Map<String, Value>, Stop> {
+    let mut memory = Map::new();
+    kernel
+        .provider
+        .iter()
+        .chain(kernel.program.iter())
+        .try_for_each(|(name, pointer)| {
+            if let GlobalType::Db(db) = pointer.as_ref().borrow().deref() {
+                let name = get_string(*name);
+                let values = save_sections(db.get_interface(), retained_only, channel)
+                    .map_err(|e| e.add_sim_trace(&format!("Snapshot '{}'", name)))?;
+                if let Some(values) = values {
+                    memory.insert(name, values);
+                }
+            }
+            Ok::<(), Stop>(())
+        })?;
+    Ok(memory)
+}
+
+fn restore_pointer(pointer: &LocalPointer, value: &Value, channel: &Broadcast) -> Result<(), Stop> {
+    if pointer.is_read_only() {
+        return Ok(());
+    }
+
+    if pointer.is_plc_struct() {
+        restore_struct(pointer.as_plc_struct()?.get_interface(), value, channel)
+    } else if pointer.is_fb_instance() {
+        restore_sections(pointer.as_fb_instance()?.get_interface(), value, channel)
+    } else if pointer.is_plc_array() {
+        let values = value
+            .as_array()
+            .ok_or_else(|| error!(format!("Expected an array, got {}", value)))?;
+        pointer
+            .as_plc_array()?
+            .get_interface()
+            .iter()
+            .zip(values)
+            .try_for_each(|(a, value)| restore_pointer(a, value, channel))
+    } else {
+        restore_primitive(&mut pointer.clone(), value, channel)
+    }
+}
+
+fn restore_struct(interface: &StructInterface, value: &Value, channel: &Broadcast) -> Result<(), Stop> {
+    let values = value
+        .as_object()
+        .ok_or_else(|| error!(format!("Expected an object, got {}", value)))?;
+    interface.iter().try_for_each(|(name, pointer)| {
+        let name = get_string(*name);
+        match values.get(&name) {
+            Some(value) => restore_pointer(pointer, value, channel)
+                .map_err(|e| e.add_sim_trace(&format!("Restore '{}'", name))),
+            None => Ok(()),
+        }
+    })
+}
+
+fn restore_sections(interface: &SectionInterface, value: &Value, channel: &Broadcast) -> Result<(), Stop> {
+    interface
+        .iter()
+        .filter(|(section, _)| is_persistent(section))
+        .try_for_each(|(_, fields)| restore_struct(fields, value, channel))
+}
+
+/// Serializes the memory of every Db, the internal states of the operations, the state of the
+/// scheduled Obs, the simulated and system times and the cycle number.
+pub fn take_snapshot(kernel: &Kernel, channel: &Broadcast) -> Result<Value, Stop> {
+    let memory = save_memory(kernel, false, channel)?;
+    let (system_origin, time_zone) = kernel.clock.system_clock();
+
+    Ok(json!({
+        "cycle": channel.get_cycle(),
+        "time": kernel.clock.now().as_nanos() as u64,
+        "system_origin": system_origin as i64,
+        "time_zone": time_zone as i64,
+        "memory": memory,
+        "internals": kernel.internal_states.borrow().save(),
+        "scheduler": kernel.scheduler.save(),
+    }))
+}
+
+/// Serializes the retained variables only, in the snapshot format.
+pub fn take_retained_snapshot(kernel: &Kernel, channel: &Broadcast) -> Result<Value, Stop> {
+    Ok(json!({ "memory": save_memory(kernel, true, channel)? }))
+}
+
+/// Writes a snapshot back into the kernel. Dbs and variables missing from the snapshot keep
+/// their current value.
+pub fn restore_snapshot(kernel: &Kernel, snapshot: &Value, channel: &Broadcast) -> Result<(), Stop> {
+    let snapshot = snapshot
+        .as_object()
+        .ok_or_else(|| error!(format!("Invalid snapshot"), format!("Restore snapshot")))?;
+
+    if let Some(memory) = snapshot.get("memory").and_then(|a| a.as_object()) {
+        kernel
+            .provider
+            .iter()
+            .chain(kernel.program.iter())
+            .try_for_each(|(name, pointer)| {
+                let name = get_string(*name);
+                match (pointer.as_ref().borrow().deref(), memory.get(&name)) {
+                    (GlobalType::Db(db), Some(value)) => restore_sections(db.get_interface(), value, channel)
+                        .map_err(|e| e.add_sim_trace(&format!("Restore snapshot of '{}'", name))),
+                    _ => Ok(()),
+                }
+            })?;
+    }
+
+    if let Some(internals) = snapshot.get("internals").and_then(|a| a.as_object()) {
+        kernel.internal_states.borrow().restore(internals)?;
+    }
+
+    if let Some(scheduler) = snapshot.get("scheduler") {
+        kernel.scheduler
+            .restore(scheduler)
+            .map_err(|e| e.add_sim_trace("Restore scheduler"))?;
+    }
+
+    if let Some(time) = snapshot.get("time").and_then(|a| a.as_u64()) {
+        kernel.clock.set(Duration::from_nanos(time));
+    }
+
+    // The system time may have been written by WR_SYS_T
+    if let (Some(system_origin), Some(time_zone)) = (
+        snapshot.get("system_origin").and_then(|a| a.as_i64()),
+        snapshot.get("time_zone").and_then(|a| a.as_i64()),
+    ) {
+        kernel.clock.set_system_clock(system_origin as i128, time_zone as i128);
+    }
+
+    if let Some(cycle) = snapshot.get("cycle").and_then(|a| a.as_u64()) {
+        channel.set_cycle(cycle);
+    }
+    Ok(())
+}
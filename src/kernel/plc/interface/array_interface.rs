@@ -1,5 +1,4 @@
 ﻿use core::fmt::{Display, Formatter};
-use core::num::ParseIntError;
 use core::ops::{Deref, DerefMut};
 use serde::{Serialize, Serializer};
 use serde::ser::SerializeSeq;
@@ -10,15 +9,41 @@ use crate::kernel::plc::types::primitives::traits::primitive_traits::RawMut;
 use crate::kernel::arch::local::pointer::{LocalPointer, LocalPointerAndPath};
 use crate::kernel::arch::local::r#type::LocalType;
 use crate::kernel::registry::{get_string, Kernel};
+use crate::container::error::error::Stop;
+use crate::error;
 
-pub struct ArrayInterface(Vec<LocalPointer>);
+/// Lower and upper bound of a dimension, both included.
+pub type ArrayBounds = (i64, i64);
+
+/// Declaration of an array with the given bounds, e.g. `Array[1..10, 0..3]`.
+fn declare(bounds: &[ArrayBounds]) -> String {
+    format!("Array[{}]", bounds.iter().map(|(lower, upper)| format!("{}..{}", lower, upper)).collect::<Vec<String>>().join(", "))
+}
+
+/// Number of elements of a dimension, None when it does not fit an i64.
+fn dimension_length((lower, upper): &ArrayBounds) -> Option<i64> {
+    upper.checked_sub(*lower)?.checked_add(1)
+}
+
+/// Number of elements of an array with the given bounds.
+pub fn array_length(bounds: &[ArrayBounds]) -> Result<usize, Stop> {
+    bounds
+        .iter()
+        .try_fold(1_usize, |length, bound| {
+            dimension_length(bound)
+                .and_then(|a| usize::try_from(a).ok())
+                .and_then(|a| a.checked_mul(length))
+        })
+        .ok_or_else(|| error!(format!("Invalid array: {} has too many elements", declare(bounds))))
+}
+
+/// Elements of an array in row-major order, with the declared bounds of each dimension.
+pub struct ArrayInterface(Vec<LocalPointer>, Vec<ArrayBounds>);
 
 impl Display for ArrayInterface {
     fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
-        let mut i = -1;
-        self.0.iter().try_for_each(|member| {
-            i += 1;
-            writeln!(f, "\t [{}]: {}", i, member)
+        self.0.iter().enumerate().try_for_each(|(i, member)| {
+            writeln!(f, "\t [{}]: {}", self.index_at(i), member)
         })
     }
 }
@@ -29,13 +54,15 @@ impl Clone for ArrayInterface {
             .map(|pointer| {
                 LocalPointer::new(pointer.as_ref().borrow().deref().clone())
             })
-            .collect())
+            .collect(), self.1.clone())
     }
 }
 
+/// Single dimension indexed from 0.
 impl From<Vec<LocalPointer>> for ArrayInterface {
     fn from(value: Vec<LocalPointer>) -> Self {
-        Self(value)
+        let bounds = vec![(0, value.len() as i64 - 1)];
+        Self(value, bounds)
     }
 }
 
@@ -60,7 +87,8 @@ impl Serialize for ArrayInterface {
 impl ArrayInterface {
     pub fn get_raw_pointers(&self) -> Vec<*mut dyn RawMut> {
         self.iter()
-            .fold(vec![], |_all, p| p.get_raw_pointers())
+            .flat_map(|p| p.get_raw_pointers())
+            .collect()
     }
 
     pub fn get_pointers_with_path(&self, full_path: &[usize], start_with: &[usize]) -> Vec<LocalPointerAndPath> {
@@ -85,33 +113,80 @@ impl ArrayInterface {
     
     pub fn share(&self) -> Self {
         Self(self.0.iter().cloned()
-            .collect())
+            .collect(), self.1.clone())
     }
     pub fn len(&self) -> usize {
         self.0.len()
     }
     
     pub fn new() -> Self {
-        Self(Vec::new())
+        Self(Vec::new(), vec![])
     }
-    
+
+    pub fn with_bounds(values: Vec<LocalPointer>, bounds: Vec<ArrayBounds>) -> Self {
+        Self(values, bounds)
+    }
+
     pub fn get(&self, index: usize) -> Option<&LocalPointer> {
         self.0.get(index)
     }
 
+    pub fn get_bounds(&self) -> &[ArrayBounds] {
+        &self.1
+    }
+
+    /// Declaration of the array, e.g. `Array[1..10, 0..3]`.
+    pub fn declaration(&self) -> String {
+        declare(&self.1)
+    }
+
+    /// Declared index of the element at `position` in row-major order, e.g. `1,0`.
+    fn index_at(&self, mut position: usize) -> String {
+        let mut index = vec![];
+        // The length of the array has been checked when it was built, see `array_length`
+        for bounds in self.1.iter().rev() {
+            let length = dimension_length(bounds).unwrap_or(i64::MAX) as usize;
+            index.push((bounds.0 + (position % length) as i64).to_string());
+            position /= length;
+        }
+        index.reverse();
+        index.join(",")
+    }
+
+    /// Position in row-major order of a declared index, either `[2]`, `2` or `[2,1]` for two dimensions.
+    pub fn position_of(&self, key: &str) -> Result<usize, Stop> {
+        let indexes = key
+            .trim_start_matches('[')
+            .trim_end_matches(']')
+            .split(',')
+            .map(|a| a.trim().parse::<i64>())
+            .collect::<Result<Vec<i64>, _>>()
+            .map_err(|_| error!(format!("Invalid index {} of {}", key, self.declaration())))?;
+
+        if indexes.len() != self.1.len() {
+            return Err(error!(format!("Index [{}] does not match the {} dimension(s) of {}",
+                indexes.iter().map(|a| a.to_string()).collect::<Vec<String>>().join(","), self.1.len(), self.declaration())));
+        }
+
+        let invalid = |reason: &str| error!(format!("Index [{}] {} {}",
+            indexes.iter().map(|a| a.to_string()).collect::<Vec<String>>().join(","), reason, self.declaration()));
+        indexes.iter().zip(&self.1).try_fold(0_usize, |position, (index, bounds @ (lower, upper))| {
+            match (lower..=upper).contains(&index) {
+                true => dimension_length(bounds)
+                    .and_then(|length| position.checked_mul(length as usize))
+                    .and_then(|position| position.checked_add(index.checked_sub(*lower)? as usize))
+                    .ok_or_else(|| invalid("overflows the length of")),
+                false => Err(invalid("is out of the bounds of")),
+            }
+        })
+    }
+
     pub fn try_replace_pointer_nested(&mut self, path: &[usize], other: &LocalPointer) -> Option<LocalPointer> {
         if path.is_empty() {
             return None;
         }
 
-        let key = get_string(path[0]);
-        let key = match key
-            .replace('[', "")
-            .replace(']', "")
-            .parse::<usize>() {
-            Ok(a) => a,
-            Err(_) => return None
-        };
+        let key = self.position_of(&get_string(path[0])).ok()?;
 
         let next_path = &path[1..];
 
@@ -121,9 +196,9 @@ impl ArrayInterface {
                 Some(f.clone())
             } else {
                 match f.as_ref().borrow_mut().deref_mut() {
-                    LocalType::PlcStruct(_struct) => _struct.try_replace_pointer_nested(path, other),
-                    LocalType::PlcArray(array) => array.try_replace_pointer_nested(path, other),
-                    LocalType::FbInstance(instance) => instance.try_replace_pointer_nested(path, other),
+                    LocalType::PlcStruct(_struct) => _struct.try_replace_pointer_nested(next_path, other),
+                    LocalType::PlcArray(array) => array.try_replace_pointer_nested(next_path, other),
+                    LocalType::FbInstance(instance) => instance.try_replace_pointer_nested(next_path, other),
                     _ => None
                 }
             }
@@ -135,19 +210,12 @@ impl ArrayInterface {
             return None;
         }
 
-        let key = get_string(path[0]);
-        let key = match key
-            .replace('[', "")
-            .replace(']', "")
-            .parse::<usize>() {
-            Ok(a) => a,
-            Err(_) => return None
-        };
+        let key = self.position_of(&get_string(path[0])).ok()?;
 
         let next_path = &path[1..];
 
         self.0.get(key).and_then(|f| {
-            if next_path.is_empty() || f.is_primitive() {
+            if next_path.is_empty() || (f.is_primitive() && !f.is_plc_date()) {
                 Some(f.clone())
             } else {
                 match f.as_ref().borrow().deref() {
@@ -84,7 +84,8 @@ impl ToggleMonitor for SectionInterface {
 impl SectionInterface {
     pub fn get_raw_pointers(&self) -> Vec<*mut dyn RawMut> {
         self.iter()
-            .fold(vec![], |_all, p| p.1.get_raw_pointers())
+            .flat_map(|p| p.1.get_raw_pointers())
+            .collect()
     }
 
     pub fn get_pointers_with_path(&self, full_path: &[usize], start_with: &[usize]) -> Vec<LocalPointerAndPath> {
@@ -195,7 +196,7 @@ impl SectionInterface {
         self.0.0.iter().find_map(|(_section, members)| {
             members.as_ref().get(key)
                 .and_then(|f| {
-                    if next_path.is_empty() || f.is_primitive() {
+                    if next_path.is_empty() || (f.is_primitive() && !f.is_plc_date()) {
                         Some(f.clone())
                     } else {
                         match f.as_ref().borrow().deref() {
@@ -85,7 +85,8 @@ impl StructInterface {
     
     pub fn get_raw_pointers(&self) -> Vec<*mut dyn RawMut> {
         self.iter()
-            .fold(vec![], |_all, p| p.1.get_raw_pointers())
+            .flat_map(|p| p.1.get_raw_pointers())
+            .collect()
     }
 
     pub fn get_pointers_with_path(&self, full_path: &[usize], start_with: &[usize]) -> Vec<LocalPointerAndPath> {
@@ -161,7 +162,7 @@ impl StructInterface {
         let next_path = &path[1..];
 
         self.0.get(key).and_then(|f| {
-            if next_path.is_empty() || f.is_primitive() {
+            if next_path.is_empty() || (f.is_primitive() && !f.is_plc_date()) {
                 Some(f.clone())
             } else {
                 match f.as_ref().borrow().deref() {
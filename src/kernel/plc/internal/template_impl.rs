@@ -17,7 +17,8 @@ use std::collections::HashMap;
 use core::ops::{Deref, DerefMut};
 use crate::parser::body::body::parse_json_target;
 use crate::container::broadcast::broadcast::Broadcast;
-use crate::kernel::rust::auto_set::box_set_auto;
+use crate::kernel::rust::auto_set::box_set_auto_enum;
+use crate::kernel::arch::any::any_type::AnyRefType;
 use crate::kernel::plc::types::complex::instance::public::PublicInstanceTrait;
 use crate::kernel::plc::interface::traits::InterfaceAccessors;
 use crate::kernel::plc::types::primitives::traits::meta_data::MaybeHeapOrStatic;
@@ -129,7 +130,7 @@ impl BuildJsonOperation for TemplateImpl {
                         .try_for_each(|(target_path, source)| {
                             let target = inner_memory.try_get_nested(&convert_string_path_to_usize(target_path))
                                 .ok_or_else(move || error!(format!("Could not find a valid reference in instance interface for path {:?}, Current interface: {}", &target_path, interface)))?;
-                            input_actions.push(box_set_auto(&target, &source.solve_to_ref(interface, None, Some(target.as_ref().borrow().deref().clone()), registry, channel)?, 0, registry)?);
+                            input_actions.push(box_set_auto_enum(&target, &source.solve_to_ref(interface, None, Some(target.as_ref().borrow().deref().clone()), registry, channel)?, 0, registry)?);
                             Ok(())
                         })
                 },
@@ -155,17 +156,18 @@ impl BuildJsonOperation for TemplateImpl {
                         .try_for_each(|(target_path, source)| {
                             let target = inner_memory.try_get_nested(&convert_string_path_to_usize(target_path))
                                 .ok_or_else(move || error!(format!("Could not find a valid reference in instance interface for path {:?}, Current interface: {}", &target_path, interface)))?;
-                            output_actions.push(box_set_auto(&source.solve_as_local_pointer(interface, None, registry, channel).unwrap(), &target, 0, registry)?);
+                            output_actions.push(box_set_auto_enum(&source.solve_as_local_pointer(interface, None, registry, channel).unwrap(), &AnyRefType::Local(target.clone()), 0, registry)?);
                             Ok(())
                         })
                 },
                 _ => Ok(())
             })?;
 
-        let operations = template_origin
-            .borrow_mut()
+        let mut origin = template_origin.borrow_mut();
+        let body = origin
             .deref_mut()
-            .get_body(registry, channel)?
+            .get_body(registry, channel)?;
+        let operations = registry.loops.isolated(|| body
             .iter()
             .map(|instruction| {
                 parse_json_target(instruction)?.solve_as_operation(
@@ -175,11 +177,12 @@ impl BuildJsonOperation for TemplateImpl {
                     channel
                 )
             })
-            .collect::<Result<Vec<RunTimeOperation>, Stop>>()
+            .collect::<Result<Vec<RunTimeOperation>, Stop>>())
             .map_err(|e: Stop| {
                 e.add_sim_trace("Build template -> body")
                     .add_id(self.id)
             })?;
+        drop(origin);
 
         let name = get_or_insert_global_string(&self.of);
         Ok(Box::new(Operation::new(
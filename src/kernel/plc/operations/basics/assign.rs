@@ -4,6 +4,7 @@ use crate::kernel::plc::interface::section_interface::SectionInterface;
 use crate::kernel::plc::internal::template_impl::TemplateMemory;
 use crate::kernel::plc::operations::operations::{BuildJsonOperation, NewJsonOperation, RunTimeOperation};
 use crate::kernel::rust::set::box_set_plc_primitive;
+use crate::kernel::rust::enumeration::box_set_plc_enum;
 use crate::kernel::registry::Kernel;
 use crate::container::error::error::Stop;
 use crate::{error, key_reader};
@@ -72,7 +73,12 @@ impl BuildJsonOperation for Assign {
         let a1 = self
             .assign
             .solve_as_local_pointer(interface, template, registry, channel)
-            .ok_or_else(move || error!(format!("Expected a valid reference, got {}", self.assign), "Build assign -> source".to_string()).add_id(self.id))?;
+            .ok_or_else(move || {
+                self.assign
+                    .explain_unsolved(interface, template, registry, channel)
+                    .unwrap_or_else(|| error!(format!("Expected a valid reference, got {}", self.assign), "Build assign -> source".to_string()))
+                    .add_id(self.id)
+            })?;
 
         if a1.is_read_only() {
             return Err(error!(format!("Attempt to assign a constant value"), "Build assign -> source".to_string(), Some(self.id)))
@@ -86,6 +92,9 @@ impl BuildJsonOperation for Assign {
                     .add_id(self.id)
             })?;
 
-        box_set_plc_primitive(&a1, &a2, self.id, false, registry)
+        match registry.get_enum_of(&a1) {
+            Some(plc_enum) => box_set_plc_enum(&a1, &a2, plc_enum, self.id, registry),
+            None => box_set_plc_primitive(&a1, &a2, self.id, false, registry),
+        }
     }
 }
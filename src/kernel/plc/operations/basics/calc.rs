@@ -14,6 +14,8 @@ use crate::kernel::rust::operations::box_mul_plc_primitive;
 use crate::kernel::rust::operations::box_rem_plc_primitive;
 use crate::kernel::rust::operations::box_sub_plc_primitive;
 use crate::kernel::arch::local::r#type::IntoLocalType;
+use crate::kernel::plc::types::primitives::traits::family_traits::IsFamily;
+use crate::kernel::rust::date::box_date_calc;
 
 pub struct Calc {
     calc: JsonTarget,
@@ -70,6 +72,10 @@ impl BuildJsonOperation for Calc {
         let o1 = self.calc.solve_to_ref(interface, template, None, registry, channel)?;
         let o2 = self.with.solve_to_ref(interface, template, Some(o1.transform()?), registry, channel)?;
 
+        if o1.is_plc_date() || o2.is_plc_date() {
+            return box_date_calc(&o1, &o2, self.operator.as_str(), self.id, registry);
+        }
+
         match self.operator.as_str() {
             "+" => box_add_plc_primitive(&o1, &o2, self.id, registry),
             "-" => box_sub_plc_primitive(&o1, &o2, self.id, registry),
@@ -14,12 +14,15 @@ use crate::kernel::plc::types::primitives::traits::family_traits::{IsFamily, Wit
 use crate::kernel::plc::types::primitives::traits::meta_data::{HeapOrStatic, MaybeHeapOrStatic};
 use crate::kernel::plc::types::primitives::traits::primitive_traits::PrimitiveTrait;
 use crate::kernel::registry::Kernel;
-use crate::kernel::rust::partial::box_ord_plc_primitive;
+use crate::kernel::rust::date::box_ord_plc_date;
+use crate::kernel::rust::enumeration::check_enum_operands;
+use crate::kernel::rust::partial::{box_ord_plc_primitive, BoxOrd};
 use crate::parser::body::body::parse_json_target;
 use crate::parser::body::json_target::JsonTarget;
 use crate::{error, key_reader};
 use serde_json::{Map, Value};
 use core::cell::RefCell;
+use core::cmp::Ordering;
 use core::ops::Deref;
 use std::rc::Rc;
 
@@ -129,6 +132,21 @@ pub fn get_cmp_targets(
     Ok((compare, with))
 }
 
+/// Dates and times compare whatever their type, other primitives by their native value.
+fn box_ord(
+    compare: &AnyRefType,
+    with: &AnyRefType,
+    id: u32,
+    registry: &Kernel,
+) -> Result<BoxOrd, Stop> {
+    check_enum_operands(compare, with, "comparison", registry).map_err(|e| e.add_id(id))?;
+    match compare.is_plc_date() || with.is_plc_date() {
+        true => box_ord_plc_date(compare, with, id, registry),
+        false => box_ord_plc_primitive(compare, with, id, registry),
+    }
+}
+
+/// Unordered values (a NaN operand) follow IEEE 754: every comparison is false except `<>`.
 pub fn box_cmp(
     id: u32,
     compare: &AnyRefType,
@@ -141,45 +159,45 @@ pub fn box_cmp(
 ) -> Result<impl Fn(&Broadcast) -> Result<bool, Stop>, Stop> {
     let op: Result<Box<dyn Fn(&Broadcast) -> Result<bool, Stop>>, Stop> = match operator {
         "=" => {
-            let cmp = box_ord_plc_primitive(compare, with, id, registry)
+            let cmp = box_ord(compare, with, id, registry)
                 .map_err(|e| e.add_sim_trace(&format!("Build compare -> compare operation")))?;
             Ok(Box::new(move |channel: &Broadcast| {
-                Ok(cmp(channel)?.unwrap().is_eq())
+                Ok(cmp(channel)?.is_some_and(Ordering::is_eq))
             }))
         }
         "<>" => {
-            let cmp = box_ord_plc_primitive(compare, with, id, registry)
+            let cmp = box_ord(compare, with, id, registry)
                 .map_err(|e| e.add_sim_trace(&format!("Build compare -> compare operation")))?;
             Ok(Box::new(move |channel: &Broadcast| {
-                Ok(cmp(channel)?.unwrap().is_ne())
+                Ok(cmp(channel)?.is_none_or(Ordering::is_ne))
             }))
         }
         "<" => {
-            let cmp = box_ord_plc_primitive(compare, with, id, registry)
+            let cmp = box_ord(compare, with, id, registry)
                 .map_err(|e| e.add_sim_trace(&format!("Build compare -> compare operation")))?;
             Ok(Box::new(move |channel: &Broadcast| {
-                Ok(cmp(channel)?.unwrap().is_lt())
+                Ok(cmp(channel)?.is_some_and(Ordering::is_lt))
             }))
         }
         ">" => {
-            let cmp = box_ord_plc_primitive(compare, with, id, registry)
+            let cmp = box_ord(compare, with, id, registry)
                 .map_err(|e| e.add_sim_trace(&format!("Build compare -> compare operation")))?;
             Ok(Box::new(move |channel: &Broadcast| {
-                Ok(cmp(channel)?.unwrap().is_gt())
+                Ok(cmp(channel)?.is_some_and(Ordering::is_gt))
             }))
         }
         "<=" => {
-            let cmp = box_ord_plc_primitive(compare, with, id, registry)
+            let cmp = box_ord(compare, with, id, registry)
                 .map_err(|e| e.add_sim_trace(&format!("Build compare -> compare operation")))?;
             Ok(Box::new(move |channel: &Broadcast| {
-                Ok(cmp(channel)?.unwrap().is_le())
+                Ok(cmp(channel)?.is_some_and(Ordering::is_le))
             }))
         }
         ">=" => {
-            let cmp = box_ord_plc_primitive(compare, with, id, registry)
+            let cmp = box_ord(compare, with, id, registry)
                 .map_err(|e| e.add_sim_trace(&format!("Build compare -> compare operation")))?;
             Ok(Box::new(move |channel: &Broadcast| {
-                Ok(cmp(channel)?.unwrap().is_ge())
+                Ok(cmp(channel)?.is_some_and(Ordering::is_ge))
             }))
         }
         _ => Err(error!(format!("Invalid operator for compare {}", operator))),
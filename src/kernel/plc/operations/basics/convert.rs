@@ -0,0 +1,76 @@
+use crate::parser::body::json_target::JsonTarget;
+use crate::kernel::plc::interface::section_interface::SectionInterface;
+use crate::kernel::plc::internal::template_impl::TemplateMemory;
+use crate::kernel::plc::operations::operations::{BuildJsonOperation, NewJsonOperation, RunTimeOperation};
+use crate::kernel::registry::Kernel;
+use crate::container::error::error::Stop;
+use crate::{key_reader};
+use serde_json::{Map, Value};
+use crate::parser::body::body::parse_json_target;
+use crate::container::broadcast::broadcast::Broadcast;
+use crate::kernel::rust::convert::{box_convert_plc_primitive, Rounding};
+use crate::kernel::rust::overflow::Overflow;
+use crate::parser::local_type::constant_type::create_default_constant_from_str;
+use crate::kernel::arch::local::r#type::IntoLocalType;
+
+/// Explicit type conversion, e.g. INT_TO_REAL or CONVERT.
+///
+/// A float is rounded to the nearest even integer unless another `rounding` is given.
+/// A `checked` conversion stops the simulation when the value does not fit the target type,
+/// otherwise only the lowest bits are kept. Without `checked` the overflow of the target type
+/// in the provider applies, a checked conversion by default.
+#[derive(Clone)]
+pub struct Convert {
+    convert: JsonTarget,
+    to: String,
+    rounding: Rounding,
+    checked: Option<bool>,
+    id: u32,
+}
+
+impl NewJsonOperation for Convert {
+    fn new(json: &Map<String, Value>) -> Result<Self, Stop> {
+        key_reader!(
+            format!("Parse Convert"),
+            json {
+                convert,
+                to => as_str,
+                rounding? => as_str,
+                checked? => as_bool,
+                id => as_u64,
+            }
+        );
+
+        let id = id as u32;
+
+        let convert = parse_json_target(convert)?;
+        let rounding = Rounding::try_from(rounding.unwrap_or("round"))?;
+
+        Ok(Self {
+            convert,
+            to: to.to_string(),
+            rounding,
+            checked,
+            id
+        })
+    }
+}
+
+impl BuildJsonOperation for Convert {
+    fn build(
+        &self,
+        interface: &SectionInterface,
+        template: Option<&TemplateMemory>,
+        registry: &Kernel,
+        channel: &Broadcast
+    ) -> Result<RunTimeOperation, Stop> {
+        let convert = self.convert.solve_to_ref(interface, template, None, registry, channel)?;
+        let to = create_default_constant_from_str(&self.to)?.transform()?;
+        let overflow = match self.checked {
+            Some(true) => Overflow::Error,
+            Some(false) => Overflow::Wrap,
+            None => registry.get_overflow(&to),
+        };
+        box_convert_plc_primitive(&convert, to, self.rounding, overflow, registry.overflows.clone(), self.id)
+    }
+}
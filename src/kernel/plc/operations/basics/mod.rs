@@ -2,3 +2,4 @@ pub mod calc;
 pub mod compare;
 pub mod assign;
 pub mod call;
+pub mod convert;
@@ -0,0 +1,58 @@
+use crate::parser::body::json_target::JsonTarget;
+use crate::kernel::plc::interface::section_interface::SectionInterface;
+use crate::kernel::plc::internal::template_impl::TemplateMemory;
+use crate::kernel::plc::operations::operations::{BuildJsonOperation, NewJsonOperation, RunTimeOperation};
+use crate::kernel::registry::Kernel;
+use crate::container::error::error::Stop;
+use crate::{key_reader};
+use serde_json::{Map, Value};
+use crate::parser::body::body::parse_json_target;
+use crate::container::broadcast::broadcast::Broadcast;
+use crate::kernel::rust::bitwise::{box_bitwise};
+use crate::kernel::rust::select::solve_inputs;
+
+
+#[derive(Clone)]
+pub struct And {
+    and: JsonTarget,
+    and_with: JsonTarget,
+    id: u32,
+}
+
+impl NewJsonOperation for And {
+    fn new(json: &Map<String, Value>) -> Result<Self, Stop> {
+        key_reader!(
+            format!("Parse And"),
+            json {
+                and,
+                and_with,
+                id => as_u64,
+            }
+        );
+
+        let id = id as u32;
+
+        let and = parse_json_target(and)?;
+        let and_with = parse_json_target(and_with)?;
+
+        Ok(Self {
+            and,
+            and_with,
+            id
+        })
+    }
+}
+
+impl BuildJsonOperation for And {
+    fn build(
+        &self,
+        interface: &SectionInterface,
+        template: Option<&TemplateMemory>,
+        registry: &Kernel,
+        channel: &Broadcast
+    ) -> Result<RunTimeOperation, Stop> {
+        // An Implicit mask takes the type of the other operand, e.g. `status AND 16#00FF`
+        let operands = solve_inputs(&[&self.and, &self.and_with], None, interface, template, registry, channel)?;
+        box_bitwise("and", &operands[0], &operands[1], self.id, registry)
+    }
+}
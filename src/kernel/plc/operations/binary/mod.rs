@@ -2,4 +2,8 @@ pub mod shr;
 pub mod shl;
 pub mod rotate_left;
 pub mod rotate_right;
-pub mod swap;
\ No newline at end of file
+pub mod swap;
+pub mod and;
+pub mod or;
+pub mod xor;
+pub mod not;
@@ -0,0 +1,52 @@
+use crate::parser::body::json_target::JsonTarget;
+use crate::kernel::plc::interface::section_interface::SectionInterface;
+use crate::kernel::plc::internal::template_impl::TemplateMemory;
+use crate::kernel::plc::operations::operations::{BuildJsonOperation, NewJsonOperation, RunTimeOperation};
+use crate::kernel::registry::Kernel;
+use crate::container::error::error::Stop;
+use crate::{key_reader};
+use serde_json::{Map, Value};
+use crate::parser::body::body::parse_json_target;
+use crate::container::broadcast::broadcast::Broadcast;
+use crate::kernel::rust::bitwise::{box_bitwise_not};
+
+
+#[derive(Clone)]
+pub struct Not {
+    not: JsonTarget,
+    id: u32,
+}
+
+impl NewJsonOperation for Not {
+    fn new(json: &Map<String, Value>) -> Result<Self, Stop> {
+        key_reader!(
+            format!("Parse Not"),
+            json {
+                not,
+                id => as_u64,
+            }
+        );
+
+        let id = id as u32;
+
+        let not = parse_json_target(not)?;
+
+        Ok(Self {
+            not,
+            id
+        })
+    }
+}
+
+impl BuildJsonOperation for Not {
+    fn build(
+        &self,
+        interface: &SectionInterface,
+        template: Option<&TemplateMemory>,
+        registry: &Kernel,
+        channel: &Broadcast
+    ) -> Result<RunTimeOperation, Stop> {
+        let not = self.not.solve_to_ref(interface, template, None, registry, channel)?;
+        box_bitwise_not(&not, self.id, registry)
+    }
+}
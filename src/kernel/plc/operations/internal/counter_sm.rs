@@ -143,6 +143,9 @@ impl BuildJsonOperation for CounterStateMachine {
         let counter_up = box_ord_plc_primitive(&counter_var, &preset_var, self.id, registry)?;
 
         let load_counter = box_set_plc_primitive(&counter_var, &preset_var,  self.id, true, registry)?;
+        let overflow = registry.get_overflow(&counter_var);
+        let overflows = registry.overflows.clone();
+        let id = self.id;
 
         Ok(Box::new(Operation::new(
             MaybeHeapOrStatic(None),
@@ -165,17 +168,17 @@ impl BuildJsonOperation for CounterStateMachine {
 
                 // Increment
                 if let Some(a) = increment.as_ref() {
-                    if a.with_plc_bool(channel, |a| Ok(a.as_bool()?.get(channel)?))?? {
-                        counter_var
-                            .with_mut_plc_integer(channel, &mut |a| a.increment(channel))??;
+                    if a.with_plc_bool(channel, |a| a.as_bool()?.get(channel))??
+                        && counter_var.with_mut_plc_integer(channel, &mut |a| a.increment(overflow, channel))?? {
+                        overflows.record(id);
                     }
                 }
 
                 // Decrement
                 if let Some(a) = decrement.as_ref() {
-                    if a.with_plc_bool(channel, |a| Ok(a.as_bool()?.get(channel)?))?? {
-                        counter_var
-                            .with_mut_plc_integer(channel, &mut |a| a.decrement(channel))??;
+                    if a.with_plc_bool(channel, |a| a.as_bool()?.get(channel))??
+                        && counter_var.with_mut_plc_integer(channel, &mut |a| a.decrement(overflow, channel))?? {
+                        overflows.record(id);
                     }
                 }
 
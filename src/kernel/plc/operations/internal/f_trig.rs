@@ -70,6 +70,7 @@ impl BuildJsonOperation for F_Trig {
             .unwrap();
         let input = input.clone();
         let stat_bit = self.stat_bit.clone();
+        registry.internal_states.borrow_mut().push(self.id, stat_bit.clone());
 
         let return_trig = LocalPointer::new(LocalType::PlcBool(PlcBool::Bool(Bool::new_default(0))));
         let return_trig_clone = return_trig.clone();
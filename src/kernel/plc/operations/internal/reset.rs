@@ -61,7 +61,8 @@ impl BuildJsonOperation for Reset {
 
         let raw_pointers = reset
             .iter()
-            .fold(vec![], |_all, p| p.get_raw_pointers());
+            .flat_map(|p| p.get_raw_pointers())
+            .collect::<Vec<_>>();
 
         Ok(Box::new(Operation::new(
             MaybeHeapOrStatic(Some(HeapOrStatic::Static(&"Reset"))),
@@ -1,5 +1,5 @@
 ﻿use crate::container::broadcast::broadcast::Broadcast;
-use crate::container::container::{get_id, DELAYED_TIMERS};
+use crate::container::container::get_id;
 use crate::container::error::error::Stop;
 use crate::{error, key_reader};
 use crate::parser::body::body::parse_json_target;
@@ -15,12 +15,12 @@ use crate::kernel::plc::types::primitives::traits::meta_data::{HeapOrStatic, May
 use crate::kernel::plc::types::primitives::traits::primitive_traits::PrimitiveTrait;
 use crate::kernel::plc::types::primitives::timers::traits::TimeDuration;
 use crate::kernel::registry::Kernel;
-use serde_json::{Map, Value};
+use crate::kernel::arch::snapshot::snapshot::InternalState;
+use serde_json::{json, Map, Value};
 use core::cell::RefCell;
 use core::ops::{Deref, DerefMut};
 use std::rc::Rc;
 use core::time::Duration;
-use web_time::Instant;
 use crate::kernel::plc::operations::unit::test::UnitTestJson;
 
 pub struct TimerStateMachine {
@@ -34,10 +34,46 @@ pub struct TimerStateMachine {
     on_timer_reset: Vec<JsonTarget>,
 
     started: Rc<RefCell<bool>>,
-    previous_duration: Rc<RefCell<Instant>>,
+    previous_duration: Rc<RefCell<Duration>>,
     id: u32,
 }
 
+/// Running flag and start time of a timer, saved in the kernel snapshots
+struct TimerState {
+    started: Rc<RefCell<bool>>,
+    previous_duration: Rc<RefCell<Duration>>,
+}
+
+impl InternalState for TimerState {
+    fn save(&self) -> Value {
+        json!({
+            "started": *self.started.borrow().deref(),
+            "start_time": self.previous_duration.borrow().as_nanos() as u64,
+        })
+    }
+
+    fn restore(&self, value: &Value) -> Result<(), Stop> {
+        let value = value
+            .as_object()
+            .ok_or_else(|| error!(format!("Invalid timer state {}", value)))?;
+        key_reader!(
+            format!("Restore timer state"),
+            value {
+                started => as_bool,
+                start_time => as_u64,
+            }
+        );
+        *self.started.borrow_mut().deref_mut() = started;
+        *self.previous_duration.borrow_mut().deref_mut() = Duration::from_nanos(start_time);
+        Ok(())
+    }
+
+    fn reset(&self) {
+        *self.started.borrow_mut().deref_mut() = false;
+        *self.previous_duration.borrow_mut().deref_mut() = Duration::ZERO;
+    }
+}
+
 impl Clone for TimerStateMachine {
     fn clone(&self) -> Self {
         Self {
@@ -51,7 +87,7 @@ impl Clone for TimerStateMachine {
             on_timer_reset: self.on_timer_reset.clone(),
 
             started: Rc::new(RefCell::new(false)),
-            previous_duration: Rc::new(RefCell::new(Instant::now())),
+            previous_duration: Rc::new(RefCell::new(Duration::ZERO)),
             id: self.id
         }
     }
@@ -96,7 +132,7 @@ impl NewJsonOperation for TimerStateMachine {
                 .map(parse_json_target)
                 .collect::<Result<Vec<JsonTarget>, Stop>>()?,
             started: Rc::new(RefCell::new(false)),
-            previous_duration: Rc::new(RefCell::new(Instant::now())),
+            previous_duration: Rc::new(RefCell::new(Duration::ZERO)),
             id,
         })
     }
@@ -147,7 +183,12 @@ impl BuildJsonOperation for TimerStateMachine {
 
         let started = self.started.clone();
         let previous_duration = self.previous_duration.clone();
+        registry.internal_states.borrow_mut().push(self.id, Rc::new(TimerState {
+            started: started.clone(),
+            previous_duration: previous_duration.clone(),
+        }));
         let id = self.id;
+        let clock = registry.clock.clone();
 
         let elapsed = box_ord_plc_primitive(&timer_var, &preset_var, id, registry)?;
 
@@ -157,9 +198,8 @@ impl BuildJsonOperation for TimerStateMachine {
                 // If reset
                 if let Some(a) = reset.as_ref() {
                     if a.with_plc_bool(channel, |a| Ok(a.as_bool()?.get(channel)?))?? {
-                        DELAYED_TIMERS.lock().unwrap().remove(&id);
                         *started.borrow_mut().deref_mut() = false;
-                        *previous_duration.borrow_mut().deref_mut() = Instant::now();
+                        *previous_duration.borrow_mut().deref_mut() = clock.now();
                         on_timer_reset
                             .iter()
                             .try_for_each(|x| x.with_void(channel))?;
@@ -168,9 +208,7 @@ impl BuildJsonOperation for TimerStateMachine {
 
                 // If timer started
                 if *started.borrow().deref() {
-                    let instant = Instant::now()
-                        .duration_since(*previous_duration.borrow().deref())
-                        - *DELAYED_TIMERS.lock().unwrap().get(&id).unwrap();
+                    let instant = clock.elapsed_since(*previous_duration.borrow().deref());
                     timer_var
                         .with_mut_plc_time(channel, &mut |a| a.set_duration(&instant, channel))??;
 
@@ -183,12 +221,8 @@ impl BuildJsonOperation for TimerStateMachine {
                 }
                 // Else should the timer start
                 else if start.with_plc_bool(channel, |a| Ok(a.as_bool()?.get(channel)?))?? {
-                    DELAYED_TIMERS
-                        .lock()
-                        .unwrap()
-                        .insert(id, Duration::from_millis(0));
                     *started.borrow_mut().deref_mut() = true;
-                    *previous_duration.borrow_mut().deref_mut() = Instant::now();
+                    *previous_duration.borrow_mut().deref_mut() = clock.now();
                     on_timer_start
                         .iter()
                         .try_for_each(|x| x.with_void(channel))?;
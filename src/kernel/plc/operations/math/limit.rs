@@ -0,0 +1,71 @@
+use crate::parser::body::json_target::JsonTarget;
+use crate::kernel::plc::interface::section_interface::SectionInterface;
+use crate::kernel::plc::internal::template_impl::TemplateMemory;
+use crate::kernel::plc::operations::operations::{BuildJsonOperation, NewJsonOperation, RunTimeOperation};
+use crate::kernel::registry::Kernel;
+use crate::container::error::error::Stop;
+use crate::{error, key_reader};
+use serde_json::{Map, Value};
+use crate::parser::body::body::parse_json_target;
+use crate::container::broadcast::broadcast::Broadcast;
+use crate::kernel::rust::select::{box_select_operation, solve_inputs, compare};
+use core::cmp::Ordering;
+
+/// LIMIT(MN, IN, MX), the result has the type of IN.
+#[derive(Clone)]
+pub struct Limit {
+    limit: JsonTarget,
+    min: JsonTarget,
+    max: JsonTarget,
+    id: u32,
+}
+
+impl NewJsonOperation for Limit {
+    fn new(json: &Map<String, Value>) -> Result<Self, Stop> {
+        key_reader!(
+            format!("Parse Limit"),
+            json {
+                limit,
+                min,
+                max,
+                id => as_u64,
+            }
+        );
+
+        let id = id as u32;
+
+        let limit = parse_json_target(limit).map_err(|e| e.add_id(id))?;
+        let min = parse_json_target(min).map_err(|e| e.add_id(id))?;
+        let max = parse_json_target(max).map_err(|e| e.add_id(id))?;
+
+        Ok(Self {
+            limit,
+            min,
+            max,
+            id
+        })
+    }
+}
+
+impl BuildJsonOperation for Limit {
+    fn build(
+        &self,
+        interface: &SectionInterface,
+        template: Option<&TemplateMemory>,
+        registry: &Kernel,
+        channel: &Broadcast
+    ) -> Result<RunTimeOperation, Stop> {
+        let inputs = solve_inputs(&[&self.limit, &self.min, &self.max], None, interface, template, registry, channel)?;
+
+        box_select_operation("limit", None, inputs, |_, values| {
+            if compare(&values[1], &values[2]) == Some(Ordering::Greater) {
+                return Err(error!(format!("Invalid operation: Limit minimum {:?} is greater than maximum {:?}", values[1], values[2])));
+            }
+            match (compare(&values[0], &values[1]), compare(&values[0], &values[2])) {
+                (Some(Ordering::Less), _) => Ok(1),
+                (_, Some(Ordering::Greater)) => Ok(2),
+                _ => Ok(0),
+            }
+        }, self.id, registry)
+    }
+}
@@ -0,0 +1,67 @@
+use crate::parser::body::json_target::JsonTarget;
+use crate::kernel::plc::interface::section_interface::SectionInterface;
+use crate::kernel::plc::internal::template_impl::TemplateMemory;
+use crate::kernel::plc::operations::operations::{BuildJsonOperation, NewJsonOperation, RunTimeOperation};
+use crate::kernel::registry::Kernel;
+use crate::container::error::error::Stop;
+use crate::{error, key_reader};
+use serde_json::{Map, Value};
+use crate::parser::body::body::parse_json_target;
+use crate::container::broadcast::broadcast::Broadcast;
+use crate::kernel::rust::select::{box_select_operation, solve_inputs, compare};
+use core::cmp::Ordering;
+
+#[derive(Clone)]
+pub struct Min {
+    min: Vec<JsonTarget>,
+    id: u32,
+}
+
+impl NewJsonOperation for Min {
+    fn new(json: &Map<String, Value>) -> Result<Self, Stop> {
+        key_reader!(
+            format!("Parse Min"),
+            json {
+                min => as_array,
+                id => as_u64,
+            }
+        );
+
+        let id = id as u32;
+
+        if min.len() < 2 {
+            return Err(error!(format!("Min needs at least 2 inputs, got {}", min.len())).add_id(id));
+        }
+
+        let min = min
+            .iter()
+            .map(parse_json_target)
+            .collect::<Result<Vec<JsonTarget>, Stop>>().map_err(|e| e.add_id(id))?;
+
+        Ok(Self {
+            min,
+            id
+        })
+    }
+}
+
+impl BuildJsonOperation for Min {
+    fn build(
+        &self,
+        interface: &SectionInterface,
+        template: Option<&TemplateMemory>,
+        registry: &Kernel,
+        channel: &Broadcast
+    ) -> Result<RunTimeOperation, Stop> {
+        let min = solve_inputs(&self.min.iter().collect::<Vec<_>>(), None, interface, template, registry, channel)?;
+
+        box_select_operation("min", None, min, |_, values| {
+            Ok((1..values.len()).fold(0, |selected, index| {
+                match compare(&values[index], &values[selected]) {
+                    Some(Ordering::Less) => index,
+                    _ => selected,
+                }
+            }))
+        }, self.id, registry)
+    }
+}
@@ -14,3 +14,10 @@ pub mod abs;
 pub mod ceil;
 pub mod floor;
 pub mod round;
+pub mod min;
+pub mod max;
+pub mod limit;
+pub mod sel;
+pub mod mux;
+pub mod norm_x;
+pub mod scale_x;
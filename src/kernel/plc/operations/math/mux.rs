@@ -0,0 +1,76 @@
+use crate::parser::body::json_target::JsonTarget;
+use crate::kernel::plc::interface::section_interface::SectionInterface;
+use crate::kernel::plc::internal::template_impl::TemplateMemory;
+use crate::kernel::plc::operations::operations::{BuildJsonOperation, NewJsonOperation, RunTimeOperation};
+use crate::kernel::registry::Kernel;
+use crate::container::error::error::Stop;
+use crate::{error, key_reader};
+use serde_json::{Map, Value};
+use crate::parser::body::body::parse_json_target;
+use crate::container::broadcast::broadcast::Broadcast;
+use crate::kernel::rust::convert::{ConvertKind, ConvertValue};
+use crate::kernel::rust::select::{box_select_operation, solve_inputs};
+
+/// MUX(K, IN0, .., INn), the input at index K.
+#[derive(Clone)]
+pub struct Mux {
+    mux: JsonTarget,
+    inputs: Vec<JsonTarget>,
+    id: u32,
+}
+
+impl NewJsonOperation for Mux {
+    fn new(json: &Map<String, Value>) -> Result<Self, Stop> {
+        key_reader!(
+            format!("Parse Mux"),
+            json {
+                mux,
+                inputs => as_array,
+                id => as_u64,
+            }
+        );
+
+        let id = id as u32;
+
+        if inputs.is_empty() {
+            return Err(error!(format!("Mux needs at least 1 input")).add_id(id));
+        }
+
+        let mux = parse_json_target(mux).map_err(|e| e.add_id(id))?;
+        let inputs = inputs
+            .iter()
+            .map(parse_json_target)
+            .collect::<Result<Vec<JsonTarget>, Stop>>().map_err(|e| e.add_id(id))?;
+
+        Ok(Self {
+            mux,
+            inputs,
+            id
+        })
+    }
+}
+
+impl BuildJsonOperation for Mux {
+    fn build(
+        &self,
+        interface: &SectionInterface,
+        template: Option<&TemplateMemory>,
+        registry: &Kernel,
+        channel: &Broadcast
+    ) -> Result<RunTimeOperation, Stop> {
+        let mux = self.mux.solve_to_ref(interface, template, None, registry, channel)?;
+        let kind = match ConvertKind::of(&mux) {
+            Some(kind @ ConvertKind::Integer { .. }) => kind,
+            _ => return Err(error!(format!("Invalid operation: Mux expects an integer selector, got {}", mux)).add_id(self.id)),
+        };
+        let inputs = solve_inputs(&self.inputs.iter().collect::<Vec<_>>(), None, interface, template, registry, channel)?;
+
+        box_select_operation("mux", Some((mux, kind)), inputs, |selector, values| {
+            match selector {
+                Some(ConvertValue::Integer(k)) if k >= 0 && (k as usize) < values.len() => Ok(k as usize),
+                Some(ConvertValue::Integer(k)) => Err(error!(format!("Invalid operation: Mux selector {} is out of range 0..{}", k, values.len() - 1))),
+                _ => Err(error!(format!("Invalid operation: Mux without selector"))),
+            }
+        }, self.id, registry)
+    }
+}
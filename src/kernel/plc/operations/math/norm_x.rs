@@ -0,0 +1,73 @@
+use crate::parser::body::json_target::JsonTarget;
+use crate::kernel::plc::interface::section_interface::SectionInterface;
+use crate::kernel::plc::internal::template_impl::TemplateMemory;
+use crate::kernel::plc::operations::operations::{BuildJsonOperation, NewJsonOperation, RunTimeOperation};
+use crate::kernel::registry::Kernel;
+use crate::container::error::error::Stop;
+use crate::key_reader;
+use serde_json::{Map, Value};
+use crate::parser::body::body::parse_json_target;
+use crate::container::broadcast::broadcast::Broadcast;
+use crate::kernel::arch::local::r#type::IntoLocalType;
+use crate::kernel::rust::scale::{box_scale_operation, OutOfRange, Scaling};
+
+/// NORM_X(MIN, VALUE, MAX), VALUE normalized to 0.0..1.0.
+#[derive(Clone)]
+pub struct NormX {
+    norm_x: JsonTarget,
+    min: JsonTarget,
+    max: JsonTarget,
+    range: OutOfRange,
+    id: u32,
+}
+
+impl NewJsonOperation for NormX {
+    fn new(json: &Map<String, Value>) -> Result<Self, Stop> {
+        key_reader!(
+            format!("Parse NormX"),
+            json {
+                norm_x,
+                min,
+                max,
+                range? => as_str,
+                id => as_u64,
+            }
+        );
+
+        let id = id as u32;
+
+        let norm_x = parse_json_target(norm_x).map_err(|e| e.add_id(id))?;
+        let min = parse_json_target(min).map_err(|e| e.add_id(id))?;
+        let max = parse_json_target(max).map_err(|e| e.add_id(id))?;
+
+        let range = match range {
+            Some(a) => OutOfRange::try_from(a).map_err(|e| e.add_id(id))?,
+            None => OutOfRange::Extrapolate,
+        };
+
+        Ok(Self {
+            norm_x,
+            min,
+            max,
+            range,
+            id
+        })
+    }
+}
+
+impl BuildJsonOperation for NormX {
+    fn build(
+        &self,
+        interface: &SectionInterface,
+        template: Option<&TemplateMemory>,
+        registry: &Kernel,
+        channel: &Broadcast
+    ) -> Result<RunTimeOperation, Stop> {
+        // Implicit bounds take the type of VALUE
+        let norm_x = self.norm_x.solve_to_ref(interface, template, None, registry, channel)?;
+        let hint = Some(norm_x.transform()?);
+        let min = self.min.solve_to_ref(interface, template, hint.clone(), registry, channel)?;
+        let max = self.max.solve_to_ref(interface, template, hint, registry, channel)?;
+        box_scale_operation("norm_x", Scaling::Normalize, [min, norm_x, max], self.range, self.id, registry)
+    }
+}
@@ -0,0 +1,73 @@
+use crate::parser::body::json_target::JsonTarget;
+use crate::kernel::plc::interface::section_interface::SectionInterface;
+use crate::kernel::plc::internal::template_impl::TemplateMemory;
+use crate::kernel::plc::operations::operations::{BuildJsonOperation, NewJsonOperation, RunTimeOperation};
+use crate::kernel::registry::Kernel;
+use crate::container::error::error::Stop;
+use crate::key_reader;
+use serde_json::{Map, Value};
+use crate::parser::body::body::parse_json_target;
+use crate::container::broadcast::broadcast::Broadcast;
+use crate::kernel::arch::local::r#type::IntoLocalType;
+use crate::kernel::rust::scale::{box_scale_operation, OutOfRange, Scaling};
+use crate::kernel::rust::select::solve_inputs;
+
+/// SCALE_X(MIN, VALUE, MAX), VALUE in 0.0..1.0 scaled to MIN..MAX.
+#[derive(Clone)]
+pub struct ScaleX {
+    scale_x: JsonTarget,
+    min: JsonTarget,
+    max: JsonTarget,
+    range: OutOfRange,
+    id: u32,
+}
+
+impl NewJsonOperation for ScaleX {
+    fn new(json: &Map<String, Value>) -> Result<Self, Stop> {
+        key_reader!(
+            format!("Parse ScaleX"),
+            json {
+                scale_x,
+                min,
+                max,
+                range? => as_str,
+                id => as_u64,
+            }
+        );
+
+        let id = id as u32;
+
+        let scale_x = parse_json_target(scale_x).map_err(|e| e.add_id(id))?;
+        let min = parse_json_target(min).map_err(|e| e.add_id(id))?;
+        let max = parse_json_target(max).map_err(|e| e.add_id(id))?;
+
+        let range = match range {
+            Some(a) => OutOfRange::try_from(a).map_err(|e| e.add_id(id))?,
+            None => OutOfRange::Extrapolate,
+        };
+
+        Ok(Self {
+            scale_x,
+            min,
+            max,
+            range,
+            id
+        })
+    }
+}
+
+impl BuildJsonOperation for ScaleX {
+    fn build(
+        &self,
+        interface: &SectionInterface,
+        template: Option<&TemplateMemory>,
+        registry: &Kernel,
+        channel: &Broadcast
+    ) -> Result<RunTimeOperation, Stop> {
+        // OUT has the type of MIN, an Implicit bound takes the type of the other one
+        // or the type of VALUE when both are Implicit
+        let scale_x = self.scale_x.solve_to_ref(interface, template, None, registry, channel)?;
+        let bounds = solve_inputs(&[&self.min, &self.max], Some(scale_x.transform()?), interface, template, registry, channel)?;
+        box_scale_operation("scale_x", Scaling::Scale, [bounds[0].clone(), scale_x, bounds[1].clone()], self.range, self.id, registry)
+    }
+}
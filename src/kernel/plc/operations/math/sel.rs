@@ -0,0 +1,71 @@
+use crate::parser::body::json_target::JsonTarget;
+use crate::kernel::plc::interface::section_interface::SectionInterface;
+use crate::kernel::plc::internal::template_impl::TemplateMemory;
+use crate::kernel::plc::operations::operations::{BuildJsonOperation, NewJsonOperation, RunTimeOperation};
+use crate::kernel::registry::Kernel;
+use crate::container::error::error::Stop;
+use crate::{error, key_reader};
+use serde_json::{Map, Value};
+use crate::parser::body::body::parse_json_target;
+use crate::container::broadcast::broadcast::Broadcast;
+use crate::kernel::rust::convert::{ConvertKind, ConvertValue};
+use crate::kernel::rust::select::{box_select_operation, solve_inputs};
+
+/// SEL(G, IN0, IN1), IN0 when G is false, IN1 when G is true.
+#[derive(Clone)]
+pub struct Sel {
+    sel: JsonTarget,
+    in0: JsonTarget,
+    in1: JsonTarget,
+    id: u32,
+}
+
+impl NewJsonOperation for Sel {
+    fn new(json: &Map<String, Value>) -> Result<Self, Stop> {
+        key_reader!(
+            format!("Parse Sel"),
+            json {
+                sel,
+                in0,
+                in1,
+                id => as_u64,
+            }
+        );
+
+        let id = id as u32;
+
+        let sel = parse_json_target(sel).map_err(|e| e.add_id(id))?;
+        let in0 = parse_json_target(in0).map_err(|e| e.add_id(id))?;
+        let in1 = parse_json_target(in1).map_err(|e| e.add_id(id))?;
+
+        Ok(Self {
+            sel,
+            in0,
+            in1,
+            id
+        })
+    }
+}
+
+impl BuildJsonOperation for Sel {
+    fn build(
+        &self,
+        interface: &SectionInterface,
+        template: Option<&TemplateMemory>,
+        registry: &Kernel,
+        channel: &Broadcast
+    ) -> Result<RunTimeOperation, Stop> {
+        let sel = self.sel.solve_to_ref(interface, template, None, registry, channel)?;
+        if ConvertKind::of(&sel) != Some(ConvertKind::Bool) {
+            return Err(error!(format!("Invalid operation: Sel expects a boolean selector, got {}", sel)).add_id(self.id));
+        }
+        let inputs = solve_inputs(&[&self.in0, &self.in1], None, interface, template, registry, channel)?;
+
+        box_select_operation("sel", Some((sel, ConvertKind::Bool)), inputs, |selector, _| {
+            match selector {
+                Some(ConvertValue::Bool(true)) => Ok(1),
+                _ => Ok(0),
+            }
+        }, self.id, registry)
+    }
+}
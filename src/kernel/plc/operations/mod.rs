@@ -5,4 +5,7 @@ pub mod math;
 pub mod binary;
 pub mod basics;
 pub mod program_control;
+pub mod string;
 
+pub mod system;
+pub mod timers;
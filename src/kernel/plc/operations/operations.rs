@@ -11,10 +11,15 @@ use crate::kernel::plc::operations::basics::calc::Calc;
 use crate::kernel::plc::operations::basics::compare::Compare;
 use crate::kernel::plc::operations::basics::assign::Assign;
 use crate::kernel::plc::operations::basics::call::Call;
+use crate::kernel::plc::operations::basics::convert::Convert;
 use crate::kernel::plc::operations::program_control::r#return::Return;
 use crate::kernel::plc::operations::program_control::r#for::For;
 use crate::kernel::plc::operations::program_control::r#while::While;
 use crate::kernel::plc::operations::program_control::r#if::If;
+use crate::kernel::plc::operations::program_control::case::Case;
+use crate::kernel::plc::operations::program_control::repeat::Repeat;
+use crate::kernel::plc::operations::program_control::exit::Exit;
+use crate::kernel::plc::operations::program_control::r#continue::Continue;
 use crate::kernel::plc::operations::math::cos::Cos;
 use crate::kernel::plc::operations::math::sin::Sin;
 use crate::kernel::plc::operations::math::tan::Tan;
@@ -37,6 +42,7 @@ use crate::kernel::plc::types::primitives::integers::plc_integer::PlcInteger;
 use crate::kernel::plc::types::primitives::string::plc_string::PlcString;
 use crate::kernel::plc::types::primitives::timers::plc_time::PlcTime;
 use crate::kernel::plc::types::primitives::tod::plc_tod::PlcTod;
+use crate::kernel::plc::types::primitives::date::plc_date::PlcDate;
 use crate::kernel::arch::local::pointer::LocalPointer;
 use crate::kernel::arch::local::r#type::{LocalType, IntoLocalType};
 use camelpaste::paste;
@@ -53,6 +59,10 @@ use crate::kernel::plc::operations::binary::rotate_right::RotateRight;
 use crate::kernel::plc::operations::binary::shl::Shl;
 use crate::kernel::plc::operations::binary::shr::Shr;
 use crate::kernel::plc::operations::binary::swap::Swap;
+use crate::kernel::plc::operations::binary::and::And;
+use crate::kernel::plc::operations::binary::or::Or;
+use crate::kernel::plc::operations::binary::xor::Xor;
+use crate::kernel::plc::operations::binary::not::Not;
 use crate::kernel::plc::operations::internal::reset::Reset;
 use crate::kernel::plc::operations::math::abs::Abs;
 use crate::kernel::plc::operations::math::acos::ACos;
@@ -67,6 +77,29 @@ use crate::kernel::plc::operations::math::round::Round;
 use crate::kernel::plc::operations::math::sqr::Sqr;
 use crate::kernel::plc::operations::math::sqrt::Sqrt;
 use crate::kernel::plc::operations::math::trunc::Trunc;
+use crate::kernel::plc::operations::math::min::Min;
+use crate::kernel::plc::operations::math::max::Max;
+use crate::kernel::plc::operations::math::limit::Limit;
+use crate::kernel::plc::operations::math::sel::Sel;
+use crate::kernel::plc::operations::math::mux::Mux;
+use crate::kernel::plc::operations::math::norm_x::NormX;
+use crate::kernel::plc::operations::math::scale_x::ScaleX;
+use crate::kernel::plc::operations::system::rd_sys_t::RdSysT;
+use crate::kernel::plc::operations::system::rd_loc_t::RdLocT;
+use crate::kernel::plc::operations::system::wr_sys_t::WrSysT;
+use crate::kernel::plc::operations::timers::tp::Tp;
+use crate::kernel::plc::operations::timers::ton::Ton;
+use crate::kernel::plc::operations::timers::tof::Tof;
+use crate::kernel::plc::operations::timers::tonr::Tonr;
+use crate::kernel::plc::operations::string::len::Len;
+use crate::kernel::plc::operations::string::concat::Concat;
+use crate::kernel::plc::operations::string::left::Left;
+use crate::kernel::plc::operations::string::right::Right;
+use crate::kernel::plc::operations::string::mid::Mid;
+use crate::kernel::plc::operations::string::find::Find;
+use crate::kernel::plc::operations::string::insert::Insert;
+use crate::kernel::plc::operations::string::delete::Delete;
+use crate::kernel::plc::operations::string::replace::Replace;
 use crate::kernel::plc::types::primitives::string::_string::plcstr;
 use crate::kernel::plc::types::primitives::string::wstring::plcwstr;
 use crate::kernel::plc::types::primitives::traits::meta_data::{MaybeHeapOrStatic, MetaData, SetMetaData};
@@ -245,10 +278,15 @@ create_json_operations!(
     Calc,
     Compare,
     If,
+    Case,
     For,
     While,
+    Repeat,
+    Exit,
+    Continue,
     Assign,
     Call,
+    Convert,
     // Math
     Cos,
     Sin,
@@ -266,12 +304,42 @@ create_json_operations!(
     Ceil,
     Floor,
     Round,
+    Min,
+    Max,
+    Limit,
+    Sel,
+    Mux,
+    NormX,
+    ScaleX,
     // Binaries
     Shl,
     Shr,
     RotateLeft,
     RotateRight,
-    Swap
+    Swap,
+    And,
+    Or,
+    Xor,
+    Not,
+    // Strings
+    Len,
+    Concat,
+    Left,
+    Right,
+    Mid,
+    Find,
+    Insert,
+    Delete,
+    Replace,
+    // System
+    RdSysT,
+    RdLocT,
+    WrSysT,
+    // Timers
+    Tp,
+    Ton,
+    Tof,
+    Tonr
 );
 
 macro_rules! impl_family {
@@ -396,7 +464,8 @@ impl_family!(
     PlcBinary,
     PlcString,
     PlcTime,
-    PlcTod
+    PlcTod,
+    PlcDate
     +
     PlcStruct,
     PlcArray,
@@ -0,0 +1,244 @@
+use core::cell::RefCell;
+use std::rc::Rc;
+use crate::{error, key_reader};
+use crate::parser::body::json_target::JsonTarget;
+use crate::kernel::plc::interface::section_interface::SectionInterface;
+use crate::kernel::plc::internal::template_impl::TemplateMemory;
+use crate::kernel::plc::operations::operations::{
+    BuildJsonOperation, NewJsonOperation, Operation, RunTimeOperation,
+};
+use crate::kernel::plc::types::primitives::traits::meta_data::{HeapOrStatic, MaybeHeapOrStatic};
+use crate::kernel::rust::convert::{read_value, ConvertKind, ConvertValue};
+use crate::kernel::plc::operations::program_control::loop_control::run_operations;
+use crate::kernel::registry::Kernel;
+use crate::kernel::plc::types::enumeration::PlcEnum;
+use crate::container::error::error::Stop;
+use serde_json::{Map, Value};
+use crate::parser::body::body::parse_json_target;
+use crate::container::broadcast::broadcast::Broadcast;
+
+/// Label of a branch, a single value, an inclusive range or a symbol of the enumeration of the selector.
+#[derive(Clone, Debug, PartialEq)]
+pub enum CaseLabel {
+    Value(i128),
+    Range(i128, i128),
+    Symbol(String),
+}
+
+impl CaseLabel {
+    fn matches(&self, value: i128) -> bool {
+        match self {
+            CaseLabel::Value(a) => *a == value,
+            CaseLabel::Range(from, to) => (*from..=*to).contains(&value),
+            CaseLabel::Symbol(_) => false,
+        }
+    }
+
+    /// Replaces a symbol by its value.
+    fn resolve(&self, plc_enum: Option<&PlcEnum>) -> Result<CaseLabel, Stop> {
+        match (self, plc_enum) {
+            (CaseLabel::Symbol(a), Some(of)) => of
+                .value_of(a)
+                .map(|value| CaseLabel::Value(value as i128))
+                .ok_or_else(|| error!(format!("Case label {} is not a value of {}", a, of.get_name()))),
+            (CaseLabel::Symbol(a), None) => Err(error!(format!("Case label {} requires an enumerated selector", a))),
+            _ => Ok(self.clone()),
+        }
+    }
+}
+
+impl TryFrom<&Value> for CaseLabel {
+    type Error = Stop;
+
+    fn try_from(value: &Value) -> Result<Self, Self::Error> {
+        let bound = |v: &Value| v.as_i64().map(|a| a as i128)
+            .or_else(|| v.as_u64().map(|a| a as i128))
+            .ok_or_else(|| error!(format!("Case label must be an integer, got {}", v)));
+
+        match value {
+            Value::Object(range) => {
+                key_reader!(
+                    format!("Parse Case range"),
+                    range {
+                        from,
+                        to,
+                    }
+                );
+                let (from, to) = (bound(from)?, bound(to)?);
+                if from > to {
+                    return Err(error!(format!("Invalid Case range {}..{}", from, to)));
+                }
+                Ok(CaseLabel::Range(from, to))
+            }
+            Value::String(a) => Ok(CaseLabel::Symbol(a.clone())),
+            _ => Ok(CaseLabel::Value(bound(value)?)),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Case {
+    case: JsonTarget,
+    of: Vec<(Vec<CaseLabel>, Vec<JsonTarget>)>,
+    _else: Option<Vec<JsonTarget>>,
+    id: u32
+}
+
+impl NewJsonOperation for Case {
+    fn new(json: &Map<String, Value>) -> Result<Self, Stop> {
+        key_reader!(
+            format!("Parse Case"),
+            json {
+                case,
+                of => as_array,
+                _else? => as_array,
+                id => as_u64,
+            }
+        );
+
+        let id = id as u32;
+
+        let case = parse_json_target(case).map_err(|e| {
+            e.add_sim_trace("Parse Case Operation")
+                .add_id(id)
+        })?;
+
+        let of = of
+            .iter()
+            .map(|branch| {
+                let branch = branch.as_object()
+                    .ok_or_else(|| error!(format!("Case branch is not an object: {}", branch)))?;
+                key_reader!(
+                    format!("Parse Case branch"),
+                    branch {
+                        values => as_array,
+                        then => as_array,
+                    }
+                );
+                let values = values
+                    .iter()
+                    .map(CaseLabel::try_from)
+                    .collect::<Result<Vec<CaseLabel>, Stop>>()?;
+                let then = then
+                    .iter()
+                    .map(parse_json_target)
+                    .collect::<Result<Vec<JsonTarget>, Stop>>()?;
+                Ok((values, then))
+            })
+            .collect::<Result<Vec<_>, Stop>>()
+            .map_err(|e| e.add_id(id))?;
+
+        let _else = match _else {
+            Some(operations) => Some(
+                operations
+                    .iter()
+                    .map(parse_json_target)
+                    .collect::<Result<Vec<JsonTarget>, Stop>>()?,
+            ),
+            None => None,
+        };
+
+        Ok(Self {
+            case,
+            of,
+            _else,
+            id,
+        })
+    }
+}
+
+impl BuildJsonOperation for Case {
+    fn build(
+        &self,
+        interface: &SectionInterface,
+        template: Option<&TemplateMemory>,
+        registry: &Kernel,
+        channel: &Broadcast
+    ) -> Result<RunTimeOperation, Stop> {
+        let case = self
+            .case
+            .solve_to_ref(interface, template, None, registry, channel)
+            .map_err(|e| {
+                e.add_sim_trace("Build Case -> Build selector")
+                    .add_id(self.id)
+            })?;
+
+        let kind = match ConvertKind::of(&case) {
+            Some(kind @ (ConvertKind::Integer { .. } | ConvertKind::Binary { .. })) => kind,
+            _ => return Err(error!(format!("Case selector must be an integer, got {}", case)).add_id(self.id)),
+        };
+
+        let plc_enum = registry.get_enum_of(&case);
+
+        let of = self
+            .of
+            .iter()
+            .map(|(values, then)| {
+                let values = values
+                    .iter()
+                    .map(|label| label.resolve(plc_enum.as_deref()))
+                    .collect::<Result<Vec<CaseLabel>, Stop>>()?;
+                let then = then
+                    .iter()
+                    .map(|i| i.solve_as_operation(interface, template, registry, channel))
+                    .collect::<Result<Vec<RunTimeOperation>, Stop>>()?;
+                Ok((values, then))
+            })
+            .collect::<Result<Vec<_>, Stop>>()
+            .map_err(|e| {
+                e.add_sim_trace("Build Case -> Build branches")
+                    .add_id(self.id)
+            })?;
+
+        let _else: Option<Vec<RunTimeOperation>> = match &self._else {
+            Some(target) => Some(
+                target
+                    .iter()
+                    .map(|i| i.solve_as_operation(interface, template, registry, channel))
+                    .collect::<Result<Vec<RunTimeOperation>, Stop>>()
+                    .map_err(|e| {
+                        e.add_sim_trace("Build Case -> Build else operations")
+                            .add_id(self.id)
+                    })?,
+            ),
+            None => None,
+        };
+
+        let case_clone = case.clone();
+        let control = registry.loops.current();
+
+        Ok(Box::new(Operation::new(
+            MaybeHeapOrStatic(Some(HeapOrStatic::Closure(Rc::new(RefCell::new(move || format!("Case {}", case_clone)))))),
+            move |channel| {
+                let value = match read_value(&case, kind, channel)? {
+                    ConvertValue::Integer(a) => a,
+                    ConvertValue::Binary(a) => a as i128,
+                    other => return Err(error!(format!("Invalid Case selector {:?}", other))),
+                };
+
+                let branch = of
+                    .iter()
+                    .position(|(values, _)| values.iter().any(|label| label.matches(value)));
+
+                let log = match branch {
+                    Some(index) => format!("[Case]: {} -> Branch {}", value, index + 1),
+                    None if _else.is_some() => format!("[Case]: {} -> Else", value),
+                    None => format!("[Case]: {} -> No branch", value),
+                };
+                channel.get_cycle_stack().borrow_mut().insert_log(&log);
+
+                let operations = match branch {
+                    Some(index) => Some(&of[index].1),
+                    None => _else.as_ref(),
+                };
+                if let Some(operations) = operations {
+                    run_operations(operations, &control, channel)?;
+                }
+                Ok(())
+            },
+            None,
+            false,
+            self.id
+        )))
+    }
+}
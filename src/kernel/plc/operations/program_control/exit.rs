@@ -0,0 +1,56 @@
+use crate::kernel::plc::interface::section_interface::SectionInterface;
+use crate::kernel::plc::internal::template_impl::TemplateMemory;
+use crate::kernel::plc::operations::operations::{
+    BuildJsonOperation, NewJsonOperation, Operation, RunTimeOperation,
+};
+use crate::kernel::plc::operations::program_control::loop_control::LoopSignal;
+use crate::kernel::registry::Kernel;
+use crate::container::error::error::Stop;
+use serde_json::{Map, Value};
+use crate::container::broadcast::broadcast::Broadcast;
+use crate::{error, key_reader};
+use crate::kernel::plc::types::primitives::traits::meta_data::{HeapOrStatic, MaybeHeapOrStatic};
+
+#[derive(Clone)]
+pub struct Exit {
+    id: u32
+}
+
+impl NewJsonOperation for Exit {
+    fn new(json: &Map<String, Value>) -> Result<Self, Stop> {
+        key_reader!(
+            format!("Parse Exit"),
+            json {
+                id => as_u64,
+            }
+        );
+
+        let id = id as u32;
+
+        Ok(Self {id})
+    }
+}
+
+impl BuildJsonOperation for Exit {
+    fn build(
+        &self,
+        _interface: &SectionInterface,
+        _template: Option<&TemplateMemory>,
+        registry: &Kernel,
+        _channel: &Broadcast
+    ) -> Result<RunTimeOperation, Stop> {
+        let control = registry.loops.current()
+            .ok_or_else(|| error!(format!("Exit used outside of a loop")).add_id(self.id))?;
+
+        Ok(Box::new(Operation::new(
+            MaybeHeapOrStatic(Some(HeapOrStatic::Static("Exit"))),
+            move |_channel| {
+                control.raise(LoopSignal::Exit);
+                Ok(())
+            },
+            None,
+            false,
+            self.id
+        )))
+    }
+}
@@ -13,13 +13,14 @@ use crate::kernel::registry::Kernel;
 use crate::container::error::error::Stop;
 use crate::{error, key_reader};
 use serde_json::{Map, Value};
-use web_time::Instant;
 use crate::parser::body::body::parse_json_target;
 use crate::container::broadcast::broadcast::Broadcast;
 use crate::container::container::THOUSAND_MS;
+use crate::container::simulation::clock::LOOP_ITERATION_COST;
 use crate::kernel::rust::operations::box_add_plc_primitive;
 use crate::kernel::plc::types::primitives::traits::meta_data::{HeapOrStatic, MaybeHeapOrStatic};
 use crate::kernel::arch::any::any_type::AnyRefType;
+use crate::kernel::plc::operations::program_control::loop_control::run_iteration;
 
 
 #[derive(Clone)]
@@ -99,11 +100,12 @@ impl BuildJsonOperation for For {
             Some(a) => Some(a.solve_to_ref(interface, template, Some(_for.as_ref().borrow().deref().clone()), registry, channel).map_err(|e|e.add_id(self.id))?)
         };
 
-        let mut _do: Vec<RunTimeOperation> = self
+        let (control, _do) = registry.loops.with_loop(|| self
             ._do
             .iter()
             .map(|i| i.solve_as_operation(interface, template, registry, channel))
-            .collect::<Result<Vec<RunTimeOperation>, Stop>>().map_err(|e|e.add_id(self.id))?;
+            .collect::<Result<Vec<RunTimeOperation>, Stop>>());
+        let _do = _do.map_err(|e|e.add_id(self.id))?;
 
         let for_to = box_ord_plc_primitive(&_for, &to, self.id, registry).map_err(|e|e.add_id(self.id))?;
 
@@ -131,16 +133,18 @@ impl BuildJsonOperation for For {
         };
 
         let id = self.id;
+        let clock = registry.clock.clone();
 
         Ok(Box::new(Operation::new(
             display,
             move |channel| {
                 for_with.with_void(channel)?;
-                let earlier = Instant::now();
+                let earlier = clock.now();
+                control.take();
 
                 while for_to(channel)?.unwrap().is_ne() {
-                    for operation in &_do {
-                        operation.with_void(channel).map_err(|e|e.add_id(id))?;
+                    if !run_iteration(&_do, &control, channel).map_err(|e|e.add_id(id))? {
+                        break;
                     }
 
                     // Increment
@@ -148,7 +152,8 @@ impl BuildJsonOperation for For {
                         incr.with_void(channel).map_err(|e|e.add_id(id))?;
                     }
 
-                    let elapsed = Instant::now().duration_since(earlier);
+                    clock.charge(LOOP_ITERATION_COST);
+                    let elapsed = clock.elapsed_since(earlier);
                     if elapsed > THOUSAND_MS {
                         return match &by {
                             None => Err(error!(format!("For of loop took longer than 100 ms to execute. \nStatus of loop: FOR {} := {} TO {}", _for, with, to)))
@@ -9,6 +9,7 @@ use crate::kernel::plc::operations::operations::{
 };
 use crate::kernel::plc::types::primitives::traits::family_traits::{WithMutFamily, WithRefFamily};
 use crate::kernel::plc::types::primitives::traits::meta_data::{HeapOrStatic, MaybeHeapOrStatic};
+use crate::kernel::plc::operations::program_control::loop_control::run_operations;
 use crate::kernel::plc::types::primitives::traits::primitive_traits::PrimitiveTrait;
 use crate::kernel::registry::Kernel;
 use crate::container::error::error::Stop;
@@ -112,20 +113,17 @@ impl BuildJsonOperation for If {
         };
 
         let if_clone = self._if.clone();
+        let control = registry.loops.current();
 
         Ok(Box::new(Operation::new(
             MaybeHeapOrStatic(Some(HeapOrStatic::Closure(Rc::new(RefCell::new(move || format!("If {}", if_clone)))))),
             move |channel| {
                 _if.with_plc_bool(channel, |a| {
                     if a.as_bool().unwrap().get(channel)? {
-                        for operation in &then {
-                            operation.with_void(channel)?;
-                        }
+                        run_operations(&then, &control, channel)?;
                     } else if _else.is_some() {
                         let else_operations = _else.as_ref().unwrap();
-                        for operation in else_operations {
-                            operation.with_void(channel)?;
-                        }
+                        run_operations(else_operations, &control, channel)?;
                     };
                     Ok(())
                 })??;
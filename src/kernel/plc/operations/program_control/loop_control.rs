@@ -0,0 +1,81 @@
+use core::cell::{Cell, RefCell};
+use std::rc::Rc;
+use crate::container::broadcast::broadcast::Broadcast;
+use crate::container::error::error::Stop;
+use crate::kernel::plc::operations::operations::{RunTimeOperation, RuntimeOperationTrait};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LoopSignal {
+    Exit,
+    Continue,
+}
+
+/// Signal raised by Exit or Continue and consumed by the innermost enclosing loop.
+#[derive(Clone, Default)]
+pub struct LoopControl(Rc<Cell<Option<LoopSignal>>>);
+
+impl LoopControl {
+    pub fn raise(&self, signal: LoopSignal) {
+        self.0.set(Some(signal))
+    }
+
+    pub fn is_raised(&self) -> bool {
+        self.0.get().is_some()
+    }
+
+    pub fn take(&self) -> Option<LoopSignal> {
+        self.0.take()
+    }
+}
+
+/// Loops currently being built, the innermost last.
+///
+/// Operations built inside a loop capture its control, so an Exit nested in an If
+/// still reaches the loop. The body of a called block starts a new scope.
+#[derive(Default)]
+pub struct LoopScopes(RefCell<Vec<LoopControl>>);
+
+impl LoopScopes {
+    /// Builds the body of a loop, returns its control with the result.
+    pub fn with_loop<R>(&self, f: impl FnOnce() -> R) -> (LoopControl, R) {
+        let control = LoopControl::default();
+        self.0.borrow_mut().push(control.clone());
+        let result = f();
+        self.0.borrow_mut().pop();
+        (control, result)
+    }
+
+    /// Builds the body of a block, loops of the caller are not reachable from it.
+    pub fn isolated<R>(&self, f: impl FnOnce() -> R) -> R {
+        let outer = self.0.replace(Vec::new());
+        let result = f();
+        self.0.replace(outer);
+        result
+    }
+
+    pub fn current(&self) -> Option<LoopControl> {
+        self.0.borrow().last().cloned()
+    }
+}
+
+/// Runs operations until one of them raises the control of the enclosing loop.
+pub fn run_operations(operations: &[RunTimeOperation], control: &Option<LoopControl>, channel: &Broadcast) -> Result<(), Stop> {
+    for operation in operations {
+        operation.with_void(channel)?;
+        if control.as_ref().is_some_and(|c| c.is_raised()) {
+            break;
+        }
+    }
+    Ok(())
+}
+
+/// Runs one iteration of a loop body, returns false when the loop has to be left.
+pub fn run_iteration(operations: &[RunTimeOperation], control: &LoopControl, channel: &Broadcast) -> Result<bool, Stop> {
+    for operation in operations {
+        operation.with_void(channel)?;
+        if control.is_raised() {
+            break;
+        }
+    }
+    Ok(control.take() != Some(LoopSignal::Exit))
+}
@@ -2,3 +2,8 @@ pub mod r#if;
 pub mod r#for;
 pub mod r#while;
 pub mod r#return;
+pub mod case;
+pub mod repeat;
+pub mod exit;
+pub mod r#continue;
+pub mod loop_control;
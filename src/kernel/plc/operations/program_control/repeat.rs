@@ -0,0 +1,112 @@
+use core::cell::RefCell;
+use std::rc::Rc;
+use crate::parser::body::json_target::JsonTarget;
+use crate::kernel::plc::interface::section_interface::SectionInterface;
+use crate::kernel::plc::internal::template_impl::TemplateMemory;
+use crate::kernel::plc::operations::operations::{
+    BuildJsonOperation, NewJsonOperation, Operation, RunTimeOperation,
+};
+use crate::kernel::registry::Kernel;
+use crate::container::error::error::Stop;
+use crate::{error, key_reader};
+use serde_json::{Map, Value};
+use crate::parser::body::body::parse_json_target;
+use crate::container::broadcast::broadcast::Broadcast;
+use crate::container::container::THOUSAND_MS;
+use crate::container::simulation::clock::LOOP_ITERATION_COST;
+use crate::kernel::plc::types::primitives::traits::primitive_traits::Primitive;
+use crate::kernel::plc::types::primitives::traits::meta_data::{HeapOrStatic, MaybeHeapOrStatic};
+use crate::kernel::plc::operations::program_control::loop_control::run_iteration;
+
+/// REPEAT .. UNTIL, the body runs at least once.
+#[derive(Clone)]
+pub struct Repeat {
+    _do: Vec<JsonTarget>,
+    until: JsonTarget,
+    id: u32,
+}
+
+impl NewJsonOperation for Repeat {
+    fn new(json: &Map<String, Value>) -> Result<Self, Stop> {
+        key_reader!(
+            format!("Parse Repeat"),
+            json {
+                _do => as_array,
+                until,
+                id => as_u64,
+            }
+        );
+
+        let id = id as u32;
+
+        let until = parse_json_target(until).map_err(|e|e.add_id(id))?;
+
+        let _do = _do
+            .iter()
+            .map(parse_json_target)
+            .collect::<Result<Vec<JsonTarget>, Stop>>().map_err(|e|e.add_id(id))?;
+
+        Ok(Self {
+            _do,
+            until,
+            id,
+        })
+    }
+}
+
+impl BuildJsonOperation for Repeat {
+    fn build(
+        &self,
+        interface: &SectionInterface,
+        template: Option<&TemplateMemory>,
+        registry: &Kernel,
+        channel: &Broadcast
+    ) -> Result<RunTimeOperation, Stop> {
+
+        let until = self
+            .until
+            .solve_to_ref(interface, template, None, registry, channel)
+            .map_err(|e|e.add_id(self.id))?;
+
+        let (control, _do) = registry.loops.with_loop(|| self
+            ._do
+            .iter()
+            .map(|i| i.solve_as_operation(interface, template, registry, channel))
+            .collect::<Result<Vec<RunTimeOperation>, Stop>>());
+        let _do = _do.map_err(|e|e.add_id(self.id))?;
+
+        let until_clone = until.clone();
+        let id = self.id;
+        let clock = registry.clock.clone();
+
+        Ok(Box::new(Operation::new(
+            MaybeHeapOrStatic(Some(HeapOrStatic::Closure(Rc::new(RefCell::new(move || format!("Repeat until {}", until_clone)))))),
+            move |channel| {
+                let earlier = clock.now();
+                control.take();
+
+                loop {
+                    // Continue still evaluates the condition
+                    if !run_iteration(&_do, &control, channel).map_err(|e|e.add_id(id))? {
+                        break;
+                    }
+
+                    if until.as_bool(channel)? {
+                        break;
+                    }
+
+                    clock.charge(LOOP_ITERATION_COST);
+                    let elapsed = clock.elapsed_since(earlier);
+                    if elapsed > THOUSAND_MS {
+                      return  Err(error!(format!("Repeat loop took longer than 100 ms to execute.")))
+                            .map_err(|e|e.add_id(id))
+                    };
+                }
+                Ok(())
+            },
+            None,
+            false,
+            self.id
+        )))
+    }
+}
@@ -13,13 +13,14 @@ use crate::kernel::registry::Kernel;
 use crate::container::error::error::Stop;
 use crate::{error, key_reader};
 use serde_json::{Map, Value};
-use web_time::Instant;
 use crate::parser::body::body::parse_json_target;
 use crate::container::broadcast::broadcast::Broadcast;
 use crate::container::container::THOUSAND_MS;
+use crate::container::simulation::clock::LOOP_ITERATION_COST;
 use crate::kernel::rust::operations::box_add_plc_primitive;
 use crate::kernel::plc::types::primitives::traits::primitive_traits::{Primitive, PrimitiveTrait};
 use crate::kernel::plc::types::primitives::traits::meta_data::{HeapOrStatic, MaybeHeapOrStatic};
+use crate::kernel::plc::operations::program_control::loop_control::run_iteration;
 
 #[derive(Clone)]
 pub struct While {
@@ -70,26 +71,30 @@ impl BuildJsonOperation for While {
             .solve_to_ref(interface, template, None, registry, channel)
             .map_err(|e|e.add_id(self.id))?;
 
-        let _do: Vec<RunTimeOperation> = self
+        let (control, _do) = registry.loops.with_loop(|| self
             ._do
             .iter()
             .map(|i| i.solve_as_operation(interface, template, registry, channel))
-            .collect::<Result<Vec<RunTimeOperation>, Stop>>().map_err(|e|e.add_id(self.id))?;
+            .collect::<Result<Vec<RunTimeOperation>, Stop>>());
+        let _do = _do.map_err(|e|e.add_id(self.id))?;
 
         let _while_clone = _while.clone();
         let id = self.id;
+        let clock = registry.clock.clone();
 
         Ok(Box::new(Operation::new(
             MaybeHeapOrStatic(Some(HeapOrStatic::Closure(Rc::new(RefCell::new(move || format!("While {}", _while_clone)))))),
             move |channel| {
-                let earlier = Instant::now();
+                let earlier = clock.now();
+                control.take();
 
                 while _while.as_bool(channel)? {
-                    for operation in &_do {
-                        operation.with_void(channel).map_err(|e|e.add_id(id))?;
+                    if !run_iteration(&_do, &control, channel).map_err(|e|e.add_id(id))? {
+                        break;
                     }
 
-                    let elapsed = Instant::now().duration_since(earlier);
+                    clock.charge(LOOP_ITERATION_COST);
+                    let elapsed = clock.elapsed_since(earlier);
                     if elapsed > THOUSAND_MS {
                       return  Err(error!(format!("While loop took longer than 100 ms to execute.")))
                             .map_err(|e|e.add_id(id))
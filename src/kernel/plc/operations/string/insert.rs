@@ -0,0 +1,76 @@
+use crate::parser::body::json_target::JsonTarget;
+use crate::kernel::plc::interface::section_interface::SectionInterface;
+use crate::kernel::plc::internal::template_impl::TemplateMemory;
+use crate::kernel::plc::operations::operations::{BuildJsonOperation, NewJsonOperation, RunTimeOperation};
+use crate::kernel::registry::Kernel;
+use crate::container::error::error::Stop;
+use crate::{key_reader};
+use serde_json::{Map, Value};
+use crate::parser::body::body::parse_json_target;
+use crate::container::broadcast::broadcast::Broadcast;
+use crate::kernel::rust::string::{box_string_operation, StringOutput, insert};
+use crate::kernel::arch::local::r#type::LocalType;
+use crate::kernel::plc::types::primitives::string::_string::_String;
+use crate::kernel::plc::types::primitives::string::plc_string::PlcString;
+use crate::kernel::plc::types::primitives::integers::dint::DInt;
+use crate::kernel::plc::types::primitives::integers::plc_integer::PlcInteger;
+use crate::kernel::plc::types::primitives::traits::primitive_traits::PrimitiveTrait;
+
+/// INSERT(IN1, IN2, P), IN2 inserted after the P-th character of IN1.
+#[derive(Clone)]
+pub struct Insert {
+    insert: JsonTarget,
+    with: JsonTarget,
+    position: JsonTarget,
+    id: u32,
+}
+
+impl NewJsonOperation for Insert {
+    fn new(json: &Map<String, Value>) -> Result<Self, Stop> {
+        key_reader!(
+            format!("Parse Insert"),
+            json {
+                insert,
+                with,
+                position,
+                id => as_u64,
+            }
+        );
+
+        let id = id as u32;
+
+        let insert = parse_json_target(insert).map_err(|e| e.add_id(id))?;
+        let with = parse_json_target(with).map_err(|e| e.add_id(id))?;
+        let position = parse_json_target(position).map_err(|e| e.add_id(id))?;
+
+        Ok(Self {
+            insert,
+            with,
+            position,
+            id
+        })
+    }
+}
+
+impl BuildJsonOperation for Insert {
+    fn build(
+        &self,
+        interface: &SectionInterface,
+        template: Option<&TemplateMemory>,
+        registry: &Kernel,
+        channel: &Broadcast
+    ) -> Result<RunTimeOperation, Stop> {
+        let string = LocalType::PlcString(PlcString::_String(_String::new_default(0)));
+        let integer = LocalType::PlcInteger(PlcInteger::DInt(DInt::new_default(0)));
+        let texts = vec![
+            self.insert.solve_to_ref(interface, template, Some(string.clone()), registry, channel)?,
+            self.with.solve_to_ref(interface, template, Some(string.clone()), registry, channel)?,
+        ];
+        let integers = vec![
+            self.position.solve_to_ref(interface, template, Some(integer.clone()), registry, channel)?,
+        ];
+        box_string_operation("Insert", texts, integers, true, move |texts, integers| {
+            Ok(StringOutput::Text(insert(&texts[0], &texts[1], integers[0])?))
+        }, self.id).map_err(|e| e.add_id(self.id))
+    }
+}
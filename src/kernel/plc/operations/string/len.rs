@@ -0,0 +1,62 @@
+use crate::parser::body::json_target::JsonTarget;
+use crate::kernel::plc::interface::section_interface::SectionInterface;
+use crate::kernel::plc::internal::template_impl::TemplateMemory;
+use crate::kernel::plc::operations::operations::{BuildJsonOperation, NewJsonOperation, RunTimeOperation};
+use crate::kernel::registry::Kernel;
+use crate::container::error::error::Stop;
+use crate::{key_reader};
+use serde_json::{Map, Value};
+use crate::parser::body::body::parse_json_target;
+use crate::container::broadcast::broadcast::Broadcast;
+use crate::kernel::rust::string::{box_string_operation, StringOutput};
+use crate::kernel::arch::local::r#type::LocalType;
+use crate::kernel::plc::types::primitives::string::_string::_String;
+use crate::kernel::plc::types::primitives::string::plc_string::PlcString;
+use crate::kernel::plc::types::primitives::traits::primitive_traits::PrimitiveTrait;
+
+/// LEN(IN), number of characters of a string.
+#[derive(Clone)]
+pub struct Len {
+    len: JsonTarget,
+    id: u32,
+}
+
+impl NewJsonOperation for Len {
+    fn new(json: &Map<String, Value>) -> Result<Self, Stop> {
+        key_reader!(
+            format!("Parse Len"),
+            json {
+                len,
+                id => as_u64,
+            }
+        );
+
+        let id = id as u32;
+
+        let len = parse_json_target(len).map_err(|e| e.add_id(id))?;
+
+        Ok(Self {
+            len,
+            id
+        })
+    }
+}
+
+impl BuildJsonOperation for Len {
+    fn build(
+        &self,
+        interface: &SectionInterface,
+        template: Option<&TemplateMemory>,
+        registry: &Kernel,
+        channel: &Broadcast
+    ) -> Result<RunTimeOperation, Stop> {
+        let string = LocalType::PlcString(PlcString::_String(_String::new_default(0)));
+        let texts = vec![
+            self.len.solve_to_ref(interface, template, Some(string.clone()), registry, channel)?,
+        ];
+        let integers = vec![];
+        box_string_operation("Len", texts, integers, false, move |texts, _| {
+            Ok(StringOutput::Integer(texts[0].chars().count() as i128))
+        }, self.id).map_err(|e| e.add_id(self.id))
+    }
+}
@@ -0,0 +1,9 @@
+pub mod len;
+pub mod concat;
+pub mod left;
+pub mod right;
+pub mod mid;
+pub mod find;
+pub mod insert;
+pub mod delete;
+pub mod replace;
@@ -0,0 +1,81 @@
+use crate::parser::body::json_target::JsonTarget;
+use crate::kernel::plc::interface::section_interface::SectionInterface;
+use crate::kernel::plc::internal::template_impl::TemplateMemory;
+use crate::kernel::plc::operations::operations::{BuildJsonOperation, NewJsonOperation, RunTimeOperation};
+use crate::kernel::registry::Kernel;
+use crate::container::error::error::Stop;
+use crate::{key_reader};
+use serde_json::{Map, Value};
+use crate::parser::body::body::parse_json_target;
+use crate::container::broadcast::broadcast::Broadcast;
+use crate::kernel::rust::string::{box_string_operation, StringOutput, replace};
+use crate::kernel::arch::local::r#type::LocalType;
+use crate::kernel::plc::types::primitives::string::_string::_String;
+use crate::kernel::plc::types::primitives::string::plc_string::PlcString;
+use crate::kernel::plc::types::primitives::integers::dint::DInt;
+use crate::kernel::plc::types::primitives::integers::plc_integer::PlcInteger;
+use crate::kernel::plc::types::primitives::traits::primitive_traits::PrimitiveTrait;
+
+/// REPLACE(IN1, IN2, L, P), replaces L characters of IN1 from the position P with IN2.
+#[derive(Clone)]
+pub struct Replace {
+    replace: JsonTarget,
+    with: JsonTarget,
+    length: JsonTarget,
+    position: JsonTarget,
+    id: u32,
+}
+
+impl NewJsonOperation for Replace {
+    fn new(json: &Map<String, Value>) -> Result<Self, Stop> {
+        key_reader!(
+            format!("Parse Replace"),
+            json {
+                replace,
+                with,
+                length,
+                position,
+                id => as_u64,
+            }
+        );
+
+        let id = id as u32;
+
+        let replace = parse_json_target(replace).map_err(|e| e.add_id(id))?;
+        let with = parse_json_target(with).map_err(|e| e.add_id(id))?;
+        let length = parse_json_target(length).map_err(|e| e.add_id(id))?;
+        let position = parse_json_target(position).map_err(|e| e.add_id(id))?;
+
+        Ok(Self {
+            replace,
+            with,
+            length,
+            position,
+            id
+        })
+    }
+}
+
+impl BuildJsonOperation for Replace {
+    fn build(
+        &self,
+        interface: &SectionInterface,
+        template: Option<&TemplateMemory>,
+        registry: &Kernel,
+        channel: &Broadcast
+    ) -> Result<RunTimeOperation, Stop> {
+        let string = LocalType::PlcString(PlcString::_String(_String::new_default(0)));
+        let integer = LocalType::PlcInteger(PlcInteger::DInt(DInt::new_default(0)));
+        let texts = vec![
+            self.replace.solve_to_ref(interface, template, Some(string.clone()), registry, channel)?,
+            self.with.solve_to_ref(interface, template, Some(string.clone()), registry, channel)?,
+        ];
+        let integers = vec![
+            self.length.solve_to_ref(interface, template, Some(integer.clone()), registry, channel)?,
+            self.position.solve_to_ref(interface, template, Some(integer.clone()), registry, channel)?,
+        ];
+        box_string_operation("Replace", texts, integers, true, move |texts, integers| {
+            Ok(StringOutput::Text(replace(&texts[0], &texts[1], integers[0], integers[1])?))
+        }, self.id).map_err(|e| e.add_id(self.id))
+    }
+}
@@ -0,0 +1,71 @@
+use crate::parser::body::json_target::JsonTarget;
+use crate::kernel::plc::interface::section_interface::SectionInterface;
+use crate::kernel::plc::internal::template_impl::TemplateMemory;
+use crate::kernel::plc::operations::operations::{BuildJsonOperation, NewJsonOperation, RunTimeOperation};
+use crate::kernel::registry::Kernel;
+use crate::container::error::error::Stop;
+use crate::{key_reader};
+use serde_json::{Map, Value};
+use crate::parser::body::body::parse_json_target;
+use crate::container::broadcast::broadcast::Broadcast;
+use crate::kernel::rust::string::{box_string_operation, StringOutput, right};
+use crate::kernel::arch::local::r#type::LocalType;
+use crate::kernel::plc::types::primitives::string::_string::_String;
+use crate::kernel::plc::types::primitives::string::plc_string::PlcString;
+use crate::kernel::plc::types::primitives::integers::dint::DInt;
+use crate::kernel::plc::types::primitives::integers::plc_integer::PlcInteger;
+use crate::kernel::plc::types::primitives::traits::primitive_traits::PrimitiveTrait;
+
+/// RIGHT(IN, L), the last L characters of a string.
+#[derive(Clone)]
+pub struct Right {
+    right: JsonTarget,
+    length: JsonTarget,
+    id: u32,
+}
+
+impl NewJsonOperation for Right {
+    fn new(json: &Map<String, Value>) -> Result<Self, Stop> {
+        key_reader!(
+            format!("Parse Right"),
+            json {
+                right,
+                length,
+                id => as_u64,
+            }
+        );
+
+        let id = id as u32;
+
+        let right = parse_json_target(right).map_err(|e| e.add_id(id))?;
+        let length = parse_json_target(length).map_err(|e| e.add_id(id))?;
+
+        Ok(Self {
+            right,
+            length,
+            id
+        })
+    }
+}
+
+impl BuildJsonOperation for Right {
+    fn build(
+        &self,
+        interface: &SectionInterface,
+        template: Option<&TemplateMemory>,
+        registry: &Kernel,
+        channel: &Broadcast
+    ) -> Result<RunTimeOperation, Stop> {
+        let string = LocalType::PlcString(PlcString::_String(_String::new_default(0)));
+        let integer = LocalType::PlcInteger(PlcInteger::DInt(DInt::new_default(0)));
+        let texts = vec![
+            self.right.solve_to_ref(interface, template, Some(string.clone()), registry, channel)?,
+        ];
+        let integers = vec![
+            self.length.solve_to_ref(interface, template, Some(integer.clone()), registry, channel)?,
+        ];
+        box_string_operation("Right", texts, integers, true, move |texts, integers| {
+            Ok(StringOutput::Text(right(&texts[0], integers[0])?))
+        }, self.id).map_err(|e| e.add_id(self.id))
+    }
+}
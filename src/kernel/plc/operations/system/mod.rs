@@ -0,0 +1,3 @@
+pub mod rd_sys_t;
+pub mod rd_loc_t;
+pub mod wr_sys_t;
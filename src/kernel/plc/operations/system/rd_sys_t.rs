@@ -0,0 +1,50 @@
+use crate::kernel::plc::interface::section_interface::SectionInterface;
+use crate::kernel::plc::internal::template_impl::TemplateMemory;
+use crate::kernel::plc::operations::operations::{BuildJsonOperation, NewJsonOperation, RunTimeOperation};
+use crate::kernel::registry::Kernel;
+use crate::container::error::error::Stop;
+use crate::key_reader;
+use serde_json::{Map, Value};
+use crate::container::broadcast::broadcast::Broadcast;
+use crate::kernel::rust::date::box_read_clock;
+use crate::parser::local_type::constant_type::create_default_constant_from_str;
+use crate::kernel::arch::local::r#type::IntoLocalType;
+
+/// RD_SYS_T, the system time (UTC) of the simulated clock.
+///
+/// Returns a DTL unless another date type is given in `to`.
+#[derive(Clone)]
+pub struct RdSysT {
+    to: String,
+    id: u32,
+}
+
+impl NewJsonOperation for RdSysT {
+    fn new(json: &Map<String, Value>) -> Result<Self, Stop> {
+        key_reader!(
+            format!("Parse RdSysT"),
+            json {
+                to? => as_str,
+                id => as_u64,
+            }
+        );
+
+        Ok(Self {
+            to: to.unwrap_or("Dtl").to_string(),
+            id: id as u32,
+        })
+    }
+}
+
+impl BuildJsonOperation for RdSysT {
+    fn build(
+        &self,
+        _interface: &SectionInterface,
+        _template: Option<&TemplateMemory>,
+        registry: &Kernel,
+        _channel: &Broadcast
+    ) -> Result<RunTimeOperation, Stop> {
+        let to = create_default_constant_from_str(&self.to).map_err(|e| e.add_id(self.id))?.transform()?;
+        box_read_clock("rd_sys_t", to, false, registry.clock.clone(), self.id)
+    }
+}
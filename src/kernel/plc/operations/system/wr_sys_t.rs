@@ -0,0 +1,52 @@
+use crate::parser::body::json_target::JsonTarget;
+use crate::kernel::plc::interface::section_interface::SectionInterface;
+use crate::kernel::plc::internal::template_impl::TemplateMemory;
+use crate::kernel::plc::operations::operations::{BuildJsonOperation, NewJsonOperation, RunTimeOperation};
+use crate::kernel::registry::Kernel;
+use crate::container::error::error::Stop;
+use crate::key_reader;
+use serde_json::{Map, Value};
+use crate::parser::body::body::parse_json_target;
+use crate::container::broadcast::broadcast::Broadcast;
+use crate::kernel::rust::date::box_write_clock;
+
+/// WR_SYS_T, sets the system time (UTC) of the simulated clock.
+#[derive(Clone)]
+pub struct WrSysT {
+    wr_sys_t: JsonTarget,
+    id: u32,
+}
+
+impl NewJsonOperation for WrSysT {
+    fn new(json: &Map<String, Value>) -> Result<Self, Stop> {
+        key_reader!(
+            format!("Parse WrSysT"),
+            json {
+                wr_sys_t,
+                id => as_u64,
+            }
+        );
+
+        let id = id as u32;
+
+        let wr_sys_t = parse_json_target(wr_sys_t).map_err(|e| e.add_id(id))?;
+
+        Ok(Self {
+            wr_sys_t,
+            id
+        })
+    }
+}
+
+impl BuildJsonOperation for WrSysT {
+    fn build(
+        &self,
+        interface: &SectionInterface,
+        template: Option<&TemplateMemory>,
+        registry: &Kernel,
+        channel: &Broadcast
+    ) -> Result<RunTimeOperation, Stop> {
+        let wr_sys_t = self.wr_sys_t.solve_to_ref(interface, template, None, registry, channel)?;
+        box_write_clock(&wr_sys_t, registry.clock.clone(), self.id)
+    }
+}
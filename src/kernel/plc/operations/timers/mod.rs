@@ -0,0 +1,4 @@
+pub mod tp;
+pub mod ton;
+pub mod tof;
+pub mod tonr;
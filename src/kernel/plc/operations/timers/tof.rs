@@ -0,0 +1,67 @@
+use crate::parser::body::json_target::JsonTarget;
+use crate::kernel::plc::interface::section_interface::SectionInterface;
+use crate::kernel::plc::internal::template_impl::TemplateMemory;
+use crate::kernel::plc::operations::operations::{BuildJsonOperation, NewJsonOperation, RunTimeOperation};
+use crate::kernel::arch::local::pointer::LocalPointer;
+use crate::kernel::registry::Kernel;
+use crate::container::error::error::Stop;
+use crate::{error, key_reader};
+use serde_json::{Map, Value};
+use crate::parser::body::body::parse_json_target;
+use crate::container::broadcast::broadcast::Broadcast;
+use crate::kernel::rust::timer::{box_iec_timer, TimerKind};
+
+/// TOF, off-delay timer.
+#[derive(Clone)]
+pub struct Tof {
+    input: JsonTarget,
+    preset: JsonTarget,
+    q: Option<JsonTarget>,
+    et: Option<JsonTarget>,
+    id: u32,
+}
+
+impl NewJsonOperation for Tof {
+    fn new(json: &Map<String, Value>) -> Result<Self, Stop> {
+        key_reader!(
+            format!("Parse Tof"),
+            json {
+                input,
+                preset,
+                q?,
+                et?,
+                id => as_u64,
+            }
+        );
+
+        Ok(Self {
+            input: parse_json_target(input)?,
+            preset: parse_json_target(preset)?,
+            q: q.map(parse_json_target).transpose()?,
+            et: et.map(parse_json_target).transpose()?,
+            id: id as u32,
+        })
+    }
+}
+
+impl BuildJsonOperation for Tof {
+    fn build(
+        &self,
+        interface: &SectionInterface,
+        template: Option<&TemplateMemory>,
+        registry: &Kernel,
+        channel: &Broadcast
+    ) -> Result<RunTimeOperation, Stop> {
+        let input = self.input.solve_to_ref(interface, template, None, registry, channel)?;
+        let preset = self.preset.solve_to_ref(interface, template, None, registry, channel)?;
+        let output = |target: &Option<JsonTarget>| -> Result<Option<LocalPointer>, Stop> {
+            target
+                .as_ref()
+                .map(|a| a
+                    .solve_as_local_pointer(interface, template, registry, channel)
+                    .ok_or_else(|| error!(format!("Expected a valid reference, got {}", a), "Build Tof -> output".to_string()).add_id(self.id)))
+                .transpose()
+        };
+        box_iec_timer(TimerKind::Tof, &input, &preset, None, output(&self.q)?, output(&self.et)?, registry.clock.clone(), self.id, registry)
+    }
+}
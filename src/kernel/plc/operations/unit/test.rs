@@ -31,6 +31,9 @@ pub struct UnitTest {
     description: String,
     id: u32,
     status: UnitTestStatus,
+    fail_message: Option<String>,
+    cycle: Option<u64>,
+    block: Option<String>,
 }
 
 #[wasm_bindgen]
@@ -40,6 +43,9 @@ impl UnitTest {
             id,
             description,
             status: UnitTestStatus::Unreached,
+            fail_message: None,
+            cycle: None,
+            block: None,
         }
     }
 
@@ -57,12 +63,43 @@ impl UnitTest {
     pub fn get_status(&self) -> UnitTestStatus {
         self.status
     }
+
+    #[wasm_bindgen(getter)]
+    pub fn get_fail_message(&self) -> Option<String> {
+        self.fail_message.clone()
+    }
+
+    /// Cycle of the last run of the test
+    #[wasm_bindgen(getter)]
+    pub fn get_cycle(&self) -> Option<u64> {
+        self.cycle
+    }
+
+    /// Block (Ob, Fb, Fc or unit block) the test last ran in
+    #[wasm_bindgen(getter)]
+    pub fn get_block(&self) -> Option<String> {
+        self.block.clone()
+    }
 }
 
 impl UnitTest {
     pub fn set_status(&mut self, status: UnitTestStatus) {
         self.status = status
     }
+
+    pub fn update(&mut self, status: &UnitTestUpdateStatus, cycle: u64, block: Option<String>) {
+        self.status = status.get_status();
+        self.fail_message = status.get_fail_message();
+        self.cycle = Some(cycle);
+        self.block = block;
+    }
+
+    pub fn reset(&mut self) {
+        self.status = UnitTestStatus::Unreached;
+        self.fail_message = None;
+        self.cycle = None;
+        self.block = None;
+    }
 }
 
 impl Display for UnitTest {
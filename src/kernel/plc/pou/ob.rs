@@ -10,11 +10,25 @@ use crate::container::broadcast::broadcast::Broadcast;
 use crate::container::error::error::Stop;
 use crate::{create_block_interface, error, key_reader};
 use serde_json::{Map, Value};
+use core::time::Duration;
 use crate::parser::body::body::parse_json_target;
 use crate::kernel::registry::get_string;
 
+/// When an Ob is executed by the simulation.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum ObKind {
+    /// Program cycle, executed every scan when used as entry
+    Main,
+    /// Executed once, in the first cycle of a simulation
+    Startup,
+    /// Cyclic interrupt, executed each time its interval elapsed on the simulated clock
+    Cyclic(Duration),
+}
+
 pub struct Ob {
     json: Map<String, Value>,
+    kind: ObKind,
+    priority: u64,
     interface: SectionInterface,
     interface_status: InterfaceStatus,
     body_status: BodyStatus,
@@ -41,12 +55,53 @@ impl Ob {
     pub fn get_interface(&self) -> &SectionInterface {
         &self.interface
     }
+
+    pub fn get_kind(&self) -> ObKind {
+        self.kind
+    }
+
+    /// Obs scheduled in the same cycle run by descending priority
+    pub fn get_priority(&self) -> u64 {
+        self.priority
+    }
+
+    fn parse_kind(&mut self) -> Result<(), Stop> {
+        let data = &self.json;
+        key_reader!(
+            format!("Parse Ob kind"),
+            data {
+                kind? => as_str,
+                priority? => as_u64,
+                interval? => as_u64,
+            }
+        );
+
+        self.kind = match (kind.unwrap_or("main"), interval) {
+            ("main", _) => ObKind::Main,
+            ("startup", _) => ObKind::Startup,
+            ("cyclic", Some(interval)) if interval > 0 => ObKind::Cyclic(Duration::from_millis(interval)),
+            ("cyclic", _) => return Err(error!(format!("A cyclic Ob requires an interval greater than 0 ms"))),
+            (other, _) => return Err(error!(format!("Invalid Ob kind '{}', expected main, startup or cyclic", other))),
+        };
+
+        self.priority = match priority {
+            Some(0) => return Err(error!(format!("Ob priority must be greater than 0"))),
+            Some(a) => a,
+            None => match self.kind {
+                ObKind::Cyclic(_) => 7,
+                _ => 1,
+            },
+        };
+        Ok(())
+    }
 }
 
 impl DeferredBuilder for Ob {
     fn default(json: &Map<String, Value>) -> Self {
         Self {
             json: json.clone(),
+            kind: ObKind::Main,
+            priority: 1,
             interface: SectionInterface::new(),
             interface_status: InterfaceStatus::Default,
             body_status: BodyStatus::Default,
@@ -57,6 +112,7 @@ impl DeferredBuilder for Ob {
 
     fn build_interface(&mut self, registry: &Kernel, channel: &Broadcast) -> Result<(), Stop> {
         self.interface_status = InterfaceStatus::Pending;
+        self.parse_kind().map_err(|e| e.add_sim_trace("Build Ob").add_id(self.id))?;
         let data = &self.json;
 
         key_reader!(
@@ -1,6 +1,6 @@
 ﻿use std::borrow::Cow;
 use crate::{error, impl_primitive_traits, key_reader};
-use crate::kernel::plc::interface::array_interface::ArrayInterface;
+use crate::kernel::plc::interface::array_interface::{array_length, ArrayBounds, ArrayInterface};
 use crate::kernel::plc::types::primitives::traits::primitive_traits::{RawMut, ToggleMonitor};
 use crate::kernel::arch::local::pointer::{LocalPointer, LocalPointerAndPath};
 use crate::kernel::registry::{convert_string_path_to_usize, get_full_path, get_string, Kernel};
@@ -111,21 +111,35 @@ impl_primitive_traits!(PlcArray, {
 });
 
 impl PlcArray {
+    /// Either `length`, indexed from 0, or `bounds` with the lower and upper bound of each
+    /// dimension: `[[1, 10]]` for Array[1..10], `[[0, 3], [0, 7]]` for Array[0..3, 0..7].
+    /// `values` are in row-major order, the last index varies the fastest.
     pub fn from_json(data: &Map<String, Value>, registry: &Kernel, channel: &Broadcast, monitor: bool) -> Result<Self, Stop> {
         key_reader!(
             format!("Parse Array"),
             data {
-                length => as_i64,
+                length? => as_i64,
+                bounds? => as_array,
                 of => as_object,
                 values => as_array,
             }
         );
 
+        let bounds = match (length, bounds) {
+            (Some(length), None) if length > 0 => vec![(0, length - 1)],
+            (None, Some(bounds)) if !bounds.is_empty() => bounds
+                .iter()
+                .map(parse_bounds)
+                .collect::<Result<Vec<ArrayBounds>, Stop>>()?,
+            _ => return Err(error!(format!("Array expects either a positive length or the bounds of each dimension"))),
+        };
+        let length = array_length(&bounds)?;
+
         let mut of = LocalPointer::from(parse_local_type(of, registry, channel, monitor)?);
         let mut interface = Vec::new();
         
         for i in 0..length {
-            match values.get(i as usize) {
+            match values.get(i) {
                 None => {
                     return Err(error!(format!("Array value is not provided at index {}", i)))
                 }
@@ -144,7 +158,7 @@ impl PlcArray {
         }
         
         Ok(Self {
-            interface: ArrayInterface::from(interface),
+            interface: ArrayInterface::with_bounds(interface, bounds),
             read_only: false,
             path: 0_usize,
         })
@@ -182,3 +196,10 @@ impl PlcArray {
         &mut self.interface
     }
 }
+
+fn parse_bounds(value: &Value) -> Result<ArrayBounds, Stop> {
+    match value.as_array().map(|a| a.iter().map(Value::as_i64).collect::<Vec<Option<i64>>>()).as_deref() {
+        Some([Some(lower), Some(upper)]) if lower <= upper => Ok((*lower, *upper)),
+        _ => Err(error!(format!("Invalid array bounds {}, expected [lower, upper]", value))),
+    }
+}
@@ -25,10 +25,13 @@ pub fn box_set_plc_complex<T: WithMutFamily + WithTypeFamily + Clone + IsFamily
             }).collect::<Result<Vec<RunTimeOperation>, Stop>>()
         })??;
     } else if o1.is_plc_array() && o2.is_plc_array() {
+        if o1.with_type_plc_array(|a| a.get_interface().get_bounds().to_vec())? != o2.with_type_plc_array(|a| a.get_interface().get_bounds().to_vec())? {
+            return Err(error!(format!("Arrays are not equal")));
+        }
         for i in 0..o1.with_type_plc_array(|a| a.get_interface().len())? {
             opexs.push(box_set_auto(
-                &o1.with_type_plc_array(|a| a.try_get_nested(&[i]).unwrap())?,
-                &o2.with_type_plc_array(|a| a.try_get_nested(&[i]).unwrap())?,
+                &o1.with_type_plc_array(|a| a.get_interface().get(i).cloned().unwrap())?,
+                &o2.with_type_plc_array(|a| a.get_interface().get(i).cloned().unwrap())?,
                 trace,
                 registry,
             )?);
@@ -74,10 +77,13 @@ pub fn box_set_plc_complex_default_once<T: WithMutFamily + WithTypeFamily + Clon
             }).collect::<Result<Vec<Box<dyn FnMut(&Broadcast) -> Result<(), Stop>>>, Stop>>()
         })??;
     } else if o1.is_plc_array() && o2.is_plc_array() {
+        if o1.with_type_plc_array(|a| a.get_interface().get_bounds().to_vec())? != o2.with_type_plc_array(|a| a.get_interface().get_bounds().to_vec())? {
+            return Err(error!(format!("Arrays are not equal")));
+        }
         for i in 0..o1.with_type_plc_array(|a| a.get_interface().len())? {
             opexs.push(box_set_auto_default_once(
-                &o1.with_type_plc_array(|a| a.try_get_nested(&[i]).unwrap())?,
-                &o2.with_type_plc_array(|a| a.try_get_nested(&[i]).unwrap())?,
+                &o1.with_type_plc_array(|a| a.get_interface().get(i).cloned().unwrap())?,
+                &o2.with_type_plc_array(|a| a.get_interface().get(i).cloned().unwrap())?,
             )?);
         }
     } else if o1.is_fb_instance() && o2.is_fb_instance() {
@@ -12,7 +12,8 @@ use crate::kernel::registry::{convert_string_path_to_usize, Kernel};
 use crate::container::error::error::{Stop};
 use crate::kernel::rust::set::box_set_plc_primitive;
 use crate::container::broadcast::broadcast::Broadcast;
-use crate::kernel::rust::auto_set::box_set_auto;
+use crate::kernel::rust::auto_set::box_set_auto_enum;
+use crate::kernel::arch::any::any_type::AnyRefType;
 
 pub trait PrivateInstanceAccessors {
     fn get_mut_interface(&mut self) -> &mut SectionInterface;
@@ -109,11 +110,11 @@ impl<T: PrivateInstanceAccessors + PublicInstanceAccessors> PrivateInstanceTrait
     }
 
     fn build_operations(&self, registry: &Kernel, channel: &Broadcast) -> Result<Vec<RunTimeOperation>, Stop> {
-        self
+        registry.loops.isolated(|| self
             .get_body()
             .iter()
             .map(|instruction| instruction.solve_as_operation(self.get_interface(), None, registry, channel))
-            .collect()
+            .collect())
     }
 
     fn define_input_actions(&mut self, match_interface: &HashMap<Section, Vec<(Vec<String>, JsonTarget)>>, parent_interface: &SectionInterface, registry: &Kernel, channel: &Broadcast) -> Result<Vec<RunTimeOperation>, Stop> {
@@ -129,7 +130,7 @@ impl<T: PrivateInstanceAccessors + PublicInstanceAccessors> PrivateInstanceTrait
                             let target = self.get_interface().try_get_nested(&convert_string_path_to_usize(target_path))
                                 .ok_or_else(|| error!(format!("Could not find a valid reference in instance interface for path {:?}, Current interface: {}", &target_path, self.get_interface())))?;
 
-                            assigners.push(box_set_auto(&target, &source.solve_to_ref(parent_interface, None, Some(target.as_ref().borrow().deref().clone()), registry, channel)?, 0, registry)?);
+                            assigners.push(box_set_auto_enum(&target, &source.solve_to_ref(parent_interface, None, Some(target.as_ref().borrow().deref().clone()), registry, channel)?, 0, registry)?);
                             Ok(())
                         })
                 }
@@ -166,7 +167,7 @@ impl<T: PrivateInstanceAccessors + PublicInstanceAccessors> PrivateInstanceTrait
                         .try_for_each(|(target_path, source)| {
                             let target = self.get_interface().try_get_nested(&convert_string_path_to_usize(target_path))
                                 .ok_or_else(move || error!(format!("Could not find a valid reference in instance interface for path {:?}, Current interface: {}", &target_path, self.get_interface())))?;
-                            assigners.push(box_set_auto(&source.solve_as_local_pointer(parent_interface, None, registry, channel).unwrap(), &target, 0, registry)?);
+                            assigners.push(box_set_auto_enum(&source.solve_as_local_pointer(parent_interface, None, registry, channel).unwrap(), &AnyRefType::Local(target.clone()), 0, registry)?);
                             Ok(())
                         })
                 }
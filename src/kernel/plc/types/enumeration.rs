@@ -0,0 +1,118 @@
+use crate::container::error::error::Stop;
+use crate::kernel::plc::types::primitives::integers::plc_integer::PlcInteger;
+use crate::kernel::rust::convert::{convert_value, ConvertKind, ConvertValue, Rounding};
+use crate::parser::local_type::constant_type::create_default_constant_from_str;
+use crate::{error, key_reader};
+use serde_json::{json, Value};
+
+/// Enumeration type, named values backed by an integer type.
+///
+/// Declared in the provider or the program:
+/// `"enums": { "Color": { "of": "Int", "values": { "Red": 0, "Green": 1 }, "default": "Red" } }`.
+/// Without `of` the values are stored in an Int, without `default` the lowest value is the default.
+#[derive(Clone, Debug)]
+pub struct PlcEnum {
+    name: String,
+    of: String,
+    values: Vec<(String, i64)>,
+    default: i64,
+}
+
+impl PlcEnum {
+    pub fn from_json(name: &str, json: &Value) -> Result<Self, Stop> {
+        let data = json
+            .as_object()
+            .ok_or_else(|| error!(format!("Enumeration {} is not an object: {}", name, json)))?;
+
+        key_reader!(
+            format!("Parse enumeration {}", name),
+            data {
+                of? => as_str,
+                values => as_object,
+                default?,
+            }
+        );
+
+        let of = of.unwrap_or("Int");
+        let kind = match ConvertKind::of(&create_default_constant_from_str(of)?) {
+            Some(kind @ ConvertKind::Integer { .. }) => kind,
+            _ => return Err(error!(format!("Enumeration {} must be backed by an integer type, got {}", name, of))),
+        };
+
+        let mut values = values
+            .iter()
+            .map(|(symbol, value)| {
+                let value = value
+                    .as_i64()
+                    .filter(|a| convert_value(ConvertValue::Integer(*a as i128), ConvertKind::Integer { bits: 64, signed: true }, kind, Rounding::Round).1)
+                    .ok_or_else(|| error!(format!("Invalid value {} for {}#{}, expected an integer of type {}", value, name, symbol, of)))?;
+                Ok((symbol.clone(), value))
+            })
+            .collect::<Result<Vec<(String, i64)>, Stop>>()?;
+        values.sort_by_key(|(_, value)| *value);
+
+        if values.is_empty() {
+            return Err(error!(format!("Enumeration {} has no value", name)));
+        }
+        if let Some(pair) = values.windows(2).find(|pair| pair[0].1 == pair[1].1) {
+            return Err(error!(format!("{}#{} and {}#{} have the same value {}", name, pair[0].0, name, pair[1].0, pair[0].1)));
+        }
+
+        let mut plc_enum = Self {
+            name: name.to_string(),
+            of: of.to_string(),
+            default: values[0].1,
+            values,
+        };
+        if let Some(default) = default {
+            plc_enum.default = plc_enum.parse_value(default)?;
+        }
+        Ok(plc_enum)
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    /// Value of a symbol, either `Red` or `Color#Red`.
+    pub fn value_of(&self, symbol: &str) -> Option<i64> {
+        let symbol = match symbol.split_once('#') {
+            Some((name, symbol)) if name == self.name => symbol,
+            Some(_) => return None,
+            None => symbol,
+        };
+        self.values.iter().find(|(a, _)| a == symbol).map(|(_, value)| *value)
+    }
+
+    /// Symbol of a value.
+    pub fn name_of(&self, value: i64) -> Option<&str> {
+        self.values.iter().find(|(_, a)| *a == value).map(|(symbol, _)| symbol.as_str())
+    }
+
+    pub fn contains(&self, value: i128) -> bool {
+        self.values.iter().any(|(_, a)| *a as i128 == value)
+    }
+
+    /// Reads a symbol or one of the integer values of the enumeration.
+    pub fn parse_value(&self, value: &Value) -> Result<i64, Stop> {
+        match value {
+            Value::String(symbol) => self
+                .value_of(symbol)
+                .ok_or_else(|| error!(format!("{} is not a value of {}", symbol, self.name))),
+            _ => value
+                .as_i64()
+                .filter(|a| self.contains(*a as i128))
+                .ok_or_else(|| error!(format!("{} is not a value of {}", value, self.name))),
+        }
+    }
+
+    /// Creates the integer holding a value of the enumeration, the default one if `value` is None.
+    pub fn create(&self, id: u32, value: Option<&Value>) -> Result<PlcInteger, Stop> {
+        let value = match value {
+            Some(a) => self.parse_value(a)?,
+            None => self.default,
+        };
+        let json = json!({ "ty": self.of, "src": { "id": id, "value": value } });
+        PlcInteger::try_from(json.as_object().unwrap())
+    }
+}
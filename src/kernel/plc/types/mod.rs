@@ -1,2 +1,2 @@
 pub mod complex;
-pub mod primitives;
\ No newline at end of file
+pub mod primitives;pub mod enumeration;
@@ -2,4 +2,5 @@
 pub mod word;
 pub mod lword;
 pub mod dword;
-pub mod byte;
\ No newline at end of file
+pub mod byte;
+pub mod slice_access;
@@ -5,6 +5,7 @@ use crate::kernel::plc::types::primitives::binaries::byte::Byte;
 use crate::kernel::plc::types::primitives::binaries::dword::DWord;
 use crate::kernel::plc::types::primitives::binaries::lword::LWord;
 use crate::kernel::plc::types::primitives::binaries::word::Word;
+use crate::kernel::plc::types::primitives::binaries::slice_access::{ByteAccess, DWordAccess, WordAccess};
 use crate::kernel::plc::types::primitives::traits::family_traits::GetRawPointerPrimitive;
 use crate::kernel::plc::types::primitives::traits::primitive_traits::{AsMutPrimitive, Primitive, PrimitiveTrait, RawMut};
 use crate::kernel::plc::types::primitives::string::wchar::wchar;
@@ -19,7 +20,7 @@ use crate::kernel::plc::types::primitives::string::wstring::plcwstr;
 
 create_family!(
     #[enum_dispatch(Crement, MetaData, SetMetaData, ToggleMonitor)]
-    PlcBinary(Byte, Word, DWord, LWord)
+    PlcBinary(Byte, Word, DWord, LWord, ByteAccess, WordAccess, DWordAccess)
 );
 
 impl_primitive_traits!(PlcBinary, {
@@ -30,9 +31,9 @@ impl_primitive_traits!(PlcBinary, {
     plcwstr, [direct false], [stop Err(error!(format!("0")))], [none Err(error!(format!("0")))],
     f32, [direct false], [stop Err(error!(format!("0")))], [none Err(error!(format!("0")))],
     f64, [direct false], [stop Err(error!(format!("0")))], [none Err(error!(format!("0")))],
-    u8, [self.is_byte], [get_mut as_mut_byte], [get as_byte],
-    u16, [self.is_word], [get_mut as_mut_word], [get as_word],
-    u32, [self.is_d_word], [get_mut as_mut_d_word], [get as_d_word],
+    u8, [self.is_byte_size], [get_mut as_mut_byte, get_mut as_mut_byte_access], [get as_byte, get as_byte_access],
+    u16, [self.is_word_size], [get_mut as_mut_word, get_mut as_mut_word_access], [get as_word, get as_word_access],
+    u32, [self.is_d_word_size], [get_mut as_mut_d_word, get_mut as_mut_d_word_access], [get as_d_word, get as_d_word_access],
     u64, [self.is_l_word], [get_mut as_mut_l_word], [get as_l_word],
     i8, [direct false], [stop Err(error!(format!("0")))], [none Err(error!(format!("0")))],
     i16, [direct false], [stop Err(error!(format!("0")))], [none Err(error!(format!("0")))],
@@ -40,6 +41,20 @@ impl_primitive_traits!(PlcBinary, {
     i64, [direct false], [stop Err(error!(format!("0")))], [none Err(error!(format!("0")))]
 });
 
+impl PlcBinary {
+    fn is_byte_size(&self) -> bool {
+        self.is_byte() || self.is_byte_access()
+    }
+
+    fn is_word_size(&self) -> bool {
+        self.is_word() || self.is_word_access()
+    }
+
+    fn is_d_word_size(&self) -> bool {
+        self.is_d_word() || self.is_d_word_access()
+    }
+}
+
 impl TryFrom<&Map<String, Value>> for PlcBinary {
     type Error = Stop;
 
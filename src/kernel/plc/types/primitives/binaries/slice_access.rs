@@ -0,0 +1,271 @@
+use core::any::TypeId;
+use core::fmt::{Display, Formatter};
+use serde::{Serialize, Serializer};
+use serde_json::{Map, Value};
+use crate::container::broadcast::broadcast::Broadcast;
+use crate::container::error::error::Stop;
+use crate::{error, key_reader};
+use crate::parser::body::body::parse_json_target;
+use crate::kernel::plc::interface::section_interface::SectionInterface;
+use crate::kernel::plc::internal::template_impl::TemplateMemory;
+use crate::kernel::plc::types::primitives::binaries::plc_binary::PlcBinary;
+use crate::kernel::plc::types::primitives::boolean::bit_access::BitAccess;
+use crate::kernel::plc::types::primitives::boolean::plc_bool::PlcBool;
+use crate::kernel::plc::types::primitives::traits::crement::Crement;
+use crate::kernel::plc::types::primitives::traits::primitive_traits::{PrimitiveTrait, AsMutPrimitive, Primitive, RawMut, ToggleMonitor};
+use crate::kernel::plc::types::primitives::traits::meta_data::{MetaData, SetMetaData};
+use crate::kernel::arch::local::pointer::LocalPointer;
+use crate::kernel::arch::local::r#type::LocalType;
+use crate::kernel::registry::Kernel;
+use crate::kernel::rust::overflow::{Overflow, Overflowing};
+
+/// Parses a slice access of a variable, `size` is `X` (default), `B`, `W` or `D` like `%X0`, `%B0`,
+/// `%W0` or `%D0`, `at` is the index of the slice starting from the least significant bits.
+pub fn parse_access(
+    json: &Map<String, Value>,
+    interface: &SectionInterface,
+    template: Option<&TemplateMemory>,
+    registry: &Kernel,
+    channel: &Broadcast) -> Result<LocalPointer, Stop> {
+    key_reader!(
+        format!("Parse Access"),
+        json {
+            size? => as_str,
+        }
+    );
+
+    let access = match size.unwrap_or("X") {
+        "X" => LocalType::PlcBool(PlcBool::BitAccess(BitAccess::new_(json, interface, template, registry, channel)?)),
+        "B" => LocalType::PlcBinary(PlcBinary::ByteAccess(ByteAccess::new_(json, interface, template, registry, channel)?)),
+        "W" => LocalType::PlcBinary(PlcBinary::WordAccess(WordAccess::new_(json, interface, template, registry, channel)?)),
+        "D" => LocalType::PlcBinary(PlcBinary::DWordAccess(DWordAccess::new_(json, interface, template, registry, channel)?)),
+        other => return Err(error!(format!("Invalid access size {}, expected X, B, W or D", other))),
+    };
+    Ok(LocalPointer::from(access))
+}
+
+/// Number of bits of an integer or a binary.
+fn width_of<T: Primitive>(variable: &T) -> Option<u64> {
+    if variable.is_u8() || variable.is_i8() {
+        Some(8)
+    } else if variable.is_u16() || variable.is_i16() {
+        Some(16)
+    } else if variable.is_u32() || variable.is_i32() {
+        Some(32)
+    } else if variable.is_u64() || variable.is_i64() {
+        Some(64)
+    } else {
+        None
+    }
+}
+
+fn read_raw<T: Primitive>(variable: &T, channel: &Broadcast) -> Result<u64, Stop> {
+    Ok(if variable.is_u8() {
+        variable.as_u8(channel)? as u64
+    } else if variable.is_i8() {
+        variable.as_i8(channel)? as u8 as u64
+    } else if variable.is_u16() {
+        variable.as_u16(channel)? as u64
+    } else if variable.is_i16() {
+        variable.as_i16(channel)? as u16 as u64
+    } else if variable.is_u32() {
+        variable.as_u32(channel)? as u64
+    } else if variable.is_i32() {
+        variable.as_i32(channel)? as u32 as u64
+    } else if variable.is_u64() {
+        variable.as_u64(channel)?
+    } else {
+        variable.as_i64(channel)? as u64
+    })
+}
+
+fn write_raw<T: Primitive + AsMutPrimitive>(variable: &mut T, raw: u64, channel: &Broadcast) -> Result<(), Stop> {
+    if variable.is_u8() {
+        variable.set_u8(raw as u8, channel)
+    } else if variable.is_i8() {
+        variable.set_i8(raw as u8 as i8, channel)
+    } else if variable.is_u16() {
+        variable.set_u16(raw as u16, channel)
+    } else if variable.is_i16() {
+        variable.set_i16(raw as u16 as i16, channel)
+    } else if variable.is_u32() {
+        variable.set_u32(raw as u32, channel)
+    } else if variable.is_i32() {
+        variable.set_i32(raw as u32 as i32, channel)
+    } else if variable.is_u64() {
+        variable.set_u64(raw, channel)
+    } else {
+        variable.set_i64(raw as i64, channel)
+    }
+}
+
+macro_rules! create_slice_access {
+    ($($access: ident, $native: ident, $name: literal, $prefix: literal);+) => {
+        $(
+            /// Slice of an integer or a binary variable, reads and writes go through the variable.
+            #[derive(Clone)]
+            pub struct $access {
+                of: LocalPointer,
+                at: u64,
+            }
+
+            impl $access {
+                pub fn new_(
+                    json: &Map<String, Value>,
+                    interface: &SectionInterface,
+                    template: Option<&TemplateMemory>,
+                    registry: &Kernel,
+                    channel: &Broadcast) -> Result<Self, Stop> {
+                    key_reader!(
+                        format!("Parse {} Access", $name),
+                        json {
+                            of,
+                            at => as_u64,
+                            id => as_u64,
+                        }
+                    );
+
+                    let of = parse_json_target(of)
+                        .and_then(|of| {
+                            of.solve_as_local_pointer(interface, template, registry, channel)
+                                .ok_or_else(|| error!(format!("Expected a valid number reference, got {}", of)))
+                        })
+                        .map_err(|e| e.add_sim_trace(&format!("Build {} access -> source", $name)).add_id(id as u32))?;
+
+                    match width_of(&of) {
+                        Some(width) if (at + 1) * $native::BITS as u64 <= width => Ok(Self { of, at }),
+                        Some(width) => Err(error!(format!("Invalid access: {}{} is out of the {} bits of {}", $prefix, at, width, of)).add_id(id as u32)),
+                        None => Err(error!(format!("Invalid access: Can not get {}{} of {}", $prefix, at, of)).add_id(id as u32)),
+                    }
+                }
+
+                fn shift(&self) -> u64 {
+                    self.at * $native::BITS as u64
+                }
+            }
+
+            impl RawMut for $access {
+                fn reset_ptr(&mut self, _channel: &Broadcast) {
+                    panic!("A slice access cannot be referenced with a raw pointer")
+                }
+            }
+
+            impl Display for $access {
+                fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+                    write!(f, "{}{} of {}", $prefix, self.at, self.of)
+                }
+            }
+
+            impl Serialize for $access {
+                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
+                    serializer.serialize_str("Slice access")
+                }
+            }
+
+            impl MetaData for $access {
+                fn name(&self) -> &'static str {
+                    &$name
+                }
+
+                fn get_alias_str<'a>(&'a self, _kernel: &'a Kernel) -> Option<&'a String> {
+                    None
+                }
+
+                fn get_alias_id(&self, _kernel: &Kernel) -> Option<usize> {
+                    None
+                }
+
+                fn is_read_only(&self) -> bool {
+                    self.of.is_read_only()
+                }
+
+                fn get_path(&self) -> String {
+                    format!("{}.{}{}", self.of.get_path(), $prefix, self.at)
+                }
+            }
+
+            impl SetMetaData for $access {
+                fn set_alias(&mut self, _alias: &str, _kernel: &Kernel) {
+                    // do nothing
+                }
+
+                fn set_read_only(&mut self, _value: bool) {
+                    // do nothing
+                }
+
+                fn set_name(&mut self, _path: usize) {
+                    // do nothing
+                }
+            }
+
+            impl ToggleMonitor for $access {
+                fn set_monitor(&self, _kernel: &Kernel) {
+                    // the variable is monitored
+                }
+            }
+
+            impl Crement for $access {
+                fn increment(&mut self, overflow: Overflow, channel: &Broadcast) -> Result<bool, Stop> {
+                    let (value, flagged) = self.get(channel)?
+                        .overflowing_flagged("add", 1, overflow)
+                        .ok_or_else(|| error!(format!("Invalid operation: Can not increment {}", self)))?;
+                    self.set(value, channel)?;
+                    Ok(flagged)
+                }
+
+                fn decrement(&mut self, overflow: Overflow, channel: &Broadcast) -> Result<bool, Stop> {
+                    let (value, flagged) = self.get(channel)?
+                        .overflowing_flagged("sub", 1, overflow)
+                        .ok_or_else(|| error!(format!("Invalid operation: Can not decrement {}", self)))?;
+                    self.set(value, channel)?;
+                    Ok(flagged)
+                }
+            }
+
+            impl PrimitiveTrait for $access {
+                type Native = $native;
+                type PlcPrimitive = $access;
+
+                fn new(_value: &Self::Native, _id: u32) -> Result<Self::PlcPrimitive, Stop> {
+                    Err(error!(format!("A slice access can't be created manually, this should not happen")))
+                }
+
+                fn new_default(_id: u32) -> Self::PlcPrimitive {
+                    panic!("A slice access can't be created manually, this should not happen")
+                }
+
+                fn get(&self, channel: &Broadcast) -> Result<Self::Native, Stop> {
+                    Ok((read_raw(&self.of, channel)? >> self.shift()) as $native)
+                }
+
+                fn set(&mut self, value: Self::Native, channel: &Broadcast) -> Result<(), Stop> {
+                    let mask = ($native::MAX as u64) << self.shift();
+                    let raw = read_raw(&self.of, channel)? & !mask | (value as u64) << self.shift();
+                    write_raw(&mut self.of, raw, channel)
+                }
+
+                fn set_default(&mut self, _value: Self::Native) -> Result<(), Stop> {
+                    panic!("A slice access can't have a default value, this should not happen")
+                }
+
+                fn reset(&mut self, _channel: &Broadcast) {
+                    panic!("A slice access can't be reset, this should not happen")
+                }
+
+                fn get_id(&self) -> u32 {
+                    panic!("A slice access does not have an id, this should not happen")
+                }
+
+                fn get_type_id(&self) -> TypeId {
+                    panic!("A slice access does not have an type id, this should not happen")
+                }
+            }
+        )+
+    };
+}
+
+create_slice_access!(
+    ByteAccess, u8, "Byte", "%B";
+    WordAccess, u16, "Word", "%W";
+    DWordAccess, u32, "DWord", "%D"
+);
@@ -0,0 +1,44 @@
+use crate::container::broadcast::broadcast::Broadcast;
+use crate::container::error::error::Stop;
+use crate::kernel::plc::types::primitives::traits::family_traits::*;
+use crate::kernel::plc::types::primitives::traits::primitive_traits::*;
+use crate::kernel::plc::types::primitives::traits::meta_data::*;
+use crate::kernel::plc::types::primitives::date::plc_date::{parse_date, DATE_MAX};
+use crate::{impl_primitive_all, key_reader};
+use serde::ser::SerializeStruct;
+use serde::{Serialize, Serializer};
+use serde_json::{Map, Value};
+use core::any::{Any, TypeId};
+use core::fmt::{Display, Formatter};
+use crate::kernel::registry::Kernel;
+use crate::kernel::registry::get_string;
+
+/// DATE, days since 1990-01-01, up to 2168-12-31.
+#[derive(Clone)]
+pub struct Date {
+    value: u16,
+    default: u16,
+
+    id: u32,
+    read_only: bool,
+    alias: Option<usize>,
+    path: usize
+}
+
+impl_primitive_all!(Date, u16, max DATE_MAX);
+
+impl TryFrom<&Map<String, Value>> for Date {
+    type Error = Stop;
+
+    fn try_from(data: &Map<String, Value>) -> Result<Self, Self::Error> {
+        key_reader!(
+            format!("Parse Date"),
+            data {
+                value,
+                id => as_u64,
+            }
+        );
+        let id = id as u32;
+        Date::new(&parse_date(value)?, id)
+    }
+}
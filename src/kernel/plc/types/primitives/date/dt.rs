@@ -0,0 +1,45 @@
+use crate::container::broadcast::broadcast::Broadcast;
+use crate::container::error::error::Stop;
+use crate::kernel::plc::types::primitives::traits::family_traits::*;
+use crate::kernel::plc::types::primitives::traits::primitive_traits::*;
+use crate::kernel::plc::types::primitives::traits::meta_data::*;
+use crate::kernel::plc::types::primitives::date::plc_date::parse_dt;
+use crate::{impl_primitive_all, key_reader};
+use serde::ser::SerializeStruct;
+use serde::{Serialize, Serializer};
+use serde_json::{Map, Value};
+use core::any::{Any, TypeId};
+use core::fmt::{Display, Formatter};
+use crate::kernel::registry::Kernel;
+use crate::kernel::registry::get_string;
+
+/// DATE_AND_TIME, ns since 1990-01-01, up to 2089-12-31 23:59:59.999.
+/// Like on the PLC the resolution is the ms, results of operations are rounded down to it.
+#[derive(Clone)]
+pub struct Dt {
+    value: i64,
+    default: i64,
+
+    id: u32,
+    read_only: bool,
+    alias: Option<usize>,
+    path: usize
+}
+
+impl_primitive_all!(Dt, i64);
+
+impl TryFrom<&Map<String, Value>> for Dt {
+    type Error = Stop;
+
+    fn try_from(data: &Map<String, Value>) -> Result<Self, Self::Error> {
+        key_reader!(
+            format!("Parse Dt"),
+            data {
+                value,
+                id => as_u64,
+            }
+        );
+        let id = id as u32;
+        Dt::new(&parse_dt(value)?, id)
+    }
+}
@@ -0,0 +1,48 @@
+use crate::container::broadcast::broadcast::Broadcast;
+use crate::container::error::error::Stop;
+use crate::kernel::plc::types::primitives::traits::family_traits::*;
+use crate::kernel::plc::types::primitives::traits::primitive_traits::*;
+use crate::kernel::plc::types::primitives::traits::meta_data::*;
+use crate::kernel::plc::types::primitives::date::plc_date::{parse_date_time, LDT_RANGE};
+use crate::{impl_primitive_all, key_reader};
+use serde::ser::SerializeStruct;
+use serde::{Serialize, Serializer};
+use serde_json::{Map, Value};
+use core::any::{Any, TypeId};
+use core::fmt::{Display, Formatter};
+use crate::kernel::registry::Kernel;
+use crate::kernel::registry::get_string;
+
+/// DTL, ns since 1970-01-01, up to 2262-04-11 23:47:16.854775807.
+///
+/// Unlike on the PLC, a DTL is a single point in time and not a structure: its members
+/// (`YEAR`, `MONTH`, `DAY`, `WEEKDAY`, `HOUR`, `MINUTE`, `SECOND`, `NANOSECOND`) can not be accessed
+/// and a path to one of them is rejected when the program is built.
+#[derive(Clone)]
+pub struct Dtl {
+    value: i64,
+    default: i64,
+
+    id: u32,
+    read_only: bool,
+    alias: Option<usize>,
+    path: usize
+}
+
+impl_primitive_all!(Dtl, i64);
+
+impl TryFrom<&Map<String, Value>> for Dtl {
+    type Error = Stop;
+
+    fn try_from(data: &Map<String, Value>) -> Result<Self, Self::Error> {
+        key_reader!(
+            format!("Parse Dtl"),
+            data {
+                value,
+                id => as_u64,
+            }
+        );
+        let id = id as u32;
+        Dtl::new(&(parse_date_time(value, 0, LDT_RANGE)? as i64), id)
+    }
+}
@@ -0,0 +1,44 @@
+use crate::container::broadcast::broadcast::Broadcast;
+use crate::container::error::error::Stop;
+use crate::kernel::plc::types::primitives::traits::family_traits::*;
+use crate::kernel::plc::types::primitives::traits::primitive_traits::*;
+use crate::kernel::plc::types::primitives::traits::meta_data::*;
+use crate::kernel::plc::types::primitives::date::plc_date::{parse_date_time, LDT_RANGE};
+use crate::{impl_primitive_all, key_reader};
+use serde::ser::SerializeStruct;
+use serde::{Serialize, Serializer};
+use serde_json::{Map, Value};
+use core::any::{Any, TypeId};
+use core::fmt::{Display, Formatter};
+use crate::kernel::registry::Kernel;
+use crate::kernel::registry::get_string;
+
+/// LDT, ns since 1970-01-01, up to 2262-04-11 23:47:16.854775807.
+#[derive(Clone)]
+pub struct Ldt {
+    value: u64,
+    default: u64,
+
+    id: u32,
+    read_only: bool,
+    alias: Option<usize>,
+    path: usize
+}
+
+impl_primitive_all!(Ldt, u64);
+
+impl TryFrom<&Map<String, Value>> for Ldt {
+    type Error = Stop;
+
+    fn try_from(data: &Map<String, Value>) -> Result<Self, Self::Error> {
+        key_reader!(
+            format!("Parse Ldt"),
+            data {
+                value,
+                id => as_u64,
+            }
+        );
+        let id = id as u32;
+        Ldt::new(&(parse_date_time(value, 0, LDT_RANGE)? as u64), id)
+    }
+}
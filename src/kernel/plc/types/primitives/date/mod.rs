@@ -0,0 +1,6 @@
+#![allow(non_snake_case)]
+pub mod date;
+pub mod dt;
+pub mod ldt;
+pub mod dtl;
+pub mod plc_date;
@@ -0,0 +1,197 @@
+#![allow(non_snake_case)]
+use crate::container::broadcast::broadcast::Broadcast;
+use crate::container::error::error::Stop;
+use crate::kernel::plc::types::primitives::traits::family_traits::GetRawPointerPrimitive;
+use crate::kernel::plc::types::primitives::traits::primitive_traits::{AsMutPrimitive, Primitive, PrimitiveTrait, RawMut};
+use crate::kernel::plc::types::primitives::string::wchar::wchar;
+use crate::kernel::plc::types::primitives::date::date::Date;
+use crate::kernel::plc::types::primitives::date::dt::Dt;
+use crate::kernel::plc::types::primitives::date::dtl::Dtl;
+use crate::kernel::plc::types::primitives::date::ldt::Ldt;
+use crate::{create_family, error, impl_primitive_traits, key_reader};
+use camelpaste::paste;
+use chrono::{NaiveDate, NaiveDateTime};
+
+use serde::Serializer;
+use serde_json::{Map, Value};
+use crate::kernel::plc::types::primitives::string::_string::plcstr;
+use crate::kernel::plc::types::primitives::string::wstring::plcwstr;
+
+pub const NS_PER_DAY: i128 = 86_400_000_000_000;
+
+/// 1990-01-01 in ns since 1970-01-01, origin of DATE and DT.
+pub const DT_EPOCH: i128 = 631_152_000_000_000_000;
+
+/// D#2168-12-31 in days since 1990-01-01, last DATE of the PLC.
+pub const DATE_MAX: u16 = 65_378;
+
+/// ns per ms, the resolution of DT.
+pub const DT_RESOLUTION: i128 = 1_000_000;
+
+/// DT#1990-01-01-00:00:00 to DT#2089-12-31-23:59:59.999, in ns since 1970-01-01.
+pub const DT_RANGE: (i128, i128) = (DT_EPOCH, 3_786_911_999_999_000_000);
+
+/// LDT#1970-01-01-00:00:00 to LDT#2262-04-11-23:47:16.854775807, in ns since 1970-01-01.
+pub const LDT_RANGE: (i128, i128) = (0, i64::MAX as i128);
+
+create_family!(
+    #[enum_dispatch(MetaData, SetMetaData, ToggleMonitor)]
+    PlcDate(Date, Dt, Ldt, Dtl)
+);
+
+impl PlcDate {
+    /// Dt and Dtl are both stored in an i64.
+    pub fn is_i64_native(&self) -> bool {
+        self.is_dt() || self.is_dtl()
+    }
+
+    /// How the native value of this type maps to ns since 1970-01-01.
+    pub fn scale(&self) -> DateScale {
+        match self {
+            PlcDate::Date(_) => DateScale { epoch: DT_EPOCH, unit: NS_PER_DAY, step: 1, range: (DT_EPOCH, DT_EPOCH + DATE_MAX as i128 * NS_PER_DAY) },
+            PlcDate::Dt(_) => DateScale { epoch: DT_EPOCH, unit: 1, step: DT_RESOLUTION, range: DT_RANGE },
+            PlcDate::Ldt(_) | PlcDate::Dtl(_) => DateScale { epoch: 0, unit: 1, step: 1, range: LDT_RANGE },
+        }
+    }
+}
+
+/// Native value of a date type as `epoch + native * unit` ns since 1970-01-01.
+/// The native value is a multiple of `step`, e.g. the ms of a DT.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DateScale {
+    pub epoch: i128,
+    pub unit: i128,
+    pub step: i128,
+    pub range: (i128, i128),
+}
+
+impl DateScale {
+    pub fn to_ns(&self, native: i128) -> i128 {
+        self.epoch + native * self.unit
+    }
+
+    /// The native value of a point in time, rounded down to the unit and the step, None when out of range.
+    pub fn from_ns(&self, ns: i128) -> Option<i128> {
+        match ns >= self.range.0 && ns <= self.range.1 {
+            true => {
+                let native = (ns - self.epoch).div_euclid(self.unit);
+                Some(native - native.rem_euclid(self.step))
+            }
+            false => None,
+        }
+    }
+}
+
+impl_primitive_traits!(PlcDate, {
+    bool, [direct false], [stop Err(error!(format!("0")))], [none Err(error!(format!("0")))],
+    char, [direct false], [stop Err(error!(format!("0")))], [none Err(error!(format!("0")))],
+    wchar, [direct false], [stop Err(error!(format!("0")))], [none Err(error!(format!("0")))],
+    plcstr, [direct false], [stop Err(error!(format!("0")))], [none Err(error!(format!("0")))],
+    plcwstr, [direct false], [stop Err(error!(format!("0")))], [none Err(error!(format!("0")))],
+    f32, [direct false], [stop Err(error!(format!("0")))], [none Err(error!(format!("0")))],
+    f64, [direct false], [stop Err(error!(format!("0")))], [none Err(error!(format!("0")))],
+    u8, [direct false], [stop Err(error!(format!("0")))], [none Err(error!(format!("0")))],
+    u16, [self.is_date], [get_mut as_mut_date], [get as_date],
+    u32, [direct false], [stop Err(error!(format!("0")))], [none Err(error!(format!("0")))],
+    u64, [self.is_ldt], [get_mut as_mut_ldt], [get as_ldt],
+    i8, [direct false], [stop Err(error!(format!("0")))], [none Err(error!(format!("0")))],
+    i16, [direct false], [stop Err(error!(format!("0")))], [none Err(error!(format!("0")))],
+    i32, [direct false], [stop Err(error!(format!("0")))], [none Err(error!(format!("0")))],
+    i64, [self.is_i64_native], [get_mut as_mut_dt, get_mut as_mut_dtl], [get as_dt, get as_dtl]
+});
+
+/// Reads a DATE, either days since 1990-01-01 or a "2024-05-01" string.
+pub fn parse_date(value: &Value) -> Result<u16, Stop> {
+    match value {
+        Value::String(a) => {
+            let date = NaiveDate::parse_from_str(a.trim_start_matches("D#"), "%Y-%m-%d")
+                .map_err(|e| error!(format!("Invalid Date {}: {}", a, e)))?;
+            let days = (date.and_hms_opt(0, 0, 0).unwrap().and_utc().timestamp() as i128 * 1_000_000_000 - DT_EPOCH) / NS_PER_DAY;
+            u16::try_from(days)
+                .ok()
+                .filter(|days| *days <= DATE_MAX)
+                .ok_or_else(|| error!(format!("Date {} is out of range D#1990-01-01..D#2168-12-31", a)))
+        }
+        _ => value
+            .as_u64()
+            .and_then(|a| u16::try_from(a).ok())
+            .filter(|days| *days <= DATE_MAX)
+            .ok_or_else(|| error!(format!("Invalid Date value: {}, expected 0..{}", value, DATE_MAX))),
+    }
+}
+
+/// Reads a DT like [`parse_date_time`], values below the ms are rejected.
+pub fn parse_dt(value: &Value) -> Result<i64, Stop> {
+    let native = parse_date_time(value, DT_EPOCH, DT_RANGE)?;
+    match native % DT_RESOLUTION {
+        0 => Ok(native as i64),
+        _ => Err(error!(format!("Date and time {} is more precise than the ms of a DT", value))),
+    }
+}
+
+/// Reads a date and time, either the native value (ns since `epoch`) or a string like
+/// "2024-05-01T12:30:00.250" or "2024-05-01-12:30:00.250".
+///
+/// Returns the native value.
+pub fn parse_date_time(value: &Value, epoch: i128, range: (i128, i128)) -> Result<i128, Stop> {
+    let ns = match value {
+        Value::String(a) => {
+            let text = a.split_once('#').map(|(_, b)| b).unwrap_or(a);
+            let date_time = ["%Y-%m-%dT%H:%M:%S%.f", "%Y-%m-%d-%H:%M:%S%.f"]
+                .iter()
+                .find_map(|format| NaiveDateTime::parse_from_str(text, format).ok())
+                .ok_or_else(|| error!(format!("Invalid date and time {}", a)))?
+                .and_utc();
+            date_time.timestamp() as i128 * 1_000_000_000 + date_time.timestamp_subsec_nanos() as i128
+        }
+        _ => epoch + value
+            .as_i64()
+            .map(|a| a as i128)
+            .or_else(|| value.as_u64().map(|a| a as i128))
+            .ok_or_else(|| error!(format!("Invalid date and time value: {}", value)))?,
+    };
+    match ns >= range.0 && ns <= range.1 {
+        true => Ok(ns - epoch),
+        false => Err(error!(format!("Date and time {} is out of range", value))),
+    }
+}
+
+impl TryFrom<&Map<String, Value>> for PlcDate {
+    type Error = Stop;
+
+    fn try_from(data: &Map<String, Value>) -> Result<Self, Self::Error> {
+        key_reader!(
+           format!("Parse PlcDate"),
+           data {
+                ty => as_str,
+                src => {
+                    value?,
+                    id => as_u64,
+                }
+            }
+        );
+        let id = id as u32;
+        match value {
+            None => match ty {
+                "Date" => Ok(PlcDate::Date(Date::new_default(id))),
+                "Dt" => Ok(PlcDate::Dt(Dt::new_default(id))),
+                "Ldt" => Ok(PlcDate::Ldt(Ldt::new_default(id))),
+                "Dtl" => Ok(PlcDate::Dtl(Dtl::new_default(id))),
+                _ => Err(error!(
+                    format!("Invalid PlcDate type: {}", ty),
+                    format!("Parse PlcDate")
+                )),
+            },
+            Some(value) => match ty {
+                "Date" => Ok(PlcDate::Date(Date::new(&parse_date(value)?, id)?)),
+                "Dt" => Ok(PlcDate::Dt(Dt::new(&parse_dt(value)?, id)?)),
+                "Ldt" => Ok(PlcDate::Ldt(Ldt::new(&(parse_date_time(value, 0, LDT_RANGE)? as u64), id)?)),
+                "Dtl" => Ok(PlcDate::Dtl(Dtl::new(&(parse_date_time(value, 0, LDT_RANGE)? as i64), id)?)),
+                _ => Err(error!(
+                    format!("Invalid PlcDate type: {}", ty),
+                    format!("Parse PlcDate")
+                )),
+            },
+        }
+    }
+}
@@ -2,9 +2,10 @@
 /// Automatic creation of any plc primitive.
 ///
 /// This will handle all cases as far as you don't have a specific behavior to implement.
+/// With `max`, every value above it is rejected when created or written.
 macro_rules! impl_primitive_all {
-    ($primitive: ident, $inner_type: ident) => {
-        crate::impl_primitive_base!($primitive, $inner_type);
+    ($primitive: ident, $inner_type: ident $(, max $max: expr)?) => {
+        crate::impl_primitive_base!($primitive, $inner_type $(, max $max)?);
         crate::impl_primitive_type_name!($primitive, $inner_type);
         crate::impl_primitive_raw_mut!($primitive, $inner_type);
         crate::impl_primitive_display!($primitive, $inner_type);
@@ -164,7 +165,7 @@ macro_rules! impl_primitive_serialize {
 
 #[macro_export]
 macro_rules! impl_primitive_base {
-    ($primitive: ident, $inner_type: ident) => {
+    ($primitive: ident, $inner_type: ident $(, max $max: expr)?) => {
         impl ToggleMonitor for $primitive {
             fn set_monitor(&self, kernel: &Kernel) {
                 kernel
@@ -196,6 +197,7 @@ macro_rules! impl_primitive_base {
             }
 
             fn new(value: &$inner_type, id: u32) -> Result<Self::PlcPrimitive, Stop> {
+                $($crate::check_primitive_max!($primitive, *value, $max);)?
                 Ok(Self {
                     default: *value,
                     value: *value,
@@ -211,11 +213,13 @@ macro_rules! impl_primitive_base {
             }
 
             fn set(&mut self, value: $inner_type, channel: &Broadcast) -> Result<(), Stop> {
+                $($crate::check_primitive_max!($primitive, value, $max);)?
                 self.value = value;
                 Ok(())
             }
 
             fn set_default(&mut self, value: $inner_type) -> Result<(), Stop> {
+                $($crate::check_primitive_max!($primitive, value, $max);)?
                 self.default = value;
                 self.value = self.default;
                 Ok(())
@@ -235,3 +239,112 @@ macro_rules! impl_primitive_base {
         }
     };
 }
+
+#[macro_export]
+macro_rules! check_primitive_max {
+    ($primitive: ident, $value: expr, $max: expr) => {
+        if $value > $max {
+            return Err($crate::error!(format!("{} {} is out of range 0..{}", stringify!($primitive), $value, $max)));
+        }
+    };
+}
+
+#[macro_export]
+/// Creation of a String or WString, like [`impl_primitive_all`] but every value
+/// written is truncated to the declared maximum length.
+macro_rules! impl_primitive_string {
+    ($primitive: ident, $inner_type: ident) => {
+        $crate::impl_primitive_type_name!($primitive, $inner_type);
+        $crate::impl_primitive_raw_mut!($primitive, $inner_type);
+        $crate::impl_primitive_display!($primitive, $inner_type);
+        $crate::impl_primitive_serialize!($primitive, $inner_type);
+
+        impl ToggleMonitor for $primitive {
+            fn set_monitor(&self, kernel: &Kernel) {
+                kernel
+                .monitor_raw_pointers
+                .borrow_mut()
+                .insert(self.id, self as *const dyn SerializeValue);
+            }
+        }
+
+        impl SerializeValue for $primitive {
+            fn get_value(&self) -> wasm_bindgen::JsValue {
+                serde_wasm_bindgen::to_value(&self.value).unwrap()
+            }
+        }
+
+        impl $primitive {
+            pub fn get_max_length(&self) -> usize {
+                self.max_length
+            }
+
+            pub fn with_max_length(mut self, max_length: usize) -> Result<Self, Stop> {
+                if max_length == 0 || max_length > DEFAULT_STRING_LENGTH {
+                    return Err(error!(format!("Invalid {} length {}, expected 1 to {}", stringify!($primitive), max_length, DEFAULT_STRING_LENGTH)));
+                }
+                if self.default.char_len() > max_length {
+                    return Err(error!(format!("Value '{}' is longer than {} characters", self.default, max_length)));
+                }
+                self.max_length = max_length;
+                Ok(self)
+            }
+        }
+
+        impl PrimitiveTrait for $primitive {
+            type Native = $inner_type;
+            type PlcPrimitive = $primitive;
+
+            fn new_default(id: u32) -> Self::PlcPrimitive {
+                Self {
+                    default: $inner_type::default(),
+                    value: $inner_type::default(),
+                    max_length: DEFAULT_STRING_LENGTH,
+                    id,
+                    read_only: false,
+                    alias: None,
+                    path: 0_usize
+                }
+            }
+
+            fn new(value: &$inner_type, id: u32) -> Result<Self::PlcPrimitive, Stop> {
+                Ok(Self {
+                    default: *value,
+                    value: *value,
+                    max_length: DEFAULT_STRING_LENGTH,
+                    id,
+                    read_only: false,
+                    alias: None,
+                    path: 0_usize
+                })
+            }
+
+            fn get(&self, channel: &Broadcast) -> Result<$inner_type, Stop> {
+                Ok(self.value)
+            }
+
+            fn set(&mut self, value: $inner_type, channel: &Broadcast) -> Result<(), Stop> {
+                self.value = value.fit(self.max_length);
+                Ok(())
+            }
+
+            fn set_default(&mut self, value: $inner_type) -> Result<(), Stop> {
+                self.default = value.fit(self.max_length);
+                self.value = self.default;
+                Ok(())
+            }
+
+            fn reset(&mut self, channel: &Broadcast) {
+                self.value = self.default;
+            }
+
+            fn get_id(&self) -> u32 {
+                self.id
+            }
+
+            fn get_type_id(&self) -> TypeId {
+                self.value.type_id()
+            }
+        }
+    };
+}
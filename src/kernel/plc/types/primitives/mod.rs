@@ -2,6 +2,7 @@
 pub mod string;
 pub mod timers;
 pub mod tod;
+pub mod date;
 pub mod binaries;
 pub mod integers;
 pub mod floats;
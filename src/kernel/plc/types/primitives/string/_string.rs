@@ -5,7 +5,8 @@ use crate::container::error::error::Stop;
 use crate::kernel::plc::types::primitives::traits::family_traits::*;
 use crate::kernel::plc::types::primitives::traits::primitive_traits::*;
 use crate::kernel::plc::types::primitives::traits::meta_data::*;
-use crate::{error, impl_primitive_all, key_reader};
+use crate::{error, impl_primitive_string, key_reader};
+use crate::kernel::plc::types::primitives::traits::string_length::{StringLength, DEFAULT_STRING_LENGTH};
 use fixedstr::str256;
 use serde::ser::SerializeStruct;
 use serde::{Serialize, Serializer};
@@ -21,6 +22,7 @@ use crate::kernel::registry::get_string;
 pub struct _String {
     value: plcstr,
     default: plcstr,
+    max_length: usize,
 
     id: u32,
     read_only: bool,
@@ -28,7 +30,7 @@ pub struct _String {
     path: usize,
 }
 
-impl_primitive_all!(_String, plcstr);
+impl_primitive_string!(_String, plcstr);
 
 #[derive(Debug, Clone, Copy, PartialOrd, Default, PartialEq)]
 pub struct plcstr(pub str256);
@@ -50,13 +52,18 @@ impl TryFrom<&Map<String, Value>> for _String {
 
     fn try_from(data: &Map<String, Value>) -> Result<Self, Self::Error> {
         key_reader!(
-                format!("Parse String"),
-                data {
-                    value => as_str,
-                    id => as_u64,
-                }
-            );
+            format!("Parse String"),
+            data {
+                value => as_str,
+                length? => as_u64,
+                id => as_u64,
+            }
+        );
         let id = id as u32;
-        _String::new(&plcstr(str256::from_str(value).map_err(|e| error!(format!("{}", e)))?), id)
+        let value = _String::new(&plcstr(str256::from_str(value).map_err(|e| error!(format!("{}", e)))?), id)?;
+        match length {
+            Some(length) => value.with_max_length(length as usize),
+            None => Ok(value),
+        }
     }
-}
\ No newline at end of file
+}
@@ -12,6 +12,7 @@ use fixedstr::str256;
 use serde::Serializer;
 use serde_json::{Map, Value};
 use core::str::FromStr;
+use crate::kernel::plc::types::primitives::traits::string_length::DEFAULT_STRING_LENGTH;
 
 create_family!(
     #[enum_dispatch(MetaData, SetMetaData, ToggleMonitor)]
@@ -46,17 +47,19 @@ impl TryFrom<&Map<String, Value>> for PlcString {
                 ty => as_str,
                 src => {
                     value?,
+                    length? => as_u64,
                     id => as_u64,
                 }
             }
         );
         let id = id as u32;
+        let length = length.map(|a| a as usize).unwrap_or(DEFAULT_STRING_LENGTH);
         match value {
             None => match ty {
                 "Char" => Ok(Self::_Char(_Char::new_default(id))),
-                "String" => Ok(Self::_String(_String::new_default(id))),
+                "String" => Ok(Self::_String(_String::new_default(id).with_max_length(length)?)),
                 "WChar" => Ok(Self::WChar(WChar::new_default(id))),
-                "WString" => Ok(Self::WString(WString::new_default(id))),
+                "WString" => Ok(Self::WString(WString::new_default(id).with_max_length(length)?)),
                 _ => Err(error!(
                     format!("Invalid PlcString type: {}", ty),
                     format!("Parse PlcString")
@@ -75,7 +78,7 @@ impl TryFrom<&Map<String, Value>> for PlcString {
                                 Ok(Self::_Char(_Char::new(&char::from_str(v).unwrap(), id)?))
                             }
                         }
-                        "String" => Ok(Self::_String(_String::new(&plcstr(v.into()), id)?)),
+                        "String" => Ok(Self::_String(_String::new(&plcstr(v.into()), id)?.with_max_length(length)?)),
                         "WChar" => {
                             if v.len() > 1 {
                                 Err(error!(
@@ -86,7 +89,7 @@ impl TryFrom<&Map<String, Value>> for PlcString {
                                 Ok(Self::WChar(WChar::new(&char::from_str(v).unwrap(), id)?))
                             }
                         }
-                        "WString" => Ok(Self::WString(WString::new(&plcwstr(v.into()), id)?)),
+                        "WString" => Ok(Self::WString(WString::new(&plcwstr(v.into()), id)?.with_max_length(length)?)),
                         _ => Err(error!(
                             format!("Invalid PlcString type: {}", ty),
                             "Parse PlcString".to_string()
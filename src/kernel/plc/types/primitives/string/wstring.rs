@@ -5,7 +5,8 @@ use crate::container::error::error::Stop;
 use crate::kernel::plc::types::primitives::traits::family_traits::*;
 use crate::kernel::plc::types::primitives::traits::primitive_traits::*;
 use crate::kernel::plc::types::primitives::traits::meta_data::*;
-use crate::{error, impl_primitive_all, key_reader};
+use crate::{error, impl_primitive_string, key_reader};
+use crate::kernel::plc::types::primitives::traits::string_length::{StringLength, DEFAULT_STRING_LENGTH};
 use fixedstr::str256;
 use serde::ser::SerializeStruct;
 use serde::{Serialize, Serializer};
@@ -22,6 +23,7 @@ use crate::kernel::registry::get_string;
 pub struct WString {
     value: plcwstr,
     default: plcwstr,
+    max_length: usize,
 
     id: u32,
     read_only: bool,
@@ -29,7 +31,7 @@ pub struct WString {
     path: usize
 }
 
-impl_primitive_all!(WString, plcwstr);
+impl_primitive_string!(WString, plcwstr);
 
 #[derive(Debug, Clone, Copy, PartialOrd, Default, PartialEq)]
 pub struct plcwstr(pub str256);
@@ -54,10 +56,15 @@ impl TryFrom<&Map<String, Value>> for WString {
             format!("Parse WString"),
             data {
                 value => as_str,
+                length? => as_u64,
                 id => as_u64,
             }
         );
         let id = id as u32;
-        WString::new(&plcwstr(str256::from_str(value).map_err(|e| error!(format!("{}", e)))?), id)
+        let value = WString::new(&plcwstr(str256::from_str(value).map_err(|e| error!(format!("{}", e)))?), id)?;
+        match length {
+            Some(length) => value.with_max_length(length as usize),
+            None => Ok(value),
+        }
     }
 }
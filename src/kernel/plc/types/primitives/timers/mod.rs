@@ -2,6 +2,7 @@
 pub(crate) mod lTime;
 
 pub(crate) mod time;
+pub(crate) mod s5Time;
 pub mod plc_time;
 pub mod traits;
 
@@ -6,6 +6,7 @@ use crate::kernel::plc::types::primitives::traits::primitive_traits::{AsMutPrimi
 use crate::kernel::plc::types::primitives::string::wchar::wchar;
 use crate::kernel::plc::types::primitives::timers::lTime::LTime;
 use crate::kernel::plc::types::primitives::timers::time::Time;
+use crate::kernel::plc::types::primitives::timers::s5Time::{S5Time, S5TIME_MAX};
 use crate::{create_family, error, impl_primitive_traits, key_reader};
 use camelpaste::paste;
 use fixedstr::str256;
@@ -17,7 +18,7 @@ use crate::kernel::plc::types::primitives::string::wstring::plcwstr;
 
 create_family!(
     #[enum_dispatch(TimeDuration, MetaData, SetMetaData, ToggleMonitor)]
-    PlcTime(Time, LTime)
+    PlcTime(Time, LTime, S5Time)
 );
 
 impl_primitive_traits!(PlcTime, {
@@ -30,7 +31,7 @@ impl_primitive_traits!(PlcTime, {
     f64, [direct false], [stop Err(error!(format!("0")))], [none Err(error!(format!("0")))],
     u8, [direct false], [stop Err(error!(format!("0")))], [none Err(error!(format!("0")))],
     u16, [direct false], [stop Err(error!(format!("0")))], [none Err(error!(format!("0")))],
-    u32, [direct false], [stop Err(error!(format!("0")))], [none Err(error!(format!("0")))],
+    u32, [self.is_s5_time], [get_mut as_mut_s5_time], [get as_s5_time],
     u64, [direct false], [stop Err(error!(format!("0")))], [none Err(error!(format!("0")))],
     i8, [direct false], [stop Err(error!(format!("0")))], [none Err(error!(format!("0")))],
     i16, [direct false], [stop Err(error!(format!("0")))], [none Err(error!(format!("0")))],
@@ -69,6 +70,7 @@ impl TryFrom<&Map<String, Value>> for PlcTime {
             None => match ty {
                 "Time" => Ok(PlcTime::Time(Time::new_default(id))),
                 "LTime" => Ok(PlcTime::LTime(LTime::new_default(id))),
+                "S5Time" => Ok(PlcTime::S5Time(S5Time::new_default(id))),
                 _ => Err(error!(
                     format!("Invalid PlcTime type: {}", ty),
                     "Parse PlcTime".to_string()
@@ -79,6 +81,10 @@ impl TryFrom<&Map<String, Value>> for PlcTime {
                     match ty {
                         "Time" => Ok(PlcTime::Time(Time::new(&(v as i32), id)?)),
                         "LTime" => Ok(PlcTime::LTime(LTime::new(&(v), id)?)),
+                        "S5Time" => match u32::try_from(v) {
+                            Ok(a) if a <= S5TIME_MAX => Ok(PlcTime::S5Time(S5Time::new(&a, id)?)),
+                            _ => Err(error!(format!("S5Time {} is out of range 0..{}", v, S5TIME_MAX))),
+                        },
                         _ => Err(error!(
                             format!("Invalid PlcTime type: {}", ty),
                             "Parse PlcTime".to_string()
@@ -0,0 +1,64 @@
+﻿use crate::container::broadcast::broadcast::Broadcast;
+use crate::container::broadcast::store::MonitorChange;
+use crate::container::container::get_id;
+use crate::container::error::error::Stop;
+use crate::kernel::plc::types::primitives::traits::family_traits::*;
+use crate::kernel::plc::types::primitives::traits::primitive_traits::*;
+use crate::kernel::plc::types::primitives::traits::meta_data::*;
+use crate::kernel::plc::types::primitives::timers::traits::TimeDuration;
+use crate::{error, impl_primitive_all, key_reader};
+use serde::ser::SerializeStruct;
+use serde::{Serialize, Serializer};
+use serde_json::{Map, Value};
+use core::any::{Any, TypeId};
+use core::fmt::{Display, Formatter};
+use core::time::Duration;
+use std::borrow::Cow;
+use crate::kernel::registry::Kernel;
+use crate::kernel::registry::get_string;
+
+/// Longest S5TIME, S5T#2h46m30s.
+pub const S5TIME_MAX: u32 = 9_990_000;
+
+/// S5TIME, in ms.
+#[derive(Clone)]
+pub struct S5Time {
+    value: u32,
+    default: u32,
+
+    id: u32,
+    read_only: bool,
+    alias: Option<usize>,
+    path: usize
+}
+
+impl_primitive_all!(S5Time, u32, max S5TIME_MAX);
+
+impl TryFrom<&Map<String, Value>> for S5Time {
+    type Error = Stop;
+
+    fn try_from(data: &Map<String, Value>) -> Result<Self, Self::Error> {
+        key_reader!(
+            format!("Parse S5Time"),
+            data {
+                value => as_u64,
+                id => as_u64,
+            }
+        );
+        let id = id as u32;
+        match u32::try_from(value) {
+            Ok(a) if a <= S5TIME_MAX => S5Time::new(&a, id),
+            _ => Err(error!(format!("S5Time {} is out of range 0..{}", value, S5TIME_MAX))),
+        }
+    }
+}
+
+impl TimeDuration for S5Time {
+    fn set_duration(&mut self, duration: &Duration, channel: &Broadcast) -> Result<(), Stop> {
+        self.set(u32::try_from(duration.as_millis()).unwrap_or(u32::MAX), channel)
+    }
+
+    fn get_duration(&self) -> Duration {
+        Duration::from_millis(self.value as u64)
+    }
+}
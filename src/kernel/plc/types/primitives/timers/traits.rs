@@ -4,6 +4,7 @@ use crate::container::error::error::Stop;
 
 use crate::kernel::plc::types::primitives::timers::time::Time;
 use crate::kernel::plc::types::primitives::timers::lTime::LTime;
+use crate::kernel::plc::types::primitives::timers::s5Time::S5Time;
 use crate::kernel::plc::types::primitives::timers::plc_time::PlcTime;
 
 #[enum_dispatch::enum_dispatch]
@@ -1,6 +1,7 @@
 #[warn(unused_imports)]
 use crate::container::broadcast::broadcast::Broadcast;
 use crate::container::error::error::Stop;
+use crate::kernel::rust::overflow::Overflow;
 
 use crate::kernel::plc::types::primitives::integers::sint::SInt;
 use crate::kernel::plc::types::primitives::integers::usint::USInt;
@@ -16,25 +17,33 @@ use crate::kernel::plc::types::primitives::binaries::byte::Byte;
 use crate::kernel::plc::types::primitives::binaries::word::Word;
 use crate::kernel::plc::types::primitives::binaries::dword::DWord;
 use crate::kernel::plc::types::primitives::binaries::lword::LWord;
+use crate::kernel::plc::types::primitives::binaries::slice_access::{ByteAccess, DWordAccess, WordAccess};
 use crate::kernel::plc::types::primitives::binaries::plc_binary::PlcBinary;
 
 
+/// Adds or subtracts 1 following the overflow policy, true when the value was wrapped or saturated.
 #[enum_dispatch::enum_dispatch]
 pub trait Crement {
-    fn increment(&mut self, channel: &Broadcast) -> Result<(), Stop>;
-    fn decrement(&mut self, channel: &Broadcast) -> Result<(), Stop>;
+    fn increment(&mut self, overflow: Overflow, channel: &Broadcast) -> Result<bool, Stop>;
+    fn decrement(&mut self, overflow: Overflow, channel: &Broadcast) -> Result<bool, Stop>;
 }
 
 #[macro_export]
 macro_rules! impl_primitive_crement {
     ($primitive: ident) => {
         impl Crement for $primitive {
-            fn increment(&mut self, channel: &Broadcast) -> Result<(), Stop> {
-                self.set(self.value + 1, channel)
+            fn increment(&mut self, overflow: $crate::kernel::rust::overflow::Overflow, channel: &Broadcast) -> Result<bool, Stop> {
+                let (value, flagged) = $crate::kernel::rust::overflow::Overflowing::overflowing_flagged(self.value, "add", 1, overflow)
+                    .ok_or_else(|| $crate::error!(format!("Invalid operation: Can not increment {}", self)))?;
+                self.set(value, channel)?;
+                Ok(flagged)
             }
 
-            fn decrement(&mut self, channel: &Broadcast) -> Result<(), Stop> {
-                self.set(self.value - 1, channel)
+            fn decrement(&mut self, overflow: $crate::kernel::rust::overflow::Overflow, channel: &Broadcast) -> Result<bool, Stop> {
+                let (value, flagged) = $crate::kernel::rust::overflow::Overflowing::overflowing_flagged(self.value, "sub", 1, overflow)
+                    .ok_or_else(|| $crate::error!(format!("Invalid operation: Can not decrement {}", self)))?;
+                self.set(value, channel)?;
+                Ok(flagged)
             }
         }
     };
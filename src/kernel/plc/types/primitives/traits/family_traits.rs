@@ -16,6 +16,7 @@ use crate::kernel::plc::types::primitives::string::plc_string::PlcString;
 use crate::kernel::plc::types::primitives::string::wchar::wchar;
 use crate::kernel::plc::types::primitives::timers::plc_time::PlcTime;
 use crate::kernel::plc::types::primitives::tod::plc_tod::PlcTod;
+use crate::kernel::plc::types::primitives::date::plc_date::PlcDate;
 use camelpaste::paste;
 
 use core::fmt::{Display, Formatter};
@@ -27,6 +28,7 @@ use crate::kernel::plc::types::primitives::boolean::bit_access::BitAccess;
 use crate::kernel::plc::types::primitives::binaries::byte::Byte;
 use crate::kernel::plc::types::primitives::binaries::dword::DWord;
 use crate::kernel::plc::types::primitives::binaries::lword::LWord;
+use crate::kernel::plc::types::primitives::binaries::slice_access::{ByteAccess, DWordAccess, WordAccess};
 use crate::kernel::plc::types::primitives::binaries::word::Word;
 
 use crate::kernel::plc::types::primitives::integers::dint::DInt;
@@ -48,9 +50,14 @@ use crate::kernel::plc::types::primitives::string::wstring::WString;
 
 use crate::kernel::plc::types::primitives::timers::lTime::LTime;
 use crate::kernel::plc::types::primitives::timers::time::Time;
+use crate::kernel::plc::types::primitives::timers::s5Time::S5Time;
 
 use crate::kernel::plc::types::primitives::tod::ltod::LTod;
 use crate::kernel::plc::types::primitives::tod::tod::Tod;
+use crate::kernel::plc::types::primitives::date::date::Date;
+use crate::kernel::plc::types::primitives::date::dt::Dt;
+use crate::kernel::plc::types::primitives::date::dtl::Dtl;
+use crate::kernel::plc::types::primitives::date::ldt::Ldt;
 use crate::kernel::registry::Kernel;
 use crate::kernel::arch::local::r#type::LocalType;
 use crate::kernel::arch::any::any_type::AnyRefType;
@@ -91,6 +98,6 @@ macro_rules! create_families_traits {
 }
 
 create_families_traits!(
-    PlcBool, PlcInteger, PlcFloat, PlcBinary, PlcTime, PlcTod, PlcString, PlcArray,
+    PlcBool, PlcInteger, PlcFloat, PlcBinary, PlcTime, PlcTod, PlcDate, PlcString, PlcArray,
     PlcStruct, FbInstance
 );
@@ -4,3 +4,4 @@ pub mod reset;
 pub mod family_traits;
 pub mod primitive_traits;
 pub mod meta_data;
+pub mod string_length;
@@ -0,0 +1,53 @@
+use fixedstr::str256;
+use crate::kernel::plc::types::primitives::string::_string::plcstr;
+use crate::kernel::plc::types::primitives::string::wstring::plcwstr;
+
+/// Length of a String or WString when none is declared, String[254] in TIA Portal.
+pub const DEFAULT_STRING_LENGTH: usize = 254;
+
+/// Characters of a String or WString, truncated to their declared length.
+pub trait StringLength: Sized {
+    fn as_text(&self) -> &str;
+    fn from_text(text: &str) -> Self;
+
+    fn char_len(&self) -> usize {
+        self.as_text().chars().count()
+    }
+
+    /// Keeps the first `max_length` characters, without splitting a character
+    /// at the end of the 255 bytes buffer.
+    fn fit(&self, max_length: usize) -> Self {
+        Self::from_text(fit_text(self.as_text(), max_length))
+    }
+}
+
+pub fn fit_text(text: &str, max_length: usize) -> &str {
+    let end = text
+        .char_indices()
+        .map(|(index, c)| index + c.len_utf8())
+        .take(max_length)
+        .take_while(|end| *end <= 255)
+        .last()
+        .unwrap_or(0);
+    &text[..end]
+}
+
+impl StringLength for plcstr {
+    fn as_text(&self) -> &str {
+        self.0.as_str()
+    }
+
+    fn from_text(text: &str) -> Self {
+        plcstr(str256::from(fit_text(text, DEFAULT_STRING_LENGTH)))
+    }
+}
+
+impl StringLength for plcwstr {
+    fn as_text(&self) -> &str {
+        self.0.as_str()
+    }
+
+    fn from_text(text: &str) -> Self {
+        plcwstr(str256::from(fit_text(text, DEFAULT_STRING_LENGTH)))
+    }
+}
@@ -7,40 +7,34 @@ use crate::kernel::arch::global::pointer::GlobalPointer;
 use crate::kernel::arch::global::r#type::GlobalType;
 use crate::kernel::arch::local::pointer::LocalPointer;
 use crate::kernel::arch::reset::reset::RawPointers;
+use crate::kernel::arch::snapshot::snapshot::{InternalState, InternalStates};
 use crate::container::error::error::Stop;
 use core::cell::RefCell;
 use std::collections::hash_map::Entry;
 use std::collections::{HashMap, HashSet};
 use core::ops::{Deref, DerefMut};
 use std::rc::Rc;
-use std::sync::{Arc, Mutex};
-use core::sync::atomic::Ordering;
 use crate::container::broadcast::broadcast::Broadcast;
 use crate::kernel::plc::interface::section::Section;
 use crate::kernel::plc::types::primitives::traits::meta_data::MetaData;
 use crate::kernel::arch::constant::r#type::ConstantType;
-use bimap::BiMap;
-use once_cell::sync::Lazy;
-use crate::container::container::COUNTER;
+use crate::kernel::plc::types::enumeration::PlcEnum;
+use crate::kernel::rust::overflow::{Overflow, OverflowReport};
+use crate::kernel::rust::float_exception::FloatExceptions;
+use crate::container::state::with_state;
+use crate::container::simulation::clock::SimClock;
+use crate::container::simulation::force::ForceTable;
+use crate::container::simulation::scheduler::ObScheduler;
+use crate::kernel::plc::operations::program_control::loop_control::LoopScopes;
 use crate::kernel::plc::types::primitives::traits::primitive_traits::{PrimitiveTrait, SerializeValue};
 
-pub static STRINGS: Lazy<Arc<Mutex<BiMap<String, usize>>>> =
-    Lazy::new(|| Arc::new(Mutex::new(BiMap::new())));
-
 pub fn get_or_insert_global_string(string: &String) -> usize {
-    let a = (*STRINGS.lock().unwrap()).get_by_left(string).map(|x| *x);
-    if let Some(a) = a {
-        a
-    } else {
-        let id = get_string_id();
-        (*STRINGS.lock().unwrap()).insert(string.clone(), id);
-        id
-    }
+    with_state(|state| state.get_or_insert_string(string))
 }
 
 pub fn get_string(id: usize) -> String {
-    (*STRINGS.lock().unwrap()).get_by_right(&id).cloned()
-        .unwrap_or_else(|| "".to_string()).clone()
+    with_state(|state| state.get_string(id))
+        .unwrap_or_else(|| "".to_string())
 }
 
 pub fn get_full_path(path: &Vec<usize>) -> Vec<String> {
@@ -48,9 +42,9 @@ pub fn get_full_path(path: &Vec<usize>) -> Vec<String> {
     path
         .iter()
         .for_each(|u| {
-            match (*STRINGS.lock().unwrap()).get_by_right(u) {
+            match with_state(|state| state.get_string(*u)) {
                 None => full_path.push("?".into()),
-                Some(a) => full_path.push(a.clone())
+                Some(a) => full_path.push(a)
             }
         });
     full_path
@@ -120,7 +114,7 @@ impl GlobalPointerMap {
         }
     }
 
-    fn iter(&self) -> impl Iterator<Item=(&usize, &GlobalPointer)> {
+    pub fn iter(&self) -> impl Iterator<Item=(&usize, &GlobalPointer)> {
         self.0.iter()
     }
 }
@@ -141,16 +135,31 @@ pub struct Kernel {
     pub program_raw_pointers: RefCell<RawPointers>,
     
     pub monitor_raw_pointers: RefCell<HashMap<u32, *const dyn SerializeValue>>,
+    pub internal_states: RefCell<InternalStates>,
 
     exclude_types: Vec<String>,
     filter_operations: HashMap<Operation, HashMap<FirstType, HashSet<SecondType>>>,
     operations_return: HashMap<Operation, HashMap<FirstType, HashMap<SecondType, ConstantType>>>,
     exclude_sections: HashMap<Section, HashSet<String>>,
+    /// Overflow of the integer operations, by type
+    overflow: HashMap<String, Overflow>,
 
     type_aliases: HashMap<String, ConstantType>,
     all_types_id: Vec<String>,
 
+    provider_enums: HashMap<String, Rc<PlcEnum>>,
+    program_enums: HashMap<String, Rc<PlcEnum>>,
+    /// Enumeration of the monitored variables, by id
+    pub monitor_enums: RefCell<HashMap<u32, Rc<PlcEnum>>>,
+
     ignore_operation: Rc<RefCell<bool>>,
+
+    pub clock: SimClock,
+    pub forces: ForceTable,
+    pub scheduler: ObScheduler,
+    pub loops: LoopScopes,
+    pub floats: FloatExceptions,
+    pub overflows: OverflowReport,
 }
 
 impl Default for Kernel {
@@ -165,22 +174,35 @@ impl Default for Kernel {
             raw_pointers_collector: RefCell::new(RawPointers::default()),
             provider_raw_pointers: RefCell::new(RawPointers::default()),
             monitor_raw_pointers: RefCell::new(HashMap::new()),
+            internal_states: RefCell::new(InternalStates::default()),
             program_raw_pointers: RefCell::new(RawPointers::default()),
             exclude_types: vec!(),
             filter_operations: HashMap::default(),
             operations_return: HashMap::default(),
             exclude_sections: HashMap::default(),
+            overflow: HashMap::default(),
 
             type_aliases: HashMap::default(),
             all_types_id: vec!(),
 
+            provider_enums: HashMap::default(),
+            program_enums: HashMap::default(),
+            monitor_enums: RefCell::new(HashMap::new()),
+
             ignore_operation: Rc::new(RefCell::new(false)),
+
+            clock: SimClock::default(),
+            forces: ForceTable::default(),
+            scheduler: ObScheduler::default(),
+            loops: LoopScopes::default(),
+            floats: FloatExceptions::default(),
+            overflows: OverflowReport::default(),
         }
     }
 }
 
 pub fn get_string_id() -> usize {
-    COUNTER.fetch_add(1, Ordering::Relaxed)
+    with_state(|state| state.next_id())
 }
 
 
@@ -201,6 +223,16 @@ impl Kernel {
         }
     }
 
+    /// All the Obs of the provider and the program
+    pub fn get_obs(&self) -> Vec<(usize, GlobalPointer)> {
+        self.provider
+            .iter()
+            .chain(self.program.iter())
+            .filter(|(_, pointer)| matches!(pointer.as_ref().borrow().deref(), GlobalType::Ob(_)))
+            .map(|(name, pointer)| (*name, pointer.clone()))
+            .collect()
+    }
+
     pub fn add_type_alias(&mut self, name: &str, of: ConstantType) -> usize {
         let name = name.to_string();
         self.type_aliases.insert(name.clone(), of);
@@ -220,6 +252,36 @@ impl Kernel {
         self.type_aliases.get(name)
     }
 
+    /// Registers an enumeration of the provider, or of the program with `in_program`.
+    pub fn add_enum(&mut self, plc_enum: PlcEnum, in_program: bool) -> Result<(), Stop> {
+        let name = plc_enum.get_name().to_string();
+        if self.type_aliases.contains_key(&name) || self.get_enum(&name).is_some() {
+            return Err(error!(format!("Type {} is already declared", name)));
+        }
+        if self.get_type_alias_id(&name).is_none() {
+            self.all_types_id.push(name.clone());
+        }
+        match in_program {
+            true => self.program_enums.insert(name, Rc::new(plc_enum)),
+            false => self.provider_enums.insert(name, Rc::new(plc_enum)),
+        };
+        Ok(())
+    }
+
+    pub fn get_enum(&self, name: &str) -> Option<Rc<PlcEnum>> {
+        self.program_enums
+            .get(name)
+            .or_else(|| self.provider_enums.get(name))
+            .cloned()
+    }
+
+    /// Enumeration of a variable, None if it is not an enumerated variable.
+    pub fn get_enum_of<T: MetaData>(&self, meta_data: &T) -> Option<Rc<PlcEnum>> {
+        meta_data
+            .get_alias_str(self)
+            .and_then(|name| self.get_enum(name))
+    }
+
     pub fn get_mut_excluded_operation(&mut self, operation: &str) -> & mut HashMap<FirstType, HashSet<SecondType>> {
         self.filter_operations.entry(operation.into()).or_default()
     }
@@ -236,6 +298,20 @@ impl Kernel {
         self.exclude_sections.entry(*operation).or_default()
     }
 
+    pub fn set_overflow(&mut self, of: &str, overflow: Overflow) {
+        self.overflow.insert(of.into(), overflow);
+    }
+
+    /// Overflow of the operations on a variable, from its alias first then its type.
+    pub fn get_overflow<T: MetaData>(&self, meta_data: &T) -> Overflow {
+        meta_data
+            .get_alias_str(self)
+            .and_then(|alias| self.overflow.get(alias))
+            .or_else(|| self.overflow.get(meta_data.name()))
+            .copied()
+            .unwrap_or_default()
+    }
+
     pub fn check_filtered_operation<T: MetaData, Y: MetaData>(&self, operation: &str, meta_data_t1: &T, meta_data_t2: &Y) -> Result<(), Stop> {
         // Find the operation
         match self.filter_operations.get(operation) {
@@ -345,17 +421,26 @@ impl Kernel {
     }
 
     // Reset methods
-    pub fn reset_all(&mut self, channel: &Broadcast) {
+    pub fn reset_all(&mut self, channel: &Broadcast) -> Result<(), Stop> {
         self.provider_raw_pointers.borrow_mut().filter_dangling();
-        self.provider_raw_pointers.borrow_mut().reset_all(channel).unwrap();
+        self.provider_raw_pointers.borrow_mut().reset_all(channel)?;
         self.program_raw_pointers.borrow_mut().filter_dangling();
-        self.program_raw_pointers.borrow_mut().reset_all(channel).unwrap();
-        channel.reset_unit_tests();
+        self.program_raw_pointers.borrow_mut().reset_all(channel)?;
+        self.internal_states.borrow().reset();
+        self.scheduler.reset();
+        self.clock.reset();
+        Ok(())
     }
 
     pub fn clear_program(&mut self, channel: &Broadcast) {
+        self.forces.clear();
+        self.internal_states.borrow_mut().clear();
         self.program.0.clear();
-        self.reset_all(channel);
+        self.program_enums.clear();
+        self.monitor_enums.borrow_mut().clear();
+        if let Err(e) = self.reset_all(channel) {
+            channel.add_error(&e);
+        }
         channel.clear_unit_tests();
         channel.clear_breakpoints();
         channel.clear_entry_points();
@@ -365,10 +450,14 @@ impl Kernel {
     pub fn clear_all(&mut self, channel: &Broadcast) {
         self.clear_program(channel);
         self.provider.0.clear();
+        self.provider_enums.clear();
         self.provider_raw_pointers.borrow_mut().clear_all();
         self.exclude_types.clear();
         self.exclude_sections.clear();
         self.filter_operations.clear();
+        self.overflow.clear();
+        self.floats.clear();
+        self.overflows.clear();
     }
 
     pub fn try_build_program_interfaces(&mut self, channel: &Broadcast) -> Result<(), Stop> {
@@ -8,6 +8,9 @@ use crate::container::error::error::Stop;
 use crate::kernel::plc::types::primitives::traits::meta_data::MetaData;
 use crate::kernel::plc::types::primitives::traits::primitive_traits::{AsMutPrimitive, Primitive};
 use crate::kernel::registry::Kernel;
+use crate::kernel::arch::any::any_type::AnyRefType;
+use crate::kernel::arch::local::pointer::LocalPointer;
+use crate::kernel::rust::enumeration::box_set_plc_enum;
 
 pub fn box_set_auto<T: 'static + MetaData + WithMutFamily + WithTypeFamily + Clone + Display + IsFamily + AsMutPrimitive + Primitive, 
     Y: 'static + MetaData + WithRefFamily + WithTypeFamily + Clone + Display + IsFamily + Primitive>(o1: &T, o2: &Y, trace: u32, registry: &Kernel) -> Result<RunTimeOperation, Stop> {
@@ -27,4 +30,13 @@ pub fn box_set_auto_default_once<T: 'static + MetaData + WithMutFamily + WithTyp
     else {
         box_set_plc_primitive_default_once(o1, o2)
     }
-}
\ No newline at end of file
+}
+
+/// Like [`box_set_auto`] for a parameter of a call, the value of an enumerated parameter must be
+/// one of its enumeration.
+pub fn box_set_auto_enum(variable: &LocalPointer, value: &AnyRefType, trace: u32, registry: &Kernel) -> Result<RunTimeOperation, Stop> {
+    match registry.get_enum_of(variable) {
+        Some(plc_enum) => box_set_plc_enum(variable, value, plc_enum, trace, registry),
+        None => box_set_auto(variable, value, trace, registry),
+    }
+}
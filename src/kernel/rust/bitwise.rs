@@ -0,0 +1,103 @@
+use crate::container::broadcast::broadcast::Broadcast;
+use crate::container::error::error::Stop;
+use crate::error;
+use crate::kernel::arch::any::any_type::AnyRefType;
+use crate::kernel::arch::local::pointer::LocalPointer;
+use crate::kernel::arch::local::r#type::{IntoLocalType, LocalType};
+use crate::kernel::plc::operations::operations::{Operation, RunTimeOperation};
+use crate::kernel::plc::types::primitives::traits::family_traits::IsFamily;
+use crate::kernel::plc::types::primitives::traits::meta_data::{HeapOrStatic, MaybeHeapOrStatic};
+use crate::kernel::plc::types::primitives::traits::primitive_traits::{AsMutPrimitive, Primitive};
+use crate::kernel::registry::Kernel;
+use core::cell::RefCell;
+use core::ops::DerefMut;
+use std::rc::Rc;
+
+fn bit_width<T: Primitive>(value: &T) -> u8 {
+    if value.is_bool() {
+        1
+    } else if value.is_u8() {
+        8
+    } else if value.is_u16() {
+        16
+    } else if value.is_u32() {
+        32
+    } else {
+        64
+    }
+}
+
+fn is_bitwise<T: IsFamily>(value: &T) -> bool {
+    value.is_plc_binary() || value.is_plc_bool()
+}
+
+fn read_bits(value: &AnyRefType, channel: &Broadcast) -> Result<u64, Stop> {
+    Ok(match bit_width(value) {
+        1 => value.as_bool(channel)? as u64,
+        8 => value.as_u8(channel)? as u64,
+        16 => value.as_u16(channel)? as u64,
+        32 => value.as_u32(channel)? as u64,
+        _ => value.as_u64(channel)?,
+    })
+}
+
+fn write_bits(target: &mut LocalType, bits: u64, channel: &Broadcast) -> Result<(), Stop> {
+    match bit_width(target) {
+        1 => target.set_bool(bits & 1 == 1, channel),
+        8 => target.set_u8(bits as u8, channel),
+        16 => target.set_u16(bits as u16, channel),
+        32 => target.set_u32(bits as u32, channel),
+        _ => target.set_u64(bits, channel),
+    }
+}
+
+/// Builds AND, OR and XOR of two Bools or of two binaries. Binaries of different widths are
+/// extended to the widest one, which is the return type.
+pub fn box_bitwise(operator: &'static str, o1: &AnyRefType, o2: &AnyRefType, trace: u32, kernel: &Kernel) -> Result<RunTimeOperation, Stop> {
+    kernel.check_filtered_operation(operator, o1, o2)?;
+    if !is_bitwise(o1) || !is_bitwise(o2) || o1.is_plc_bool() != o2.is_plc_bool() {
+        return Err(error!(format!("Invalid operation: Can not {} {} with {}, expected two Bools or two binaries", operator, o1, o2)).add_id(trace));
+    }
+
+    let function: fn(u64, u64) -> u64 = match operator {
+        "and" => |a, b| a & b,
+        "or" => |a, b| a | b,
+        "xor" => |a, b| a ^ b,
+        _ => return Err(error!(format!("Invalid operation: Unknown bitwise operator {}", operator)).add_id(trace)),
+    };
+
+    let return_ptr = match kernel.check_return_operation(operator, o1, o2) {
+        Some(a) => LocalPointer::new(a.transform()?),
+        None if bit_width(o2) > bit_width(o1) => LocalPointer::new(o2.transform()?),
+        None => LocalPointer::new(o1.transform()?),
+    };
+    let return_ptr_clone = return_ptr.clone();
+    let (o1, o2) = (o1.clone(), o2.clone());
+    let (o1_display, o2_display) = (o1.clone(), o2.clone());
+
+    Ok(Box::new(Operation::new(
+        MaybeHeapOrStatic(Some(HeapOrStatic::Closure(Rc::new(RefCell::new(move || format!("{} {} {}", o1_display, operator.to_uppercase(), o2_display)))))),
+        move |channel| {
+            let result = function(read_bits(&o1, channel)?, read_bits(&o2, channel)?);
+            write_bits(return_ptr.as_ref().borrow_mut().deref_mut(), result, channel)
+        }, Some(return_ptr_clone), false, trace)))
+}
+
+/// Builds NOT of a Bool or the complement of a binary.
+pub fn box_bitwise_not(o1: &AnyRefType, trace: u32, kernel: &Kernel) -> Result<RunTimeOperation, Stop> {
+    kernel.check_filtered_operation("not", o1, o1)?;
+    if !is_bitwise(o1) {
+        return Err(error!(format!("Invalid operation: Can not not {}, expected a Bool or a binary", o1)).add_id(trace));
+    }
+
+    let return_ptr = LocalPointer::new(o1.transform()?);
+    let return_ptr_clone = return_ptr.clone();
+    let (o1, o1_display) = (o1.clone(), o1.clone());
+
+    Ok(Box::new(Operation::new(
+        MaybeHeapOrStatic(Some(HeapOrStatic::Closure(Rc::new(RefCell::new(move || format!("NOT {}", o1_display)))))),
+        move |channel| {
+            let result = !read_bits(&o1, channel)?;
+            write_bits(return_ptr.as_ref().borrow_mut().deref_mut(), result, channel)
+        }, Some(return_ptr_clone), false, trace)))
+}
@@ -0,0 +1,348 @@
+use crate::container::broadcast::broadcast::Broadcast;
+use crate::container::error::error::Stop;
+use crate::error;
+use crate::kernel::arch::local::pointer::LocalPointer;
+use crate::kernel::arch::local::r#type::{IntoLocalType, LocalType};
+use crate::kernel::plc::operations::operations::{Operation, RunTimeOperation};
+use crate::kernel::plc::types::primitives::traits::family_traits::IsFamily;
+use crate::kernel::plc::types::primitives::traits::meta_data::{HeapOrStatic, MaybeHeapOrStatic, MetaData};
+use crate::kernel::plc::types::primitives::traits::primitive_traits::{AsMutPrimitive, Primitive};
+use crate::kernel::plc::types::primitives::timers::s5Time::S5TIME_MAX;
+use crate::kernel::rust::overflow::{Overflow, OverflowReport};
+use core::cell::RefCell;
+use core::fmt::Display;
+use core::ops::DerefMut;
+use std::rc::Rc;
+
+/// Family and width of a convertible primitive.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ConvertKind {
+    Bool,
+    Integer { bits: u32, signed: bool },
+    Binary { bits: u32 },
+    Float { bits: u32 },
+    /// Unit of the duration in ns (ms for Time, ns for LTime)
+    Time { bits: u32, unit: i128 },
+    /// Duration in ms from 0 to S5T#2h46m30s
+    S5Time,
+}
+
+/// Value read from, or written to, a primitive of the given kind.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ConvertValue {
+    Bool(bool),
+    Integer(i128),
+    /// Raw bits
+    Binary(u64),
+    Float(f64),
+    /// Count of units of the kind
+    Time(i128),
+}
+
+/// How a float is brought to an integer.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Rounding {
+    /// To the nearest integer, halfway cases to the nearest even integer
+    Round,
+    Trunc,
+    Ceil,
+    Floor,
+}
+
+impl TryFrom<&str> for Rounding {
+    type Error = Stop;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "round" => Ok(Rounding::Round),
+            "trunc" => Ok(Rounding::Trunc),
+            "ceil" => Ok(Rounding::Ceil),
+            "floor" => Ok(Rounding::Floor),
+            _ => Err(error!(format!("Invalid rounding '{}', expected round, trunc, ceil or floor", value))),
+        }
+    }
+}
+
+impl ConvertKind {
+    pub fn of<T: IsFamily + Primitive>(value: &T) -> Option<ConvertKind> {
+        if value.is_plc_bool() {
+            Some(ConvertKind::Bool)
+        } else if value.is_plc_integer() {
+            [(value.is_i8(), 8, true), (value.is_u8(), 8, false),
+             (value.is_i16(), 16, true), (value.is_u16(), 16, false),
+             (value.is_i32(), 32, true), (value.is_u32(), 32, false),
+             (value.is_i64(), 64, true), (value.is_u64(), 64, false)]
+                .into_iter()
+                .find(|(is, ..)| *is)
+                .map(|(_, bits, signed)| ConvertKind::Integer { bits, signed })
+        } else if value.is_plc_binary() {
+            [(value.is_u8(), 8), (value.is_u16(), 16), (value.is_u32(), 32), (value.is_u64(), 64)]
+                .into_iter()
+                .find(|(is, _)| *is)
+                .map(|(_, bits)| ConvertKind::Binary { bits })
+        } else if value.is_plc_float() {
+            match value.is_f32() {
+                true => Some(ConvertKind::Float { bits: 32 }),
+                false => Some(ConvertKind::Float { bits: 64 }),
+            }
+        } else if value.is_plc_time() {
+            if value.is_i32() {
+                Some(ConvertKind::Time { bits: 32, unit: 1_000_000 })
+            } else if value.is_i64() {
+                Some(ConvertKind::Time { bits: 64, unit: 1 })
+            } else if value.is_u32() {
+                Some(ConvertKind::S5Time)
+            } else {
+                None
+            }
+        } else {
+            None
+        }
+    }
+
+    /// Conversions allowed between families, following IEC 61131-3.
+    /// A float and a binary of the same width exchange their bits.
+    pub fn can_convert_to(&self, to: &ConvertKind) -> bool {
+        match (self, to) {
+            (ConvertKind::Integer { .. }, _) => true,
+            (ConvertKind::Bool, ConvertKind::Bool | ConvertKind::Integer { .. } | ConvertKind::Binary { .. }) => true,
+            (ConvertKind::Binary { .. }, ConvertKind::Bool | ConvertKind::Integer { .. } | ConvertKind::Binary { .. }) => true,
+            (ConvertKind::Binary { bits }, ConvertKind::Float { bits: b }) => bits == b,
+            (ConvertKind::Float { .. }, ConvertKind::Integer { .. } | ConvertKind::Float { .. }) => true,
+            (ConvertKind::Float { bits }, ConvertKind::Binary { bits: b }) => bits == b,
+            (ConvertKind::Time { .. } | ConvertKind::S5Time, ConvertKind::Integer { .. } | ConvertKind::Time { .. } | ConvertKind::S5Time) => true,
+            _ => false,
+        }
+    }
+
+    /// Unit in ns of a duration, None when the kind is not a time.
+    pub fn time_unit(&self) -> Option<i128> {
+        match self {
+            ConvertKind::Time { unit, .. } => Some(*unit),
+            ConvertKind::S5Time => Some(1_000_000),
+            _ => None,
+        }
+    }
+}
+
+fn fits(value: i128, bits: u32, signed: bool) -> bool {
+    match signed {
+        true => value >= -(1 << (bits - 1)) && value < (1 << (bits - 1)),
+        false => value >= 0 && value < (1 << bits),
+    }
+}
+
+/// Keeps the lowest `bits` of the two's complement of the value.
+fn wrap(value: i128, bits: u32, signed: bool) -> i128 {
+    let raw = value & ((1 << bits) - 1);
+    match signed && raw >= (1 << (bits - 1)) {
+        true => raw - (1 << bits),
+        false => raw,
+    }
+}
+
+fn round(value: f64, rounding: Rounding) -> f64 {
+    match rounding {
+        Rounding::Round => value.round_ties_even(),
+        Rounding::Trunc => value.trunc(),
+        Rounding::Ceil => value.ceil(),
+        Rounding::Floor => value.floor(),
+    }
+}
+
+/// Converts a value of kind `from` to the kind `to`.
+///
+/// Returns the converted value and whether the conversion kept the value. A lossy integer
+/// conversion keeps the lowest bits of the value, like the PLC does when ENO is ignored.
+/// The kinds must be checked with [`ConvertKind::can_convert_to`] beforehand.
+pub fn convert_value(value: ConvertValue, from: ConvertKind, to: ConvertKind, rounding: Rounding) -> (ConvertValue, bool) {
+    // Float targets
+    if let ConvertKind::Float { bits } = to {
+        let value = match value {
+            ConvertValue::Float(a) => a,
+            ConvertValue::Binary(a) if bits == 32 => f32::from_bits(a as u32) as f64,
+            ConvertValue::Binary(a) => f64::from_bits(a),
+            ConvertValue::Integer(a) => a as f64,
+            ConvertValue::Bool(a) => a as u8 as f64,
+            ConvertValue::Time(a) => a as f64,
+        };
+        return match bits {
+            32 => {
+                let narrowed = value as f32 as f64;
+                (ConvertValue::Float(narrowed), !(value.is_finite() && narrowed.is_infinite()))
+            }
+            _ => (ConvertValue::Float(value), true),
+        };
+    }
+
+    // Floats to binaries exchange their bits
+    if let (ConvertValue::Float(a), ConvertKind::Binary { bits }) = (value, to) {
+        return match bits {
+            32 => (ConvertValue::Binary((a as f32).to_bits() as u64), true),
+            _ => (ConvertValue::Binary(a.to_bits()), true),
+        };
+    }
+
+    // Everything else goes through an integer, bit strings are unsigned
+    let (integer, bit_string, exact) = match value {
+        ConvertValue::Bool(a) => (a as i128, true, true),
+        ConvertValue::Binary(a) => (a as i128, true, true),
+        ConvertValue::Integer(a) => (a, false, true),
+        ConvertValue::Time(a) => match (from.time_unit(), to.time_unit()) {
+            // LTIME#1500us is T#1ms, the dropped part makes the conversion lossy
+            (Some(from_unit), Some(to_unit)) => (a * from_unit / to_unit, false, a * from_unit % to_unit == 0),
+            _ => (a, false, true),
+        },
+        ConvertValue::Float(a) => {
+            let rounded = round(a, rounding);
+            (rounded as i128, false, rounded.is_finite())
+        }
+    };
+
+    match to {
+        ConvertKind::Bool => (ConvertValue::Bool(integer != 0), exact && (integer == 0 || integer == 1)),
+        ConvertKind::Integer { bits, signed } => {
+            let kept = match bit_string {
+                // Bit strings are transferred as is, e.g. WORD#16#FFFF is INT#-1
+                true => integer < (1 << bits),
+                false => fits(integer, bits, signed),
+            };
+            (ConvertValue::Integer(wrap(integer, bits, signed)), exact && kept)
+        }
+        ConvertKind::Binary { bits } => {
+            let kept = fits(integer, bits, false) || fits(integer, bits, true);
+            (ConvertValue::Binary(wrap(integer, bits, false) as u64), exact && kept)
+        }
+        ConvertKind::Time { bits, .. } => (ConvertValue::Time(wrap(integer, bits, true)), exact && fits(integer, bits, true)),
+        // There are no bits to wrap around, the value is clamped instead
+        ConvertKind::S5Time => {
+            let kept = (0..=S5TIME_MAX as i128).contains(&integer);
+            (ConvertValue::Time(integer.clamp(0, S5TIME_MAX as i128)), exact && kept)
+        }
+        ConvertKind::Float { .. } => unreachable!(),
+    }
+}
+
+/// Clamps a value which does not fit an integer, binary or time target to the range of the
+/// target. None when the value is not a number (NaN, a Bool ...) or the target is not integral.
+pub fn saturate_value(value: ConvertValue, from: ConvertKind, to: ConvertKind, rounding: Rounding) -> Option<ConvertValue> {
+    let integer = match value {
+        ConvertValue::Integer(a) => a,
+        ConvertValue::Time(a) => match (from.time_unit(), to.time_unit()) {
+            (Some(from_unit), Some(to_unit)) => a * from_unit / to_unit,
+            _ => a,
+        },
+        ConvertValue::Binary(a) => a as i128,
+        ConvertValue::Float(a) if !a.is_nan() => round(a, rounding) as i128,
+        _ => return None,
+    };
+    match to {
+        ConvertKind::Integer { bits, signed: true } => Some(ConvertValue::Integer(integer.clamp(-(1 << (bits - 1)), (1 << (bits - 1)) - 1))),
+        ConvertKind::Integer { bits, .. } => Some(ConvertValue::Integer(integer.clamp(0, (1 << bits) - 1))),
+        ConvertKind::Binary { bits } => Some(ConvertValue::Binary(integer.clamp(0, (1 << bits) - 1) as u64)),
+        ConvertKind::Time { bits, .. } => Some(ConvertValue::Time(integer.clamp(-(1 << (bits - 1)), (1 << (bits - 1)) - 1))),
+        ConvertKind::S5Time => Some(ConvertValue::Time(integer.clamp(0, S5TIME_MAX as i128))),
+        _ => None,
+    }
+}
+
+/// Converts a value following the overflow policy, with true when it was wrapped or saturated.
+/// None when the value is not kept with [`Overflow::Error`].
+pub fn fit_value(value: ConvertValue, from: ConvertKind, to: ConvertKind, rounding: Rounding, overflow: Overflow) -> Option<(ConvertValue, bool)> {
+    match (convert_value(value, from, to, rounding), overflow) {
+        ((value, true), _) => Some((value, false)),
+        ((value, false), Overflow::Wrap) => Some((value, true)),
+        (_, Overflow::Saturate) => saturate_value(value, from, to, rounding).map(|a| (a, true)),
+        (_, Overflow::Error) => None,
+    }
+}
+
+/// Reads the current value of a primitive of the given kind.
+pub fn read_value<T: Primitive>(source: &T, kind: ConvertKind, channel: &Broadcast) -> Result<ConvertValue, Stop> {
+    Ok(match kind {
+        ConvertKind::Bool => ConvertValue::Bool(source.as_bool(channel)?),
+        ConvertKind::Integer { bits: 8, signed: true } => ConvertValue::Integer(source.as_i8(channel)? as i128),
+        ConvertKind::Integer { bits: 8, .. } => ConvertValue::Integer(source.as_u8(channel)? as i128),
+        ConvertKind::Integer { bits: 16, signed: true } => ConvertValue::Integer(source.as_i16(channel)? as i128),
+        ConvertKind::Integer { bits: 16, .. } => ConvertValue::Integer(source.as_u16(channel)? as i128),
+        ConvertKind::Integer { bits: 32, signed: true } => ConvertValue::Integer(source.as_i32(channel)? as i128),
+        ConvertKind::Integer { bits: 32, .. } => ConvertValue::Integer(source.as_u32(channel)? as i128),
+        ConvertKind::Integer { signed: true, .. } => ConvertValue::Integer(source.as_i64(channel)? as i128),
+        ConvertKind::Integer { .. } => ConvertValue::Integer(source.as_u64(channel)? as i128),
+        ConvertKind::Binary { bits: 8 } => ConvertValue::Binary(source.as_u8(channel)? as u64),
+        ConvertKind::Binary { bits: 16 } => ConvertValue::Binary(source.as_u16(channel)? as u64),
+        ConvertKind::Binary { bits: 32 } => ConvertValue::Binary(source.as_u32(channel)? as u64),
+        ConvertKind::Binary { .. } => ConvertValue::Binary(source.as_u64(channel)?),
+        ConvertKind::Float { bits: 32 } => ConvertValue::Float(source.as_f32(channel)? as f64),
+        ConvertKind::Float { .. } => ConvertValue::Float(source.as_f64(channel)?),
+        ConvertKind::Time { bits: 32, .. } => ConvertValue::Time(source.as_i32(channel)? as i128),
+        ConvertKind::Time { .. } => ConvertValue::Time(source.as_i64(channel)? as i128),
+        ConvertKind::S5Time => ConvertValue::Time(source.as_u32(channel)? as i128),
+    })
+}
+
+/// Writes a value of the given kind, see [`convert_value`].
+pub fn write_value(target: &mut LocalType, kind: ConvertKind, value: ConvertValue, channel: &Broadcast) -> Result<(), Stop> {
+    match (kind, value) {
+        (ConvertKind::Bool, ConvertValue::Bool(a)) => target.set_bool(a, channel),
+        (ConvertKind::Integer { bits: 8, signed: true }, ConvertValue::Integer(a)) => target.set_i8(a as i8, channel),
+        (ConvertKind::Integer { bits: 8, .. }, ConvertValue::Integer(a)) => target.set_u8(a as u8, channel),
+        (ConvertKind::Integer { bits: 16, signed: true }, ConvertValue::Integer(a)) => target.set_i16(a as i16, channel),
+        (ConvertKind::Integer { bits: 16, .. }, ConvertValue::Integer(a)) => target.set_u16(a as u16, channel),
+        (ConvertKind::Integer { bits: 32, signed: true }, ConvertValue::Integer(a)) => target.set_i32(a as i32, channel),
+        (ConvertKind::Integer { bits: 32, .. }, ConvertValue::Integer(a)) => target.set_u32(a as u32, channel),
+        (ConvertKind::Integer { signed: true, .. }, ConvertValue::Integer(a)) => target.set_i64(a as i64, channel),
+        (ConvertKind::Integer { .. }, ConvertValue::Integer(a)) => target.set_u64(a as u64, channel),
+        (ConvertKind::Binary { bits: 8 }, ConvertValue::Binary(a)) => target.set_u8(a as u8, channel),
+        (ConvertKind::Binary { bits: 16 }, ConvertValue::Binary(a)) => target.set_u16(a as u16, channel),
+        (ConvertKind::Binary { bits: 32 }, ConvertValue::Binary(a)) => target.set_u32(a as u32, channel),
+        (ConvertKind::Binary { .. }, ConvertValue::Binary(a)) => target.set_u64(a, channel),
+        (ConvertKind::Float { bits: 32 }, ConvertValue::Float(a)) => target.set_f32(a as f32, channel),
+        (ConvertKind::Float { .. }, ConvertValue::Float(a)) => target.set_f64(a, channel),
+        (ConvertKind::Time { bits: 32, .. }, ConvertValue::Time(a)) => target.set_i32(a as i32, channel),
+        (ConvertKind::Time { .. }, ConvertValue::Time(a)) => target.set_i64(a as i64, channel),
+        (ConvertKind::S5Time, ConvertValue::Time(a)) => target.set_u32(a as u32, channel),
+        _ => Err(error!(format!("Converted value {:?} does not match {:?}", value, kind))),
+    }
+}
+
+/// Converts `source` into a new primitive of the type of `target`.
+///
+/// A conversion which does not keep the value (overflow, NaN, a bit string wider than the
+/// target ...) stops the simulation with [`Overflow::Error`], keeps the lowest bits with
+/// [`Overflow::Wrap`] and clamps the value to the target with [`Overflow::Saturate`], both are
+/// recorded in `overflows`.
+pub fn box_convert_plc_primitive<T: 'static + MetaData + Primitive + IsFamily + Clone + Display>(
+    source: &T,
+    target: LocalType,
+    rounding: Rounding,
+    overflow: Overflow,
+    overflows: OverflowReport,
+    trace: u32,
+) -> Result<RunTimeOperation, Stop> {
+    let from = ConvertKind::of(source)
+        .ok_or_else(|| error!(format!("Invalid conversion: Can not convert {}", source)))?;
+    let to = ConvertKind::of(&target)
+        .ok_or_else(|| error!(format!("Invalid conversion: Can not convert to {}", target)))?;
+    if !from.can_convert_to(&to) {
+        return Err(error!(format!("Invalid conversion: Can not convert {} to {}", source, target)));
+    }
+
+    let return_ptr = LocalPointer::new(target.transform()?);
+    let return_ptr_clone = return_ptr.clone();
+    let source_clone = source.clone();
+    let source_clone_1 = source.clone();
+    let target_clone = target.clone();
+
+    Ok(Box::new(Operation::new(
+        MaybeHeapOrStatic(Some(HeapOrStatic::Closure(Rc::new(RefCell::new(move || format!("Convert {} to {}", source_clone_1, target_clone)))))),
+        move |channel| {
+            let source_value = read_value(&source_clone, from, channel)?;
+            let (value, flagged) = fit_value(source_value, from, to, rounding, overflow)
+                .ok_or_else(|| error!(format!("Lossy conversion of {} to {}", source_clone, target)))?;
+            if flagged {
+                overflows.record(trace);
+            }
+            write_value(return_ptr.as_ref().borrow_mut().deref_mut(), to, value, channel)
+        }, Some(return_ptr_clone), false, trace)))
+}
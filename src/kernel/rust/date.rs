@@ -0,0 +1,202 @@
+use crate::container::broadcast::broadcast::Broadcast;
+use crate::container::error::error::Stop;
+use crate::container::simulation::clock::SimClock;
+use crate::error;
+use crate::kernel::arch::any::any_type::AnyRefType;
+use crate::kernel::arch::local::pointer::LocalPointer;
+use crate::kernel::arch::local::r#type::{IntoLocalType, LocalType};
+use crate::kernel::plc::operations::operations::{Operation, RunTimeOperation};
+use crate::kernel::plc::types::primitives::date::plc_date::DateScale;
+use crate::kernel::plc::types::primitives::integers::int::Int;
+use crate::kernel::plc::types::primitives::integers::plc_integer::PlcInteger;
+use crate::kernel::plc::types::primitives::timers::lTime::LTime;
+use crate::kernel::plc::types::primitives::timers::plc_time::PlcTime;
+use crate::kernel::plc::types::primitives::timers::time::Time;
+use crate::kernel::plc::types::primitives::traits::family_traits::{IsFamily, WithTypeFamily};
+use crate::kernel::plc::types::primitives::traits::meta_data::{HeapOrStatic, MaybeHeapOrStatic};
+use crate::kernel::plc::types::primitives::traits::primitive_traits::{AsMutPrimitive, Primitive, PrimitiveTrait};
+use crate::kernel::registry::Kernel;
+use crate::kernel::rust::partial::BoxOrd;
+use core::cell::RefCell;
+use core::ops::DerefMut;
+use std::rc::Rc;
+
+/// Run time part of a date and time operation.
+type DateClosure = Box<dyn Fn(&Broadcast) -> Result<(), Stop>>;
+
+/// Reads a DATE, DT, LDT or DTL in ns since 1970-01-01.
+fn read_point<T: Primitive>(value: &T, scale: DateScale, channel: &Broadcast) -> Result<i128, Stop> {
+    let native = if value.is_u16() {
+        value.as_u16(channel)? as i128
+    } else if value.is_u64() {
+        value.as_u64(channel)? as i128
+    } else {
+        value.as_i64(channel)? as i128
+    };
+    Ok(scale.to_ns(native))
+}
+
+fn write_point(target: &mut LocalType, scale: DateScale, ns: i128, channel: &Broadcast) -> Result<(), Stop> {
+    let native = scale
+        .from_ns(ns)
+        .ok_or_else(|| error!(format!("Invalid operation: {} ns since 1970-01-01 is out of the range of {}", ns, target)))?;
+    if target.is_u16() {
+        target.set_u16(native as u16, channel)
+    } else if target.is_u64() {
+        target.set_u64(native as u64, channel)
+    } else {
+        target.set_i64(native as i64, channel)
+    }
+}
+
+/// ns per unit of a TIME, LTIME or S5TIME.
+fn duration_unit<T: IsFamily + Primitive>(value: &T) -> Option<i128> {
+    match value.is_plc_time() {
+        true if value.is_i32() || value.is_u32() => Some(1_000_000),
+        true if value.is_i64() => Some(1),
+        _ => None,
+    }
+}
+
+fn read_duration<T: Primitive>(value: &T, unit: i128, channel: &Broadcast) -> Result<i128, Stop> {
+    let native = if value.is_i32() {
+        value.as_i32(channel)? as i128
+    } else if value.is_u32() {
+        value.as_u32(channel)? as i128
+    } else {
+        value.as_i64(channel)? as i128
+    };
+    Ok(native * unit)
+}
+
+fn write_duration(target: &mut LocalType, unit: i128, ns: i128, channel: &Broadcast) -> Result<(), Stop> {
+    let out_of_range = || error!(format!("Invalid operation: {} ns is out of the range of {}", ns, target));
+    if target.is_i32() {
+        target.set_i32(i32::try_from(ns / unit).map_err(|_| out_of_range())?, channel)
+    } else if target.is_u32() {
+        target.set_u32(u32::try_from(ns / unit).map_err(|_| out_of_range())?, channel)
+    } else {
+        target.set_i64(i64::try_from(ns / unit).map_err(|_| out_of_range())?, channel)
+    }
+}
+
+fn date_scale(value: &AnyRefType) -> Result<DateScale, Stop> {
+    value.with_type_plc_date(|a| a.scale())
+}
+
+/// Builds `+` and `-` where one of the operands is a DATE, DT, LDT or DTL.
+///
+/// A date and time plus or minus a duration has the type of the date and time, the difference of
+/// two dates and times is a TIME, or an LTIME as soon as one of them is an LDT or a DTL. The Kernel
+/// may override both return types.
+pub fn box_date_calc(o1: &AnyRefType, o2: &AnyRefType, operator: &str, trace: u32, kernel: &Kernel) -> Result<RunTimeOperation, Stop> {
+    let (name, sign) = match operator {
+        "+" => ("add", 1),
+        "-" => ("sub", -1),
+        _ => return Err(error!(format!("Invalid operation: Can not {} {} with {}", operator, o1, o2))),
+    };
+    kernel.check_filtered_operation(name, o1, o2)?;
+
+    let scale1 = date_scale(o1).map_err(|_| error!(format!("Invalid operation: Can not {} {} with {}", name, o1, o2)))?;
+    let duration = duration_unit(o2);
+    let scale2 = date_scale(o2).ok();
+
+    let return_type = match kernel.check_return_operation(name, o1, o2) {
+        Some(a) => a.transform()?,
+        None => match (duration, scale2) {
+            (Some(_), _) => o1.transform()?,
+            (None, Some(_)) if sign < 0 => {
+                match o1.with_type_plc_date(|a| a.is_date() || a.is_dt())? && o2.with_type_plc_date(|a| a.is_date() || a.is_dt())? {
+                    true => LocalType::PlcTime(PlcTime::Time(Time::new_default(0))),
+                    false => LocalType::PlcTime(PlcTime::LTime(LTime::new_default(0))),
+                }
+            }
+            _ => return Err(error!(format!("Invalid operation: Can not {} {} with {}", name, o1, o2))),
+        },
+    };
+
+    let return_ptr = LocalPointer::new(return_type);
+    let return_ptr_clone = return_ptr.clone();
+    let (o1_clone, o2_clone) = (o1.clone(), o2.clone());
+    let (o1_display, o2_display, operator) = (o1.clone(), o2.clone(), operator.to_string());
+
+    let closure: DateClosure = match (duration, scale2) {
+        // Date and time +/- duration
+        (Some(unit), _) => {
+            let scale = return_ptr
+                .with_type_plc_date(|a| a.scale())
+                .map_err(|_| error!(format!("Invalid operation: {} of {} with {} can not return {}", name, o1, o2, return_ptr)))?;
+            Box::new(move |channel| {
+                let ns = read_point(&o1_clone, scale1, channel)? + sign * read_duration(&o2_clone, unit, channel)?;
+                write_point(return_ptr.as_ref().borrow_mut().deref_mut(), scale, ns, channel)
+            })
+        }
+        // Date and time - date and time
+        (None, Some(scale2)) => {
+            let unit = duration_unit(&return_ptr)
+                .ok_or_else(|| error!(format!("Invalid operation: {} of {} with {} can not return {}", name, o1, o2, return_ptr)))?;
+            Box::new(move |channel| {
+                let ns = read_point(&o1_clone, scale1, channel)? - read_point(&o2_clone, scale2, channel)?;
+                write_duration(return_ptr.as_ref().borrow_mut().deref_mut(), unit, ns, channel)
+            })
+        }
+        (None, None) => return Err(error!(format!("Invalid operation: Can not {} {} with {}", name, o1, o2))),
+    };
+
+    Ok(Box::new(Operation::new(
+        MaybeHeapOrStatic(Some(HeapOrStatic::Closure(Rc::new(RefCell::new(move || format!("{} {} {}", o1_display, operator, o2_display)))))),
+        closure, Some(return_ptr_clone), false, trace)))
+}
+
+/// Compares two dates and times, whatever their type.
+pub fn box_ord_plc_date(o1: &AnyRefType, o2: &AnyRefType, trace: u32, kernel: &Kernel) -> Result<BoxOrd, Stop> {
+    kernel.check_filtered_operation("cmp", o1, o2)?;
+    let (scale1, scale2) = match (date_scale(o1), date_scale(o2)) {
+        (Ok(a), Ok(b)) => (a, b),
+        _ => return Err(error!(format!("Invalid operation: Can not compare {} with {}", o1, o2)).add_id(trace)),
+    };
+    let (o1, o2) = (o1.clone(), o2.clone());
+
+    Ok(Box::new(move |channel| {
+        Ok(Some(read_point(&o1, scale1, channel)?.cmp(&read_point(&o2, scale2, channel)?)))
+    }))
+}
+
+/// Builds RD_SYS_T, or RD_LOC_T when `local`, returning the simulated clock as a DATE, DT, LDT or DTL.
+pub fn box_read_clock(name: &'static str, to: LocalType, local: bool, clock: SimClock, trace: u32) -> Result<RunTimeOperation, Stop> {
+    let scale = to
+        .with_type_plc_date(|a| a.scale())
+        .map_err(|_| error!(format!("Invalid operation: {} can not return {}", name, to)).add_id(trace))?;
+
+    let return_ptr = LocalPointer::new(to);
+    let return_ptr_clone = return_ptr.clone();
+
+    Ok(Box::new(Operation::new(
+        MaybeHeapOrStatic(Some(HeapOrStatic::Closure(Rc::new(RefCell::new(move || name.to_string()))))),
+        move |channel| {
+            let ns = match local {
+                true => clock.local_time(),
+                false => clock.system_time(),
+            };
+            write_point(return_ptr.as_ref().borrow_mut().deref_mut(), scale, ns, channel)
+        }, Some(return_ptr_clone), false, trace)))
+}
+
+/// Builds WR_SYS_T, the system time moves to the given date and time and keeps running from there.
+///
+/// Returns 0 like the RET_VAL of the PLC.
+pub fn box_write_clock(input: &AnyRefType, clock: SimClock, trace: u32) -> Result<RunTimeOperation, Stop> {
+    let scale = date_scale(input)
+        .map_err(|_| error!(format!("Invalid operation: wr_sys_t expects a date and time, got {}", input)).add_id(trace))?;
+
+    let return_ptr = LocalPointer::new(LocalType::PlcInteger(PlcInteger::Int(Int::new_default(0))));
+    let return_ptr_clone = return_ptr.clone();
+    let (input, input_display) = (input.clone(), input.clone());
+
+    Ok(Box::new(Operation::new(
+        MaybeHeapOrStatic(Some(HeapOrStatic::Closure(Rc::new(RefCell::new(move || format!("wr_sys_t {}", input_display)))))),
+        move |channel| {
+            clock.set_system_time(read_point(&input, scale, channel)?);
+            return_ptr.as_ref().borrow_mut().deref_mut().set_i16(0, channel)
+        }, Some(return_ptr_clone), false, trace)))
+}
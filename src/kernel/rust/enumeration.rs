@@ -0,0 +1,49 @@
+use crate::container::broadcast::broadcast::Broadcast;
+use crate::container::error::error::Stop;
+use crate::error;
+use crate::kernel::arch::any::any_type::AnyRefType;
+use crate::kernel::arch::local::pointer::LocalPointer;
+use crate::kernel::plc::operations::operations::{Operation, RunTimeOperation};
+use crate::kernel::plc::types::enumeration::PlcEnum;
+use crate::kernel::plc::types::primitives::traits::meta_data::{HeapOrStatic, MaybeHeapOrStatic, MetaData};
+use crate::kernel::registry::Kernel;
+use crate::kernel::rust::convert::{read_value, write_value, ConvertKind, ConvertValue};
+use core::cell::RefCell;
+use core::ops::DerefMut;
+use std::rc::Rc;
+
+/// Values of two different enumerations can not be mixed.
+pub fn check_enum_operands<T: MetaData, Y: MetaData>(o1: &T, o2: &Y, operation: &str, kernel: &Kernel) -> Result<(), Stop> {
+    match (kernel.get_enum_of(o1), kernel.get_enum_of(o2)) {
+        (Some(a), Some(b)) if a.get_name() != b.get_name() => Err(error!(format!(
+            "Invalid {}: Can not mix {} of {} with {} of {}", operation, o1.get_path(), a.get_name(), o2.get_path(), b.get_name()
+        ))),
+        _ => Ok(()),
+    }
+}
+
+/// Builds the assignment of an enumerated variable, the simulation stops when the value is not
+/// one of the enumeration.
+pub fn box_set_plc_enum(variable: &LocalPointer, value: &AnyRefType, plc_enum: Rc<PlcEnum>, trace: u32, kernel: &Kernel) -> Result<RunTimeOperation, Stop> {
+    kernel.check_filtered_operation("assign", variable, value)?;
+    check_enum_operands(variable, value, "assignment", kernel)?;
+
+    let (to, from) = match (ConvertKind::of(variable), ConvertKind::of(value)) {
+        (Some(to @ ConvertKind::Integer { .. }), Some(from @ ConvertKind::Integer { .. })) => (to, from),
+        _ => return Err(error!(format!("Invalid assignment: Can not set {} with {}", variable, value))),
+    };
+
+    let (variable, value) = (variable.clone(), value.clone());
+    let (variable_display, value_display) = (variable.clone(), value.clone());
+
+    Ok(Box::new(Operation::new(
+        MaybeHeapOrStatic(Some(HeapOrStatic::Closure(Rc::new(RefCell::new(move || format!("{} := {}", variable_display, value_display)))))),
+        move |channel: &Broadcast| {
+            let result = read_value(&value, from, channel)?;
+            match result {
+                ConvertValue::Integer(a) if plc_enum.contains(a) => write_value(variable.as_ref().borrow_mut().deref_mut(), to, result, channel),
+                ConvertValue::Integer(a) => Err(error!(format!("Invalid assignment: {} is not a value of {}", a, plc_enum.get_name()))),
+                other => Err(error!(format!("Invalid assignment: Expected an integer, got {:?}", other))),
+            }
+        }, None, false, trace)))
+}
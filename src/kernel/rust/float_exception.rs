@@ -0,0 +1,172 @@
+use crate::container::broadcast::broadcast::Broadcast;
+use crate::container::error::error::Stop;
+use crate::error;
+use core::cell::RefCell;
+use serde::Serialize;
+use std::rc::Rc;
+
+/// What a float operation does when its result is NaN, infinite or subnormal.
+///
+/// Selected in the provider with `"float_exceptions": "stop" | "propagate" | "report"`.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub enum FloatPolicy {
+    /// Stops the simulation with the trace of the operation
+    #[default]
+    Stop,
+    /// Keeps the IEEE-754 result like a CPU does
+    Propagate,
+    /// Keeps the result and reports the operation where it appeared in the cycle
+    Report,
+}
+
+impl TryFrom<&str> for FloatPolicy {
+    type Error = Stop;
+
+    fn try_from(value: &str) -> Result<Self, Stop> {
+        match value {
+            "stop" => Ok(FloatPolicy::Stop),
+            "propagate" => Ok(FloatPolicy::Propagate),
+            "report" => Ok(FloatPolicy::Report),
+            _ => Err(error!(format!("Invalid float exceptions {}, expected stop, propagate or report", value))),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Debug, Serialize)]
+pub enum FloatSpecial {
+    NaN,
+    Infinity,
+    Subnormal,
+}
+
+/// A special value and the id of the operation which produced it first in a cycle.
+#[derive(Clone, Copy, PartialEq, Debug, Serialize)]
+pub struct FloatException {
+    pub value: FloatSpecial,
+    pub id: u32,
+}
+
+pub trait Ieee754: Sized {
+    /// `self op rhs` without checking the result, None for integers. `op` is the name of the
+    /// operation, `rhs` is ignored by the operations on self like `sqrt`.
+    fn ieee(self, op: &str, rhs: f64) -> Option<Self>;
+
+    fn special(&self) -> Option<FloatSpecial>;
+}
+
+macro_rules! impl_ieee_float {
+    ($($native: ident),+) => {
+        $(
+            impl Ieee754 for $native {
+                fn ieee(self, op: &str, rhs: f64) -> Option<Self> {
+                    let rhs = rhs as $native;
+                    match op {
+                        "add" => Some(self + rhs),
+                        "sub" => Some(self - rhs),
+                        "mul" => Some(self * rhs),
+                        "div" => Some(self / rhs),
+                        "rem" => Some(self % rhs),
+                        "cos" => Some(self.cos()),
+                        "sin" => Some(self.sin()),
+                        "tan" => Some(self.tan()),
+                        "acos" => Some(self.acos()),
+                        "asin" => Some(self.asin()),
+                        "atan" => Some(self.atan()),
+                        "exp" => Some(self.exp()),
+                        "ln" => Some(self.ln()),
+                        "fract" => Some(self.fract()),
+                        "trunc" => Some(self.trunc()),
+                        "floor" => Some(self.floor()),
+                        "ceil" => Some(self.ceil()),
+                        "sqrt" => Some(self.sqrt()),
+                        "sqr" => Some(self * self),
+                        _ => None,
+                    }
+                }
+
+                fn special(&self) -> Option<FloatSpecial> {
+                    if self.is_nan() {
+                        Some(FloatSpecial::NaN)
+                    } else if self.is_infinite() {
+                        Some(FloatSpecial::Infinity)
+                    } else if self.is_subnormal() {
+                        Some(FloatSpecial::Subnormal)
+                    } else {
+                        None
+                    }
+                }
+            }
+        )+
+    };
+}
+
+macro_rules! impl_ieee_integer {
+    ($($native: ident),+) => {
+        $(
+            impl Ieee754 for $native {
+                fn ieee(self, _op: &str, _rhs: f64) -> Option<Self> {
+                    None
+                }
+
+                fn special(&self) -> Option<FloatSpecial> {
+                    None
+                }
+            }
+        )+
+    };
+}
+
+impl_ieee_float!(f32, f64);
+impl_ieee_integer!(u8, u16, u32, u64, i8, i16, i32, i64);
+
+/// Float exceptions policy of the kernel with the report of the current and the last cycle.
+#[derive(Clone, Default)]
+pub struct FloatExceptions {
+    policy: FloatPolicy,
+    current: Rc<RefCell<Vec<FloatException>>>,
+    last: Rc<RefCell<Vec<FloatException>>>,
+}
+
+impl FloatExceptions {
+    pub fn set_policy(&mut self, policy: FloatPolicy) {
+        self.policy = policy;
+    }
+
+    /// Keeps the special result of the operation `id` unless the policy stops on it. With a
+    /// report, the result is recorded when `created` by the operation from regular operands.
+    pub fn propagate<T: Ieee754>(&self, result: Option<T>, created: bool, id: u32) -> Option<T> {
+        let result = result?;
+        let value = result.special()?;
+        match self.policy {
+            FloatPolicy::Stop => return None,
+            FloatPolicy::Propagate => {}
+            FloatPolicy::Report => {
+                let mut current = self.current.borrow_mut();
+                if created && !current.iter().any(|a| a.value == value) {
+                    current.push(FloatException { value, id });
+                }
+            }
+        }
+        Some(result)
+    }
+
+    /// Publishes where the special values of the cycle appeared first and starts a new report.
+    pub fn end_cycle(&self, channel: &Broadcast) {
+        let current = core::mem::take(&mut *self.current.borrow_mut());
+        current.iter().for_each(|a| {
+            channel.add_warning(&format!("[Float] Cycle {}: {:?} first appeared in operation {}", channel.get_cycle(), a.value, a.id));
+        });
+        *self.last.borrow_mut() = current;
+    }
+
+    /// Special values reported by the last cycle.
+    pub fn get_last_report(&self) -> Vec<FloatException> {
+        self.last.borrow().clone()
+    }
+
+    pub fn clear(&mut self) {
+        self.policy = FloatPolicy::default();
+        self.current.borrow_mut().clear();
+        self.last.borrow_mut().clear();
+    }
+}
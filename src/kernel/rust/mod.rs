@@ -2,3 +2,13 @@ pub mod auto_set;
 pub mod set;
 pub mod partial;
 pub mod operations;
+pub mod convert;
+pub mod string;
+pub mod select;
+pub mod scale;
+pub mod date;
+pub mod enumeration;
+pub mod bitwise;
+pub mod timer;
+pub mod overflow;
+pub mod float_exception;
@@ -11,9 +11,15 @@ use core::ops::{DerefMut};
 use crate::kernel::registry::Kernel;
 use crate::kernel::plc::types::primitives::traits::meta_data::{MetaData, MaybeHeapOrStatic, HeapOrStatic};
 use crate::kernel::plc::types::primitives::floats::checked_float::CheckedFloat;
+use crate::kernel::rust::overflow::{exact, Overflowing};
+use crate::kernel::rust::convert::{fit_value, write_value, ConvertKind, ConvertValue, Rounding};
+use crate::kernel::rust::float_exception::Ieee754;
 use std::rc::Rc;
 use core::cell::RefCell;
 
+/// Kind of the exact result of an integer operation
+const EXACT: ConvertKind = ConvertKind::Integer { bits: 128, signed: true };
+
 macro_rules! box_create_checked_operation_primitive {
     ($op_fn: ident, $operator: literal,
      $({
@@ -29,9 +35,20 @@ macro_rules! box_create_checked_operation_primitive {
                     if variable1.[<is_$primitive>]() {
                         $(
                            if variable2.[<is_$associated>]() {
-                               let return_ptr = match kernel.check_return_operation(&stringify!($op_fn), variable1, variable2) {
-                                   Some(a) => LocalPointer::new(a.transform()?),
-                                   None => LocalPointer::new(variable1.transform()?)
+                               // The overflow follows the type of the result, an integer result of another
+                               // width is computed exactly then fit to its type
+                               let (return_ptr, overflow, widened) = match kernel.check_return_operation(&stringify!($op_fn), variable1, variable2) {
+                                   Some(a) => {
+                                       let return_type = a.transform()?;
+                                       let widened = match (ConvertKind::of(&variable1.transform()?), ConvertKind::of(&return_type)) {
+                                           (Some(from @ ConvertKind::Integer { .. }), Some(to @ ConvertKind::Integer { .. })) if from != to => Some(to),
+                                           _ => None,
+                                       };
+                                       let return_ptr = LocalPointer::new(return_type);
+                                       let overflow = kernel.get_overflow(&return_ptr);
+                                       (return_ptr, overflow, widened)
+                                   }
+                                   None => (LocalPointer::new(variable1.transform()?), kernel.get_overflow(variable1), None)
                                };
                                let return_ptr_clone = return_ptr.clone();
                                let o1_clone = variable1.clone();
@@ -40,18 +57,43 @@ macro_rules! box_create_checked_operation_primitive {
                                let o1_clone_1 = variable1.clone();
                                let o2_clone_1 = variable2.clone();
 
-                               let o1_clone_2 = variable1.clone();
-                               let o2_clone_2 = variable2.clone();
+                               let floats = kernel.floats.clone();
+                               let overflows = kernel.overflows.clone();
 
                                return Ok(Box::new(Operation::new(
                                    MaybeHeapOrStatic(Some(HeapOrStatic::Closure(Rc::new(RefCell::new(move || format!("{} {} {}", o1_clone_1, $operator, o2_clone_1)))))),
                                    move |channel| {
-                                    let result = (o1_clone.[<as_$primitive>](channel)?)
-                                        .[<checked_$op_fn $(_$signed)?>](o2_clone.[<as_$associated>](channel)?
-                                            .try_into()
-                                            .map_err(|_| error!(format!("Failed {} of {} with {}", stringify!($op_fn), o1_clone, o2_clone)))?
-                                    )
-                                    .ok_or_else(|| error!(format!("Invalid operation: Can not {} {} with {}", stringify!($op_fn), o1_clone_2, o2_clone_2)))?;
+                                    let o1 = o1_clone.[<as_$primitive>](channel)?;
+                                    let o2 = o2_clone.[<as_$associated>](channel)?;
+                                    if let Some(to) = widened {
+                                        let (value, flagged) = exact(o1 as i128, stringify!($op_fn), o2 as i128)
+                                            .and_then(|a| fit_value(ConvertValue::Integer(a), EXACT, to, Rounding::Trunc, overflow))
+                                            .ok_or_else(|| error!(format!("Invalid operation: Can not {} {} with {}", stringify!($op_fn), o1_clone, o2_clone)))?;
+                                        if flagged {
+                                            overflows.record(trace);
+                                        }
+                                        return write_value(return_ptr.as_ref().borrow_mut().deref_mut(), to, value, channel);
+                                    }
+
+                                    let result = match o2.try_into() {
+                                        Ok(a) => o1.[<checked_$op_fn $(_$signed)?>](a),
+                                        Err(_) => None
+                                    };
+
+                                    // Integers overflow, floats may propagate their special values
+                                    let result = match result {
+                                        Some(a) => a,
+                                        None => o1
+                                            .overflowing_flagged(stringify!($op_fn), o2 as i128, overflow)
+                                            .map(|(a, flagged)| {
+                                                if flagged {
+                                                    overflows.record(trace);
+                                                }
+                                                a
+                                            })
+                                            .or_else(|| floats.propagate(o1.ieee(stringify!($op_fn), o2 as f64), o1.special().is_none() && o2.special().is_none(), trace))
+                                            .ok_or_else(|| error!(format!("Invalid operation: Can not {} {} with {}", stringify!($op_fn), o1_clone, o2_clone)))?
+                                    };
 
                                     return_ptr.as_ref().borrow_mut().deref_mut().[<set_$primitive>](result, channel)?;
                                     Ok(())
@@ -82,11 +124,14 @@ macro_rules! box_create_checked_operation_primitive_on_self {
 
                         let o1_clone_1 = variable1.clone();
                         let o1_clone_2 = variable1.clone();
+                        let floats = kernel.floats.clone();
 
                         return Ok(Box::new(Operation::new(
                             MaybeHeapOrStatic(Some(HeapOrStatic::Closure(Rc::new(RefCell::new(move || format!("{} {}", stringify!([<$op_fn:camel >]), o1_clone_1)))))),
                             move |channel| {
-                            let result = o1_clone.[<as_$primitive>](channel)?.[<checked_$op_fn>]()
+                            let o1 = o1_clone.[<as_$primitive>](channel)?;
+                            let result = o1.[<checked_$op_fn>]()
+                            .or_else(|| floats.propagate(o1.ieee(stringify!($op_fn), 0.0), o1.special().is_none(), trace))
                             .ok_or_else(|| error!(format!("Invalid operation: Can not {} {}", stringify!($op_fn), o1_clone_2)))?;
 
                             return_ptr.as_ref().borrow_mut().deref_mut().[<set_$primitive>](result, channel)?;
@@ -0,0 +1,134 @@
+use crate::container::error::error::Stop;
+use crate::error;
+use core::cell::RefCell;
+use std::rc::Rc;
+
+/// What an integer operation does when its result does not fit its type.
+///
+/// Selected per type in the provider, e.g. `"overflow": { "AnySignedInteger": "wrap", "Word": "saturate" }`.
+/// The type is the one of the result of the operation. Without an entry the simulation stops with
+/// the trace of the operation.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub enum Overflow {
+    /// Keeps the lowest bits of the result, like S7 does
+    Wrap,
+    /// Clamps the result to the range of the type
+    Saturate,
+    #[default]
+    Error,
+}
+
+impl TryFrom<&str> for Overflow {
+    type Error = Stop;
+
+    fn try_from(value: &str) -> Result<Self, Stop> {
+        match value {
+            "wrap" => Ok(Overflow::Wrap),
+            "saturate" => Ok(Overflow::Saturate),
+            "error" => Ok(Overflow::Error),
+            _ => Err(error!(format!("Invalid overflow {}, expected wrap, saturate or error", value))),
+        }
+    }
+}
+
+pub trait Overflowing: Sized {
+    /// Result of `self op rhs` following the overflow policy, `op` is `add`, `sub`, `mul`, `div`
+    /// or `rem`. None when the result overflows with [`Overflow::Error`] or does not exist
+    /// (division by zero, floats).
+    fn overflowing(self, op: &str, rhs: i128, overflow: Overflow) -> Option<Self>;
+
+    /// Like [`Overflowing::overflowing`], with true when the result was wrapped or saturated.
+    fn overflowing_flagged(self, op: &str, rhs: i128, overflow: Overflow) -> Option<(Self, bool)>
+    where
+        Self: Copy,
+    {
+        match self.overflowing(op, rhs, Overflow::Error) {
+            Some(a) => Some((a, false)),
+            None => self.overflowing(op, rhs, overflow).map(|a| (a, true)),
+        }
+    }
+}
+
+/// Exact result of `lhs op rhs`, None when it leaves the i128 range or does not exist.
+pub fn exact(lhs: i128, op: &str, rhs: i128) -> Option<i128> {
+    match op {
+        "add" => lhs.checked_add(rhs),
+        "sub" => lhs.checked_sub(rhs),
+        "mul" => lhs.checked_mul(rhs),
+        "div" => lhs.checked_div(rhs),
+        "rem" => lhs.checked_rem(rhs),
+        _ => None,
+    }
+}
+
+macro_rules! impl_overflowing_integer {
+    ($($native: ident),+) => {
+        $(
+            impl Overflowing for $native {
+                fn overflowing(self, op: &str, rhs: i128, overflow: Overflow) -> Option<Self> {
+                    let lhs = self as i128;
+                    if !matches!(op, "add" | "sub" | "mul" | "div" | "rem") {
+                        return None;
+                    }
+                    match (exact(lhs, op, rhs), overflow) {
+                        (Some(a), _) if a >= $native::MIN as i128 && a <= $native::MAX as i128 => Some(a as $native),
+                        (_, Overflow::Error) => None,
+                        (Some(a), Overflow::Wrap) => Some(a as $native),
+                        (Some(a), Overflow::Saturate) => Some(a.clamp($native::MIN as i128, $native::MAX as i128) as $native),
+                        // Only a product of 64 bits operands can leave the i128 range
+                        (None, Overflow::Wrap) if op == "mul" => Some(lhs.wrapping_mul(rhs) as $native),
+                        (None, Overflow::Saturate) if op == "mul" => Some(if (lhs < 0) != (rhs < 0) { $native::MIN } else { $native::MAX }),
+                        (None, _) => None,
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_overflowing_integer!(u8, u16, u32, u64, i8, i16, i32, i64);
+
+impl Overflowing for f32 {
+    fn overflowing(self, _op: &str, _rhs: i128, _overflow: Overflow) -> Option<Self> {
+        None
+    }
+}
+
+impl Overflowing for f64 {
+    fn overflowing(self, _op: &str, _rhs: i128, _overflow: Overflow) -> Option<Self> {
+        None
+    }
+}
+
+/// Integer operations whose result was wrapped or saturated in the current and the last cycle,
+/// like the OV status bit S7 sets on an overflow.
+#[derive(Clone, Default)]
+pub struct OverflowReport {
+    current: Rc<RefCell<Vec<u32>>>,
+    last: Rc<RefCell<Vec<u32>>>,
+}
+
+impl OverflowReport {
+    /// Records the operation `id`, once per cycle.
+    pub fn record(&self, id: u32) {
+        let mut current = self.current.borrow_mut();
+        if !current.contains(&id) {
+            current.push(id);
+        }
+    }
+
+    /// Publishes the operations which overflowed in the cycle and starts a new report.
+    pub fn end_cycle(&self) {
+        *self.last.borrow_mut() = core::mem::take(&mut *self.current.borrow_mut());
+    }
+
+    /// Operations which overflowed in the last cycle, in the order they first overflowed.
+    pub fn get_last_report(&self) -> Vec<u32> {
+        self.last.borrow().clone()
+    }
+
+    pub fn clear(&mut self) {
+        self.current.borrow_mut().clear();
+        self.last.borrow_mut().clear();
+    }
+}
@@ -9,6 +9,9 @@ use crate::kernel::plc::types::primitives::traits::primitive_traits::{AsMutPrimi
 use crate::kernel::registry::Kernel;
 use crate::kernel::plc::types::primitives::traits::meta_data::MetaData;
 
+/// Compares two operands at run time, None when they are unordered.
+pub type BoxOrd = Box<dyn Fn(&Broadcast) -> Result<Option<Ordering>, Stop>>;
+
 pub fn ord<T: Sized + PartialOrd + Clone>(o1: T, o2: T) -> Option<Ordering> {
     o1.partial_cmp(&o2)
 }
@@ -20,7 +23,7 @@ macro_rules! box_ord_primitive {
     }),+
     ) => {
         pub fn box_ord_plc_primitive<T: 'static + MetaData + Primitive + Clone + Display, 
-        Y : 'static + MetaData + Primitive + Clone + Display>(variable1: &T, variable2: &Y, trace: u32, kernel: &Kernel) -> Result<Box<dyn Fn(&Broadcast) -> Result<Option<Ordering>, Stop>>, Stop>{
+        Y : 'static + MetaData + Primitive + Clone + Display>(variable1: &T, variable2: &Y, trace: u32, kernel: &Kernel) -> Result<BoxOrd, Stop>{
             paste! {
                 kernel.check_filtered_operation(&"cmp", variable1, variable2)?;
                 $(
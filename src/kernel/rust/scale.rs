@@ -0,0 +1,133 @@
+use crate::container::broadcast::broadcast::Broadcast;
+use crate::container::error::error::Stop;
+use crate::error;
+use crate::kernel::arch::any::any_type::AnyRefType;
+use crate::kernel::arch::local::pointer::LocalPointer;
+use crate::kernel::arch::local::r#type::{IntoLocalType, LocalType};
+use crate::kernel::plc::operations::operations::{Operation, RunTimeOperation};
+use crate::kernel::plc::types::primitives::floats::plc_float::PlcFloat;
+use crate::kernel::plc::types::primitives::floats::real::Real;
+use crate::kernel::plc::types::primitives::traits::meta_data::{HeapOrStatic, MaybeHeapOrStatic};
+use crate::kernel::plc::types::primitives::traits::primitive_traits::PrimitiveTrait;
+use crate::kernel::registry::Kernel;
+use crate::kernel::rust::convert::{convert_value, read_value, write_value, ConvertKind, ConvertValue, Rounding};
+use core::cell::RefCell;
+use core::ops::DerefMut;
+use std::rc::Rc;
+
+/// What happens to a value outside of MIN..MAX (NORM_X) or 0.0..1.0 (SCALE_X).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum OutOfRange {
+    /// Linear extrapolation, like the PLC
+    Extrapolate,
+    Clamp,
+    Error,
+}
+
+impl TryFrom<&str> for OutOfRange {
+    type Error = Stop;
+
+    fn try_from(value: &str) -> Result<Self, Stop> {
+        match value {
+            "extrapolate" => Ok(OutOfRange::Extrapolate),
+            "clamp" => Ok(OutOfRange::Clamp),
+            "error" => Ok(OutOfRange::Error),
+            _ => Err(error!(format!("Invalid range '{}', expected extrapolate, clamp or error", value))),
+        }
+    }
+}
+
+/// Analog scaling function.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Scaling {
+    /// NORM_X(MIN, VALUE, MAX) = (VALUE - MIN) / (MAX - MIN)
+    Normalize,
+    /// SCALE_X(MIN, VALUE, MAX) = VALUE * (MAX - MIN) + MIN
+    Scale,
+}
+
+/// Applies the scaling to plain floats.
+pub fn scale(scaling: Scaling, min: f64, value: f64, max: f64, range: OutOfRange) -> Result<f64, Stop> {
+    let ratio = match scaling {
+        Scaling::Normalize => {
+            if min == max {
+                return Err(error!(format!("Invalid operation: Norm_x with equal MIN and MAX {}", min)));
+            }
+            (value - min) / (max - min)
+        }
+        Scaling::Scale => value,
+    };
+
+    let ratio = match (range, (0.0..=1.0).contains(&ratio)) {
+        (OutOfRange::Extrapolate, _) | (_, true) => ratio,
+        (OutOfRange::Clamp, false) => ratio.clamp(0.0, 1.0),
+        (OutOfRange::Error, false) => return Err(error!(format!("Invalid operation: {} is out of range {}..{}", value, min, max))),
+    };
+
+    Ok(match scaling {
+        Scaling::Normalize => ratio,
+        Scaling::Scale => ratio * (max - min) + min,
+    })
+}
+
+fn read_float(input: &AnyRefType, kind: ConvertKind, channel: &Broadcast) -> Result<f64, Stop> {
+    match read_value(input, kind, channel)? {
+        ConvertValue::Integer(a) => Ok(a as f64),
+        ConvertValue::Float(a) => Ok(a),
+        other => Err(error!(format!("Expected a number, got {:?}", other))),
+    }
+}
+
+/// Builds NORM_X or SCALE_X over integer or float MIN, VALUE and MAX inputs.
+///
+/// NORM_X returns a Real and SCALE_X the type of MIN, unless the Kernel overrides the return of
+/// the operation for the types of MIN and VALUE. An integer result is rounded to the nearest.
+pub fn box_scale_operation(
+    name: &'static str,
+    scaling: Scaling,
+    [min, value, max]: [AnyRefType; 3],
+    range: OutOfRange,
+    trace: u32,
+    kernel: &Kernel,
+) -> Result<RunTimeOperation, Stop> {
+    kernel.check_filtered_operation(name, &min, &value)?;
+
+    let kind = |a: &AnyRefType| match ConvertKind::of(a) {
+        Some(kind @ (ConvertKind::Integer { .. } | ConvertKind::Float { .. })) => Ok(kind),
+        _ => Err(error!(format!("Invalid operation: {} expects an integer or a float, got {}", name, a))),
+    };
+    let kinds = [kind(&min)?, kind(&value)?, kind(&max)?];
+
+    let return_type = match (kernel.check_return_operation(name, &min, &value), scaling) {
+        (Some(a), _) => a.transform()?,
+        (None, Scaling::Normalize) => LocalType::PlcFloat(PlcFloat::Real(Real::new_default(0))),
+        (None, Scaling::Scale) => min.transform()?,
+    };
+    let to = match ConvertKind::of(&return_type) {
+        Some(kind @ (ConvertKind::Integer { .. } | ConvertKind::Float { .. })) => kind,
+        _ => return Err(error!(format!("Invalid operation: {} can not return {}", name, return_type))),
+    };
+
+    let return_ptr = LocalPointer::new(return_type);
+    let return_ptr_clone = return_ptr.clone();
+
+    let (min_clone, value_clone, max_clone) = (min.clone(), value.clone(), max.clone());
+
+    Ok(Box::new(Operation::new(
+        MaybeHeapOrStatic(Some(HeapOrStatic::Closure(Rc::new(RefCell::new(move || format!("{} {}, {}, {}", name, min_clone, value_clone, max_clone)))))),
+        move |channel| {
+            let result = scale(
+                scaling,
+                read_float(&min, kinds[0], channel)?,
+                read_float(&value, kinds[1], channel)?,
+                read_float(&max, kinds[2], channel)?,
+                range,
+            )?;
+
+            let (result, kept) = convert_value(ConvertValue::Float(result), ConvertKind::Float { bits: 64 }, to, Rounding::Round);
+            if !kept {
+                return Err(error!(format!("Invalid operation: {} result does not fit in {}", name, return_ptr)));
+            }
+            write_value(return_ptr.as_ref().borrow_mut().deref_mut(), to, result, channel)
+        }, Some(return_ptr_clone), false, trace)))
+}
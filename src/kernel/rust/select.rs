@@ -0,0 +1,132 @@
+use crate::container::broadcast::broadcast::Broadcast;
+use crate::container::error::error::Stop;
+use crate::error;
+use crate::kernel::arch::any::any_type::AnyRefType;
+use crate::kernel::arch::local::pointer::LocalPointer;
+use crate::kernel::arch::local::r#type::{IntoLocalType, LocalType};
+use crate::kernel::plc::interface::section_interface::SectionInterface;
+use crate::kernel::plc::internal::template_impl::TemplateMemory;
+use crate::kernel::plc::operations::operations::{Operation, RunTimeOperation};
+use crate::kernel::plc::types::primitives::traits::meta_data::{HeapOrStatic, MaybeHeapOrStatic};
+use crate::kernel::registry::Kernel;
+use crate::kernel::rust::convert::{convert_value, read_value, write_value, ConvertKind, ConvertValue, Rounding};
+use crate::parser::body::json_target::JsonTarget;
+use core::cell::RefCell;
+use core::cmp::Ordering;
+use core::ops::DerefMut;
+use std::rc::Rc;
+
+/// Solves the inputs of a selection or a bitwise operation in order. Implicit constants take the
+/// type of the first input which is not one, e.g. `MIN(a, 10)` or `LIMIT(0, x, 100)`, or the type
+/// of `fallback` when every input is Implicit.
+pub fn solve_inputs(
+    inputs: &[&JsonTarget],
+    fallback: Option<LocalType>,
+    interface: &SectionInterface,
+    template: Option<&TemplateMemory>,
+    registry: &Kernel,
+    channel: &Broadcast,
+) -> Result<Vec<AnyRefType>, Stop> {
+    let typed = inputs.iter().position(|a| !a.is_implicit()).unwrap_or(0);
+    let first = inputs[typed].solve_to_ref(interface, template, fallback, registry, channel)?;
+    let hint = first.transform()?;
+
+    let mut solved = inputs
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| *index != typed)
+        .map(|(_, a)| a.solve_to_ref(interface, template, Some(hint.clone()), registry, channel))
+        .collect::<Result<Vec<AnyRefType>, Stop>>()?;
+    solved.insert(typed, first);
+    Ok(solved)
+}
+
+/// Kind every input of a family is compared in, times are compared in ns.
+fn common_kind(kind: ConvertKind) -> Option<ConvertKind> {
+    match kind {
+        ConvertKind::Integer { .. } => Some(ConvertKind::Integer { bits: 64, signed: true }),
+        ConvertKind::Float { .. } => Some(ConvertKind::Float { bits: 64 }),
+        ConvertKind::Time { .. } | ConvertKind::S5Time => Some(ConvertKind::Time { bits: 64, unit: 1 }),
+        _ => None,
+    }
+}
+
+fn read_common(input: &AnyRefType, kind: ConvertKind, channel: &Broadcast) -> Result<ConvertValue, Stop> {
+    Ok(match (read_value(input, kind, channel)?, kind.time_unit()) {
+        (ConvertValue::Time(a), Some(unit)) => ConvertValue::Time(a * unit),
+        (value, _) => value,
+    })
+}
+
+/// Order of two values of the same family, None when a float is NaN.
+pub fn compare(a: &ConvertValue, b: &ConvertValue) -> Option<Ordering> {
+    match (a, b) {
+        (ConvertValue::Integer(a), ConvertValue::Integer(b)) => Some(a.cmp(b)),
+        (ConvertValue::Time(a), ConvertValue::Time(b)) => Some(a.cmp(b)),
+        (ConvertValue::Float(a), ConvertValue::Float(b)) => a.partial_cmp(b),
+        _ => None,
+    }
+}
+
+/// Builds a selection function, `select` returns the index of the input to copy to the result.
+///
+/// The inputs must all be of the AnyInteger, AnyFloat or AnyTime family. The result has the type
+/// of the first input, unless the Kernel overrides the return of the operation for the types of
+/// the first two inputs.
+pub fn box_select_operation(
+    name: &'static str,
+    selector: Option<(AnyRefType, ConvertKind)>,
+    inputs: Vec<AnyRefType>,
+    select: impl Fn(Option<ConvertValue>, &[ConvertValue]) -> Result<usize, Stop> + 'static,
+    trace: u32,
+    kernel: &Kernel,
+) -> Result<RunTimeOperation, Stop> {
+    let first = inputs.first().ok_or_else(|| error!(format!("Invalid operation: {} without input", name)))?;
+    let second = inputs.get(1).unwrap_or(first);
+    kernel.check_filtered_operation(name, first, second)?;
+
+    let kinds = inputs
+        .iter()
+        .map(|a| ConvertKind::of(a).ok_or_else(|| error!(format!("Invalid operation: {} expects an integer, a float or a time, got {}", name, a))))
+        .collect::<Result<Vec<ConvertKind>, Stop>>()?;
+    let family = common_kind(kinds[0])
+        .ok_or_else(|| error!(format!("Invalid operation: {} expects an integer, a float or a time, got {}", name, first)))?;
+    if let Some((a, _)) = inputs.iter().zip(kinds.iter()).find(|(_, kind)| common_kind(**kind) != Some(family)) {
+        return Err(error!(format!("Invalid operation: Can not {} {} with {}", name, first, a)));
+    }
+
+    let return_type = match kernel.check_return_operation(name, first, second) {
+        Some(a) => a.transform()?,
+        None => first.transform()?,
+    };
+    let to = ConvertKind::of(&return_type)
+        .filter(|kind| common_kind(*kind) == Some(family))
+        .ok_or_else(|| error!(format!("Invalid operation: {} can not return {} for {}", name, return_type, first)))?;
+
+    let return_ptr = LocalPointer::new(return_type);
+    let return_ptr_clone = return_ptr.clone();
+
+    let operands = selector.iter().map(|(a, _)| a).chain(inputs.iter()).cloned().collect::<Vec<AnyRefType>>();
+    let display = move || format!("{} {}", name, operands.iter().map(|a| format!("{}", a)).collect::<Vec<String>>().join(", "));
+
+    Ok(Box::new(Operation::new(
+        MaybeHeapOrStatic(Some(HeapOrStatic::Closure(Rc::new(RefCell::new(display))))),
+        move |channel| {
+            let selector_value = match &selector {
+                Some((a, kind)) => Some(read_value(a, *kind, channel)?),
+                None => None,
+            };
+            let values = inputs
+                .iter()
+                .zip(kinds.iter())
+                .map(|(a, kind)| read_common(a, *kind, channel))
+                .collect::<Result<Vec<ConvertValue>, Stop>>()?;
+
+            let index = select(selector_value, &values)?;
+            let (value, kept) = convert_value(values[index], family, to, Rounding::Round);
+            if !kept {
+                return Err(error!(format!("Invalid operation: {} result {} does not fit in {}", name, inputs[index], return_ptr)));
+            }
+            write_value(return_ptr.as_ref().borrow_mut().deref_mut(), to, value, channel)
+        }, Some(return_ptr_clone), false, trace)))
+}
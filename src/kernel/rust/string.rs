@@ -0,0 +1,177 @@
+use crate::container::broadcast::broadcast::Broadcast;
+use crate::container::error::error::Stop;
+use crate::error;
+use crate::kernel::arch::any::any_type::AnyRefType;
+use crate::kernel::arch::local::pointer::LocalPointer;
+use crate::kernel::arch::local::r#type::LocalType;
+use crate::kernel::plc::operations::operations::{Operation, RunTimeOperation};
+use crate::kernel::plc::types::primitives::integers::int::Int;
+use crate::kernel::plc::types::primitives::integers::plc_integer::PlcInteger;
+use crate::kernel::plc::types::primitives::string::_string::{_String, plcstr};
+use crate::kernel::plc::types::primitives::string::plc_string::PlcString;
+use crate::kernel::plc::types::primitives::string::wstring::{plcwstr, WString};
+use crate::kernel::plc::types::primitives::traits::meta_data::{HeapOrStatic, MaybeHeapOrStatic};
+use crate::kernel::plc::types::primitives::traits::primitive_traits::{AsMutPrimitive, Primitive, PrimitiveTrait};
+use crate::kernel::plc::types::primitives::traits::string_length::StringLength;
+use crate::kernel::rust::convert::{read_value, ConvertKind, ConvertValue};
+use core::cell::RefCell;
+use core::ops::DerefMut;
+use std::rc::Rc;
+
+/// Result of a string function.
+pub enum StringOutput {
+    Text(String),
+    Integer(i128),
+}
+
+fn check_length(length: i128) -> Result<usize, Stop> {
+    match usize::try_from(length) {
+        Ok(a) => Ok(a),
+        Err(_) => Err(error!(format!("Invalid length {}", length))),
+    }
+}
+
+/// Checks a position starting at 1 and returns its index in the characters.
+fn check_position(position: i128, last: usize) -> Result<usize, Stop> {
+    match position >= 1 && position <= last as i128 {
+        true => Ok(position as usize - 1),
+        false => Err(error!(format!("Position {} is out of range 1..{}", position, last))),
+    }
+}
+
+fn chars(text: &str) -> Vec<char> {
+    text.chars().collect()
+}
+
+/// LEFT(IN, L), the first L characters, the whole string when L is greater than LEN(IN).
+pub fn left(text: &str, length: i128) -> Result<String, Stop> {
+    let length = check_length(length)?;
+    Ok(text.chars().take(length).collect())
+}
+
+/// RIGHT(IN, L), the last L characters.
+pub fn right(text: &str, length: i128) -> Result<String, Stop> {
+    let length = check_length(length)?;
+    let chars = chars(text);
+    Ok(chars[chars.len().saturating_sub(length)..].iter().collect())
+}
+
+/// MID(IN, L, P), L characters from the position P.
+pub fn mid(text: &str, length: i128, position: i128) -> Result<String, Stop> {
+    let length = check_length(length)?;
+    let chars = chars(text);
+    let start = check_position(position, chars.len())?;
+    Ok(chars[start..].iter().take(length).collect())
+}
+
+/// FIND(IN1, IN2), position of the first occurrence of IN2 in IN1, 0 when not found.
+pub fn find(text: &str, pattern: &str) -> i128 {
+    match pattern.is_empty() {
+        true => 0,
+        false => text
+            .find(pattern)
+            .map(|index| text[..index].chars().count() as i128 + 1)
+            .unwrap_or(0),
+    }
+}
+
+/// INSERT(IN1, IN2, P), IN2 inserted after the P-th character of IN1, P = 0 inserts at the start.
+pub fn insert(text: &str, other: &str, position: i128) -> Result<String, Stop> {
+    let chars = chars(text);
+    let index = match position == 0 {
+        true => 0,
+        false => check_position(position, chars.len())? + 1,
+    };
+    Ok(chars[..index].iter().chain(other.chars().collect::<Vec<char>>().iter()).chain(chars[index..].iter()).collect())
+}
+
+/// DELETE(IN, L, P), removes L characters from the position P.
+pub fn delete(text: &str, length: i128, position: i128) -> Result<String, Stop> {
+    let length = check_length(length)?;
+    let chars = chars(text);
+    let start = check_position(position, chars.len())?;
+    let end = (start + length).min(chars.len());
+    Ok(chars[..start].iter().chain(chars[end..].iter()).collect())
+}
+
+/// REPLACE(IN1, IN2, L, P), replaces L characters of IN1 from the position P with IN2.
+/// P = LEN(IN1) + 1 appends IN2.
+pub fn replace(text: &str, other: &str, length: i128, position: i128) -> Result<String, Stop> {
+    let length = check_length(length)?;
+    let chars = chars(text);
+    let start = check_position(position, chars.len() + 1)?;
+    let end = (start + length).min(chars.len());
+    Ok(chars[..start].iter().chain(other.chars().collect::<Vec<char>>().iter()).chain(chars[end..].iter()).collect())
+}
+
+fn read_text(value: &AnyRefType, channel: &Broadcast) -> Result<String, Stop> {
+    if value.is_plcstr() {
+        Ok(value.as_plcstr(channel)?.as_text().to_string())
+    } else if value.is_plcwstr() {
+        Ok(value.as_plcwstr(channel)?.as_text().to_string())
+    } else if value.is_char() {
+        Ok(value.as_char(channel)?.to_string())
+    } else {
+        Ok(value.as_wchar(channel)?.to_string())
+    }
+}
+
+/// Builds a string function over string operands (`texts`) and integer operands (`integers`).
+///
+/// A text result is a String, or a WString as soon as one operand is wide, and is truncated
+/// when assigned to a shorter String[n]. An integer result is an Int.
+pub fn box_string_operation(
+    name: &'static str,
+    texts: Vec<AnyRefType>,
+    integers: Vec<AnyRefType>,
+    returns_text: bool,
+    function: impl Fn(&[String], &[i128]) -> Result<StringOutput, Stop> + 'static,
+    trace: u32,
+) -> Result<RunTimeOperation, Stop> {
+    if let Some(invalid) = texts.iter().find(|a| !(a.is_plcstr() || a.is_plcwstr() || a.is_char() || a.is_wchar())) {
+        return Err(error!(format!("Invalid operation: {} expects a string, got {}", name, invalid)));
+    }
+    let kinds = integers
+        .iter()
+        .map(|a| match ConvertKind::of(a) {
+            Some(kind @ ConvertKind::Integer { .. }) => Ok(kind),
+            _ => Err(error!(format!("Invalid operation: {} expects an integer, got {}", name, a))),
+        })
+        .collect::<Result<Vec<ConvertKind>, Stop>>()?;
+
+    let wide = texts.iter().any(|a| a.is_plcwstr() || a.is_wchar());
+    let return_type = match (returns_text, wide) {
+        (true, false) => LocalType::PlcString(PlcString::_String(_String::new_default(0))),
+        (true, true) => LocalType::PlcString(PlcString::WString(WString::new_default(0))),
+        (false, _) => LocalType::PlcInteger(PlcInteger::Int(Int::new_default(0))),
+    };
+    let return_ptr = LocalPointer::new(return_type);
+    let return_ptr_clone = return_ptr.clone();
+
+    let operands = texts.iter().chain(integers.iter()).cloned().collect::<Vec<AnyRefType>>();
+    let display = move || format!("{} {}", name, operands.iter().map(|a| format!("{}", a)).collect::<Vec<String>>().join(", "));
+
+    Ok(Box::new(Operation::new(
+        MaybeHeapOrStatic(Some(HeapOrStatic::Closure(Rc::new(RefCell::new(display))))),
+        move |channel| {
+            let text_values = texts
+                .iter()
+                .map(|a| read_text(a, channel))
+                .collect::<Result<Vec<String>, Stop>>()?;
+            let integer_values = integers
+                .iter()
+                .zip(kinds.iter())
+                .map(|(a, kind)| match read_value(a, *kind, channel)? {
+                    ConvertValue::Integer(value) => Ok(value),
+                    other => Err(error!(format!("Expected an integer, got {:?}", other))),
+                })
+                .collect::<Result<Vec<i128>, Stop>>()?;
+
+            let mut result = return_ptr.as_ref().borrow_mut();
+            match function(&text_values, &integer_values)? {
+                StringOutput::Text(a) if wide => result.deref_mut().set_plcwstr(plcwstr::from_text(&a), channel),
+                StringOutput::Text(a) => result.deref_mut().set_plcstr(plcstr::from_text(&a), channel),
+                StringOutput::Integer(a) => result.deref_mut().set_i16(a as i16, channel),
+            }
+        }, Some(return_ptr_clone), false, trace)))
+}
@@ -0,0 +1,230 @@
+use crate::container::broadcast::broadcast::Broadcast;
+use crate::container::error::error::Stop;
+use crate::container::simulation::clock::SimClock;
+use crate::{error, key_reader};
+use crate::kernel::arch::any::any_type::AnyRefType;
+use crate::kernel::arch::local::pointer::LocalPointer;
+use crate::kernel::arch::snapshot::snapshot::InternalState;
+use crate::kernel::plc::operations::operations::{Operation, RunTimeOperation};
+use crate::kernel::plc::types::primitives::traits::family_traits::{IsFamily, WithMutFamily, WithRefFamily};
+use crate::kernel::plc::types::primitives::traits::meta_data::{HeapOrStatic, MaybeHeapOrStatic};
+use crate::kernel::plc::types::primitives::traits::primitive_traits::{AsMutPrimitive, Primitive};
+use crate::kernel::plc::types::primitives::timers::traits::TimeDuration;
+use crate::kernel::registry::Kernel;
+use core::cell::RefCell;
+use core::ops::DerefMut;
+use core::time::Duration;
+use serde_json::{json, Value};
+use std::rc::Rc;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum TimerKind {
+    Tp,
+    Ton,
+    Tof,
+    Tonr,
+}
+
+impl TimerKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            TimerKind::Tp => "TP",
+            TimerKind::Ton => "TON",
+            TimerKind::Tof => "TOF",
+            TimerKind::Tonr => "TONR",
+        }
+    }
+}
+
+/// State of an IEC timer between two calls, saved in the kernel snapshots.
+#[derive(Clone, Default, PartialEq, Debug)]
+pub struct TimerState {
+    pub input: bool,
+    pub running: bool,
+    pub q: bool,
+    pub start: Duration,
+    pub elapsed: Duration,
+    pub accumulated: Duration,
+}
+
+impl TimerState {
+    /// Evaluates the timer at `now`, Q and ET are then `self.q` and `self.elapsed`.
+    ///
+    /// - TP: a rising edge of IN starts a pulse of PT, ignoring the edges until the pulse ends.
+    ///   ET counts up to PT and holds PT while IN is still TRUE, it is reset once both the pulse
+    ///   ended and IN is FALSE.
+    /// - TON: Q is TRUE once IN has been TRUE for PT, ET is reset as soon as IN is FALSE.
+    /// - TOF: Q is TRUE while IN is TRUE and for PT after its falling edge. A new rising edge
+    ///   resets ET, ET holds PT once the delay elapsed.
+    /// - TONR: ET accumulates while IN is TRUE and holds while IN is FALSE, Q is TRUE once ET
+    ///   reaches PT. Only R resets Q and ET, R has priority over IN.
+    pub fn update(&mut self, kind: TimerKind, input: bool, reset: bool, preset: Duration, now: Duration) {
+        let rising = input && !self.input;
+        let falling = !input && self.input;
+        self.input = input;
+
+        match kind {
+            TimerKind::Tp => {
+                if rising && !self.running {
+                    self.running = true;
+                    self.start = now;
+                }
+                if self.running {
+                    let elapsed = now.saturating_sub(self.start);
+                    if elapsed < preset {
+                        self.q = true;
+                        self.elapsed = elapsed;
+                        return;
+                    }
+                    self.running = false;
+                }
+                self.q = false;
+                self.elapsed = if input { preset } else { Duration::ZERO };
+            }
+            TimerKind::Ton => {
+                if rising {
+                    self.start = now;
+                }
+                self.elapsed = if input { now.saturating_sub(self.start).min(preset) } else { Duration::ZERO };
+                self.q = input && self.elapsed >= preset;
+            }
+            TimerKind::Tof => {
+                if input {
+                    self.running = false;
+                    self.q = true;
+                    self.elapsed = Duration::ZERO;
+                } else {
+                    if falling {
+                        self.running = true;
+                        self.start = now;
+                    }
+                    if self.running {
+                        self.elapsed = now.saturating_sub(self.start).min(preset);
+                        self.running = self.elapsed < preset;
+                        self.q = self.running;
+                    }
+                }
+            }
+            TimerKind::Tonr => {
+                if reset {
+                    self.start = now;
+                    self.accumulated = Duration::ZERO;
+                    self.elapsed = Duration::ZERO;
+                    self.q = false;
+                    return;
+                }
+                if rising {
+                    self.start = now;
+                }
+                if input {
+                    self.elapsed = (self.accumulated + now.saturating_sub(self.start)).min(preset);
+                    self.q |= self.elapsed >= preset;
+                } else if falling {
+                    self.accumulated = self.elapsed;
+                }
+            }
+        }
+    }
+}
+
+impl InternalState for RefCell<TimerState> {
+    fn save(&self) -> Value {
+        let state = self.borrow();
+        json!({
+            "input": state.input,
+            "running": state.running,
+            "q": state.q,
+            "start": state.start.as_nanos() as u64,
+            "elapsed": state.elapsed.as_nanos() as u64,
+            "accumulated": state.accumulated.as_nanos() as u64,
+        })
+    }
+
+    fn restore(&self, value: &Value) -> Result<(), Stop> {
+        let value = value
+            .as_object()
+            .ok_or_else(|| error!(format!("Invalid timer state {}", value)))?;
+        key_reader!(
+            format!("Restore timer state"),
+            value {
+                input => as_bool,
+                running => as_bool,
+                q => as_bool,
+                start => as_u64,
+                elapsed => as_u64,
+                accumulated => as_u64,
+            }
+        );
+        *self.borrow_mut().deref_mut() = TimerState {
+            input,
+            running,
+            q,
+            start: Duration::from_nanos(start),
+            elapsed: Duration::from_nanos(elapsed),
+            accumulated: Duration::from_nanos(accumulated),
+        };
+        Ok(())
+    }
+
+    fn reset(&self) {
+        *self.borrow_mut().deref_mut() = TimerState::default();
+    }
+}
+
+/// Builds a TP, TON, TOF or TONR on the simulated clock.
+///
+/// Called like a function block, Q and ET are written to `q` and `et` when they are given.
+/// `reset` is the R input of a TONR.
+#[allow(clippy::too_many_arguments)]
+pub fn box_iec_timer(
+    kind: TimerKind,
+    input: &AnyRefType,
+    preset: &AnyRefType,
+    reset: Option<&AnyRefType>,
+    q: Option<LocalPointer>,
+    et: Option<LocalPointer>,
+    clock: SimClock,
+    trace: u32,
+    kernel: &Kernel,
+) -> Result<RunTimeOperation, Stop> {
+    let name = kind.name();
+    if !input.is_plc_bool() || !reset.is_none_or(|a| a.is_plc_bool()) {
+        return Err(error!(format!("Invalid {}: IN and R must be Bools, got {}", name, input)).add_id(trace));
+    }
+    if !preset.is_plc_time() {
+        return Err(error!(format!("Invalid {}: PT must be a duration, got {}", name, preset)).add_id(trace));
+    }
+    if !q.as_ref().is_none_or(|a| a.is_plc_bool()) || !et.as_ref().is_none_or(|a| a.is_plc_time()) {
+        return Err(error!(format!("Invalid {}: Q must be a Bool and ET a duration", name)).add_id(trace));
+    }
+
+    let state = Rc::new(RefCell::new(TimerState::default()));
+    kernel.internal_states.borrow_mut().push(trace, state.clone());
+
+    let (input, preset, reset) = (input.clone(), preset.clone(), reset.cloned());
+
+    Ok(Box::new(Operation::new(
+        MaybeHeapOrStatic(Some(HeapOrStatic::Static(name))),
+        move |channel: &Broadcast| {
+            let input = input.as_bool(channel)?;
+            let reset = match reset.as_ref() {
+                Some(a) => a.as_bool(channel)?,
+                None => false,
+            };
+            let preset = preset.with_plc_time(channel, |a| a.get_duration())?;
+
+            let (output, elapsed) = {
+                let mut state = state.borrow_mut();
+                state.update(kind, input, reset, preset, clock.now());
+                (state.q, state.elapsed)
+            };
+
+            if let Some(q) = q.as_ref() {
+                q.as_ref().borrow_mut().deref_mut().set_bool(output, channel)?;
+            }
+            if let Some(et) = et.as_ref() {
+                et.with_mut_plc_time(channel, &mut |a| a.set_duration(&elapsed, channel))??;
+            }
+            Ok(())
+        }, None, false, trace)))
+}
@@ -1,34 +1,223 @@
-﻿
-
-use vifsimlib::container::container::ParseStatus;
+use vifsimlib::container::broadcast::report::ReportFormat;
+use vifsimlib::container::container::Container;
+use vifsimlib::container::error::error::Stop;
+use vifsimlib::kernel::plc::operations::unit::test::{UnitTest, UnitTestStatus};
 use pollster;
+use serde_json::json;
 use std::fs::File;
 use std::io::Read;
+use std::process::exit;
+
+const USAGE: &str = "Usage: vifsim [options]
+
+Options:
+    --provider <path>      Provider file (default: provider.json)
+    --program <path>       Program file (default: program.json)
+    --entry <name>         Entry OB (default: Main)
+    --stop-on <mode>       'tests' to stop once all unit tests are reached, 'infinite' otherwise (default: tests)
+    --stop-after <ms>      Stops the simulation after this amount of simulated time, so that unreached tests
+                           cannot hang the runner (default: 60000)
+    --cycle-time <ms>      Simulated time of a cycle (default: 5)
+    --real-time            Paces the cycles on the host clock
+    --start-time <time>    System time at the start of the simulation, e.g. 2024-05-01T06:00:00 (default: 1990-01-01T00:00:00)
+    --time-zone <min>      Offset of the local time in minutes (default: 0)
+    --format <format>      Summary format, 'json', 'junit' or 'tap' (default: json)
+    --output <path>        Writes the summary to a file instead of stdout, the simulation log always goes to stderr
+    -h, --help             Prints this message
+
+Exit codes:
+    0  All unit tests succeeded
+    1  A unit test failed or has not been reached, or the simulation stopped on an error
+    2  Invalid arguments or unreadable files";
+
+#[derive(Clone, Copy)]
+enum Format {
+    Json,
+    Report(ReportFormat),
+}
+
+struct Args {
+    provider: String,
+    program: String,
+    entry: String,
+    stop_on: u64,
+    stop_after: u64,
+    cycle_time: u64,
+    real_time: bool,
+    start_time: Option<String>,
+    time_zone: i64,
+    format: Format,
+    output: Option<String>,
+}
+
+impl Default for Args {
+    fn default() -> Self {
+        Self {
+            provider: "provider.json".into(),
+            program: "program.json".into(),
+            entry: "Main".into(),
+            stop_on: 1,
+            stop_after: 60000,
+            cycle_time: 5,
+            real_time: false,
+            start_time: None,
+            time_zone: 0,
+            format: Format::Json,
+            output: None,
+        }
+    }
+}
+
+fn parse_args(mut raw: impl Iterator<Item = String>) -> Result<Args, String> {
+    let mut args = Args::default();
+
+    while let Some(arg) = raw.next() {
+        let mut value = |name: &str| raw.next().ok_or_else(|| format!("Missing value for {}", name));
+        match arg.as_str() {
+            "--provider" => args.provider = value(&arg)?,
+            "--program" => args.program = value(&arg)?,
+            "--entry" => args.entry = value(&arg)?,
+            "--stop-on" => args.stop_on = match value(&arg)?.as_str() {
+                "infinite" => 0,
+                "tests" => 1,
+                other => return Err(format!("Invalid stop-on mode '{}'", other)),
+            },
+            "--stop-after" => args.stop_after = parse_ms(&arg, &value(&arg)?)?,
+            "--cycle-time" => args.cycle_time = parse_ms(&arg, &value(&arg)?)?,
+            "--real-time" => args.real_time = true,
+            "--start-time" => args.start_time = Some(value(&arg)?),
+            "--time-zone" => args.time_zone = {
+                let minutes = value(&arg)?;
+                minutes
+                    .parse()
+                    .map_err(|_| format!("Invalid value for {}: '{}' is not a number of minutes", arg, minutes))?
+            },
+            "--format" => args.format = match value(&arg)?.as_str() {
+                "json" => Format::Json,
+                "junit" => Format::Report(ReportFormat::Junit),
+                "tap" => Format::Report(ReportFormat::Tap),
+                other => return Err(format!("Invalid format '{}'", other)),
+            },
+            "--output" => args.output = Some(value(&arg)?),
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                exit(0)
+            }
+            other => return Err(format!("Unknown argument '{}'", other)),
+        }
+    }
+
+    if args.stop_after == 0 {
+        return Err("--stop-after must be greater than 0".into());
+    }
+
+    Ok(args)
+}
+
+fn parse_ms(name: &str, value: &str) -> Result<u64, String> {
+    value
+        .parse()
+        .map_err(|_| format!("Invalid value for {}: '{}' is not a number of ms", name, value))
+}
+
+fn read_file(path: &str) -> Result<String, String> {
+    let mut data = String::new();
+    File::open(path)
+        .and_then(|mut file| file.read_to_string(&mut data))
+        .map_err(|e| format!("Could not read '{}': {}", path, e))?;
+    Ok(data)
+}
+
+fn status_str(status: UnitTestStatus) -> &'static str {
+    match status {
+        UnitTestStatus::Unreached => "unreached",
+        UnitTestStatus::Failed => "failed",
+        UnitTestStatus::Succeed => "succeed",
+    }
+}
+
+fn json_summary(tests: &[UnitTest], error: &Option<Stop>) -> String {
+    let summary = json!({
+        "error": error,
+        "total": tests.len(),
+        "succeed": tests.iter().filter(|t| matches!(t.get_status(), UnitTestStatus::Succeed)).count(),
+        "failed": tests.iter().filter(|t| matches!(t.get_status(), UnitTestStatus::Failed)).count(),
+        "unreached": tests.iter().filter(|t| matches!(t.get_status(), UnitTestStatus::Unreached)).count(),
+        "tests": tests.iter().map(|t| json!({
+            "id": t.get_id(),
+            "description": t.get_description(),
+            "status": status_str(t.get_status()),
+            "block": t.get_block(),
+            "cycle": t.get_cycle(),
+            "message": t.get_fail_message(),
+        })).collect::<Vec<_>>(),
+    });
+    serde_json::to_string_pretty(&summary).unwrap()
+}
+
+async fn run(args: &Args) -> Result<(Container, Option<Stop>), String> {
+    let provider = read_file(&args.provider)?;
+    let program = read_file(&args.program)?;
+
+    let mut server: Container = vifsimlib::container::container::boot_container(None);
+    let mut params = json!({
+        "stopOn": args.stop_on,
+        "stopAfter": args.stop_after,
+        "cycleTime": args.cycle_time,
+        "realTime": args.real_time,
+        "timeZone": args.time_zone,
+    });
+    if let Some(a) = &args.start_time {
+        params["startTime"] = json!(a);
+    }
+
+    let result = match server
+        .load_server_params(&params.to_string())
+        .and_then(|_| server.load_provider(&provider))
+        .and_then(|_| server.load_program(&program))
+    {
+        Ok(_) => server.start(&args.entry).await,
+        Err(e) => Err(e),
+    };
+    Ok((server, result.err()))
+}
 
 #[pollster::main]
 async fn main() {
-    use std::time::Instant;
-    let now = Instant::now();
-
-    let mut provider = File::open("provider.json").unwrap();
-    let mut provider_data = String::new();
-    provider.read_to_string(&mut provider_data).unwrap();
-
-    let mut program = File::open("program.json").unwrap();
-    let mut program_data = String::new();
-    program.read_to_string(&mut program_data).unwrap();
-
-    let mut server = vifsimlib::container::container::boot_container(None);
-    server.load_server_params(&"{ \"stopAfter\": 5000, \"stopOn\": 0 }");
-    server.load_server_params(&"{ \"stopAfter\": 5000, \"stopOn\": 1 }");
-    match server.load_provider(&provider_data) {
-        ParseStatus::Empty => panic!("Parse went wrong"),
-        ParseStatus::Loaded => match server.load_program(&program_data) {
-                ParseStatus::Empty => panic!("Parse went wrong"),
-                ParseStatus::Loaded => server.start("Main").await,
+    let args = match parse_args(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("{}\n\n{}", e, USAGE);
+            exit(2)
+        }
+    };
+
+    let (server, error) = match run(&args).await {
+        Ok(a) => a,
+        Err(e) => {
+            eprintln!("{}", e);
+            exit(2)
+        }
+    };
+
+    let mut tests = server.get_unit_tests();
+    tests.sort_by_key(|t| t.get_id());
+
+    let summary = match args.format {
+        Format::Json => json_summary(&tests, &error),
+        Format::Report(format) => server.get_unit_tests_report(format, &args.entry),
+    };
+
+    match &args.output {
+        None => println!("{}", summary),
+        Some(path) => if let Err(e) = std::fs::write(path, summary) {
+            eprintln!("Could not write '{}': {}", path, e);
+            exit(2)
         }
     }
 
-    let elapsed = now.elapsed();
-    println!("Elapsed: {:.2?}", elapsed);
-}
\ No newline at end of file
+    let passed = tests
+        .iter()
+        .all(|t| matches!(t.get_status(), UnitTestStatus::Succeed));
+    exit(if error.is_some() || !passed { 1 } else { 0 })
+}
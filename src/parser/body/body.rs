@@ -5,9 +5,14 @@ use crate::kernel::plc::operations::basics::calc::Calc;
 use crate::kernel::plc::operations::basics::compare::Compare;
 use crate::kernel::plc::operations::basics::assign::Assign;
 use crate::kernel::plc::operations::basics::call::Call;
+use crate::kernel::plc::operations::basics::convert::Convert;
 use crate::kernel::plc::operations::program_control::r#for::For;
 use crate::kernel::plc::operations::program_control::r#while::While;
 use crate::kernel::plc::operations::program_control::r#if::If;
+use crate::kernel::plc::operations::program_control::case::Case;
+use crate::kernel::plc::operations::program_control::repeat::Repeat;
+use crate::kernel::plc::operations::program_control::exit::Exit;
+use crate::kernel::plc::operations::program_control::r#continue::Continue;
 use crate::{error, key_reader};
 use crate::parser::body::path::parse_path;
 use crate::parser::body::json_target::JsonTarget;
@@ -26,6 +31,10 @@ use crate::kernel::plc::operations::binary::rotate_right::RotateRight;
 use crate::kernel::plc::operations::binary::shl::Shl;
 use crate::kernel::plc::operations::binary::shr::Shr;
 use crate::kernel::plc::operations::binary::swap::Swap;
+use crate::kernel::plc::operations::binary::and::And;
+use crate::kernel::plc::operations::binary::or::Or;
+use crate::kernel::plc::operations::binary::xor::Xor;
+use crate::kernel::plc::operations::binary::not::Not;
 use crate::kernel::plc::operations::internal::r_trig::R_Trig;
 use crate::kernel::plc::operations::internal::reset::Reset;
 use crate::kernel::plc::operations::math::acos::ACos;
@@ -44,6 +53,29 @@ use crate::kernel::plc::operations::math::abs::Abs;
 use crate::kernel::plc::operations::math::round::Round;
 use crate::kernel::plc::operations::math::tan::Tan;
 use crate::kernel::plc::operations::math::trunc::Trunc;
+use crate::kernel::plc::operations::math::min::Min;
+use crate::kernel::plc::operations::math::max::Max;
+use crate::kernel::plc::operations::math::limit::Limit;
+use crate::kernel::plc::operations::math::sel::Sel;
+use crate::kernel::plc::operations::math::mux::Mux;
+use crate::kernel::plc::operations::math::norm_x::NormX;
+use crate::kernel::plc::operations::math::scale_x::ScaleX;
+use crate::kernel::plc::operations::system::rd_sys_t::RdSysT;
+use crate::kernel::plc::operations::system::rd_loc_t::RdLocT;
+use crate::kernel::plc::operations::system::wr_sys_t::WrSysT;
+use crate::kernel::plc::operations::timers::tp::Tp;
+use crate::kernel::plc::operations::timers::ton::Ton;
+use crate::kernel::plc::operations::timers::tof::Tof;
+use crate::kernel::plc::operations::timers::tonr::Tonr;
+use crate::kernel::plc::operations::string::len::Len;
+use crate::kernel::plc::operations::string::concat::Concat;
+use crate::kernel::plc::operations::string::left::Left;
+use crate::kernel::plc::operations::string::right::Right;
+use crate::kernel::plc::operations::string::mid::Mid;
+use crate::kernel::plc::operations::string::find::Find;
+use crate::kernel::plc::operations::string::insert::Insert;
+use crate::kernel::plc::operations::string::delete::Delete;
+use crate::kernel::plc::operations::string::replace::Replace;
 
 
 pub fn parse_json_target(json: &Value) -> Result<JsonTarget, Stop> {
@@ -79,10 +111,15 @@ pub fn parse_json_target(json: &Value) -> Result<JsonTarget, Stop> {
         "calc" => Ok(JsonTarget::Operation(Box::new(JsonOperation::Calc(Calc::new(src)?)))),
         "compare" => Ok(JsonTarget::Operation(Box::new(JsonOperation::Compare(Compare::new(src)?)))),
         "if" => Ok(JsonTarget::Operation(Box::new(JsonOperation::If(If::new(src)?)))),
+        "case" => Ok(JsonTarget::Operation(Box::new(JsonOperation::Case(Case::new(src)?)))),
         "for" => Ok(JsonTarget::Operation(Box::new(JsonOperation::For(For::new(src)?)))),
         "while" => Ok(JsonTarget::Operation(Box::new(JsonOperation::While(While::new(src)?)))),
+        "repeat" => Ok(JsonTarget::Operation(Box::new(JsonOperation::Repeat(Repeat::new(src)?)))),
+        "exit" => Ok(JsonTarget::Operation(Box::new(JsonOperation::Exit(Exit::new(src)?)))),
+        "continue" => Ok(JsonTarget::Operation(Box::new(JsonOperation::Continue(Continue::new(src)?)))),
         "asg" => Ok(JsonTarget::Operation(Box::new(JsonOperation::Assign(Assign::new(src)?)))),
         "call" => Ok(JsonTarget::Operation(Box::new(JsonOperation::Call(Call::new(src)?)))),
+        "convert" => Ok(JsonTarget::Operation(Box::new(JsonOperation::Convert(Convert::new(src)?)))),
         "resolve_template" => Ok(JsonTarget::Operation(Box::new(JsonOperation::TemplateImpl(TemplateImpl::new(src)?)))),
 
         // Math
@@ -102,6 +139,13 @@ pub fn parse_json_target(json: &Value) -> Result<JsonTarget, Stop> {
         "ceil" => Ok(JsonTarget::Operation(Box::new(JsonOperation::Ceil(Ceil::new(&src)?)))),
         "floor" => Ok(JsonTarget::Operation(Box::new(JsonOperation::Floor(Floor::new(&src)?)))),
         "round" => Ok(JsonTarget::Operation(Box::new(JsonOperation::Round(Round::new(&src)?)))),
+        "min" => Ok(JsonTarget::Operation(Box::new(JsonOperation::Min(Min::new(src)?)))),
+        "max" => Ok(JsonTarget::Operation(Box::new(JsonOperation::Max(Max::new(src)?)))),
+        "limit" => Ok(JsonTarget::Operation(Box::new(JsonOperation::Limit(Limit::new(src)?)))),
+        "sel" => Ok(JsonTarget::Operation(Box::new(JsonOperation::Sel(Sel::new(src)?)))),
+        "mux" => Ok(JsonTarget::Operation(Box::new(JsonOperation::Mux(Mux::new(src)?)))),
+        "norm_x" => Ok(JsonTarget::Operation(Box::new(JsonOperation::NormX(NormX::new(src)?)))),
+        "scale_x" => Ok(JsonTarget::Operation(Box::new(JsonOperation::ScaleX(ScaleX::new(src)?)))),
 
         // Binaries
         "shl" => Ok(JsonTarget::Operation(Box::new(JsonOperation::Shl(Shl::new(&src)?)))),
@@ -109,6 +153,32 @@ pub fn parse_json_target(json: &Value) -> Result<JsonTarget, Stop> {
         "rol" => Ok(JsonTarget::Operation(Box::new(JsonOperation::RotateLeft(RotateLeft::new(&src)?)))),
         "ror" => Ok(JsonTarget::Operation(Box::new(JsonOperation::RotateRight(RotateRight::new(&src)?)))),
         "swap" => Ok(JsonTarget::Operation(Box::new(JsonOperation::Swap(Swap::new(&src)?)))),
+        "and" => Ok(JsonTarget::Operation(Box::new(JsonOperation::And(And::new(src)?)))),
+        "or" => Ok(JsonTarget::Operation(Box::new(JsonOperation::Or(Or::new(src)?)))),
+        "xor" => Ok(JsonTarget::Operation(Box::new(JsonOperation::Xor(Xor::new(src)?)))),
+        "not" => Ok(JsonTarget::Operation(Box::new(JsonOperation::Not(Not::new(src)?)))),
+
+        // Strings
+        "len" => Ok(JsonTarget::Operation(Box::new(JsonOperation::Len(Len::new(src)?)))),
+        "concat" => Ok(JsonTarget::Operation(Box::new(JsonOperation::Concat(Concat::new(src)?)))),
+        "left" => Ok(JsonTarget::Operation(Box::new(JsonOperation::Left(Left::new(src)?)))),
+        "right" => Ok(JsonTarget::Operation(Box::new(JsonOperation::Right(Right::new(src)?)))),
+        "mid" => Ok(JsonTarget::Operation(Box::new(JsonOperation::Mid(Mid::new(src)?)))),
+        "find" => Ok(JsonTarget::Operation(Box::new(JsonOperation::Find(Find::new(src)?)))),
+        "insert" => Ok(JsonTarget::Operation(Box::new(JsonOperation::Insert(Insert::new(src)?)))),
+        "delete" => Ok(JsonTarget::Operation(Box::new(JsonOperation::Delete(Delete::new(src)?)))),
+        "replace" => Ok(JsonTarget::Operation(Box::new(JsonOperation::Replace(Replace::new(src)?)))),
+
+        // System
+        "rd_sys_t" => Ok(JsonTarget::Operation(Box::new(JsonOperation::RdSysT(RdSysT::new(src)?)))),
+        "rd_loc_t" => Ok(JsonTarget::Operation(Box::new(JsonOperation::RdLocT(RdLocT::new(src)?)))),
+        "wr_sys_t" => Ok(JsonTarget::Operation(Box::new(JsonOperation::WrSysT(WrSysT::new(src)?)))),
+
+        // Timers
+        "tp" => Ok(JsonTarget::Operation(Box::new(JsonOperation::Tp(Tp::new(src)?)))),
+        "ton" => Ok(JsonTarget::Operation(Box::new(JsonOperation::Ton(Ton::new(src)?)))),
+        "tof" => Ok(JsonTarget::Operation(Box::new(JsonOperation::Tof(Tof::new(src)?)))),
+        "tonr" => Ok(JsonTarget::Operation(Box::new(JsonOperation::Tonr(Tonr::new(src)?)))),
 
         // Internal
         "#timer_sm" => Ok(JsonTarget::Operation(Box::new(JsonOperation::TimerStateMachine(TimerStateMachine::new(src)?)))),
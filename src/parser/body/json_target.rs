@@ -12,9 +12,8 @@ use crate::kernel::plc::types::primitives::binaries::dword::DWord;
 use crate::kernel::plc::types::primitives::binaries::lword::LWord;
 use crate::kernel::plc::types::primitives::binaries::plc_binary::PlcBinary;
 use crate::kernel::plc::types::primitives::binaries::word::Word;
-use crate::kernel::plc::types::primitives::boolean::bit_access::BitAccess;
+use crate::kernel::plc::types::primitives::binaries::slice_access::parse_access;
 use crate::kernel::plc::types::primitives::boolean::bool::Bool;
-use crate::kernel::plc::types::primitives::boolean::plc_bool::PlcBool;
 use crate::kernel::plc::types::primitives::floats::lreal::LReal;
 use crate::kernel::plc::types::primitives::floats::plc_float::PlcFloat;
 use crate::kernel::plc::types::primitives::floats::real::Real;
@@ -36,6 +35,7 @@ use crate::kernel::plc::types::primitives::string::wstring::WString;
 use crate::kernel::plc::types::primitives::timers::lTime::LTime;
 use crate::kernel::plc::types::primitives::timers::plc_time::PlcTime;
 use crate::kernel::plc::types::primitives::timers::time::Time;
+use crate::kernel::plc::types::primitives::date::plc_date::PlcDate;
 use crate::kernel::plc::types::primitives::tod::ltod::LTod;
 use crate::kernel::plc::types::primitives::tod::plc_tod::PlcTod;
 use crate::kernel::plc::types::primitives::tod::tod::Tod;
@@ -47,6 +47,7 @@ use crate::kernel::arch::local::r#type::LocalType;
 use crate::kernel::registry::{convert_string_path_to_usize, get_or_insert_global_string, GlobalOrLocal, Kernel};
 use serde_json::{Map, Value};
 use core::fmt::{Display, Formatter};
+use core::ops::Deref;
 
 #[derive(Clone)]
 pub enum JsonTarget {
@@ -79,6 +80,9 @@ impl Display for JsonTarget {
     }
 }
 
+/// Builds a reference of the same kind as the one being explained, from a part of its path.
+type ParentTarget = fn(Vec<String>) -> JsonTarget;
+
 impl JsonTarget {
     pub fn is_constant(&self) -> bool {
         matches!(self, Self::Constant(_))
@@ -99,6 +103,10 @@ impl JsonTarget {
         matches!(self, Self::Operation(_))
     }
     pub fn is_access(&self) -> bool { matches!(self, Self::Access(_)) }
+    /// Constant which takes its type from the context
+    pub fn is_implicit(&self) -> bool {
+        matches!(self, Self::Constant(a) if a.get("ty").and_then(Value::as_str) == Some("Implicit"))
+    }
 
     pub fn solve_as_local_pointer(
         &self,
@@ -114,16 +122,42 @@ impl JsonTarget {
             },
             Self::Local(local) => interface.try_get_nested(&convert_string_path_to_usize(local)),
             Self::Inner(inner) => template.and_then(|x| x.try_get_nested(&convert_string_path_to_usize(inner))),
-            Self::Access(access) => {
-                Some(LocalPointer::from(LocalType::PlcBool(PlcBool::BitAccess(
-                    BitAccess::new_(access, interface, template, registry, channel).unwrap(),
-                ))))
-                // todo eventually add other slice access (Byte, Word ...)
-            }
+            Self::Access(access) => parse_access(access, interface, template, registry, channel).ok(),
             _ => None,
         }
     }
 
+    /// Explains why a local reference could not be solved when an index is not within the
+    /// declared bounds of an array, when a slice access is invalid or when it is a member of a DTL.
+    pub fn explain_unsolved(
+        &self,
+        interface: &SectionInterface,
+        template: Option<&TemplateMemory>,
+        registry: &Kernel,
+        channel: &Broadcast,
+    ) -> Option<Stop> {
+        let (path, parent): (&Vec<String>, ParentTarget) = match self {
+            Self::Local(a) => (a, Self::Local),
+            Self::LocalOut(a) => (a, Self::LocalOut),
+            Self::Inner(a) => (a, Self::Inner),
+            Self::Access(access) => return parse_access(access, interface, template, registry, channel).err(),
+            _ => return None,
+        };
+
+        (1..path.len()).rev().find_map(|i| {
+            let pointer = parent(path[..i].to_vec()).solve_as_local_pointer(interface, template, registry, channel)?;
+            let error = match pointer.as_ref().borrow().deref() {
+                LocalType::PlcArray(array) => array.get_interface().position_of(&path[i]).err(),
+                LocalType::PlcDate(PlcDate::Dtl(_)) => Some(error!(format!(
+                    "Invalid access: {} of {} is not supported, a DTL is a single date and time and has no member",
+                    path[i], path[..i].join(".")
+                ))),
+                _ => None,
+            };
+            error
+        })
+    }
+
     pub fn solve_as_global_pointer(&self, registry: &Kernel) -> Option<GlobalPointer> {
         match self {
             Self::Global(global) => registry.get(&get_or_insert_global_string(&global[0])).clone(),
@@ -162,17 +196,13 @@ impl JsonTarget {
         match self {
             Self::Local(a) | Self::LocalOut(a) | Self::Inner(a) => Ok(AnyRefType::Local(
                 self.solve_as_local_pointer(interface, template, registry, channel)
-                    .ok_or_else(move || error!(format!(
+                    .ok_or_else(move || self.explain_unsolved(interface, template, registry, channel).unwrap_or_else(|| error!(format!(
                         "Could not solve json target as local reference {:?}",
                         a
-                    )))?,
+                    ))))?,
             )),
-            Self::Access(a) => Ok(AnyRefType::Local(
-                self.solve_as_local_pointer(interface, template, registry, channel)
-                    .ok_or_else(move || error!(format!(
-                        "Could not solve json target as local reference {:?}",
-                        a
-                    )))?,
+            Self::Access(access) => Ok(AnyRefType::Local(
+                parse_access(access, interface, template, registry, channel)?,
             )),
             Self::Constant(..) => Ok(AnyRefType::Constant(
                 self.solve_as_constant(&registry, force_constant_type)?,
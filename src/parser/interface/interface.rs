@@ -41,6 +41,14 @@ pub fn parse_struct_interface(
             name, value
         )))?;
 
+        let retain = json.get("retain").and_then(|a| a.as_bool()).unwrap_or(false);
+        if retain && !matches!(section, Section::Input | Section::Output | Section::InOut | Section::Static | Section::NONE) {
+            return Err(error!(
+                format!("Member '{}' can not be retained in section {}", name, section),
+                format!("Parse member")
+            ));
+        }
+
         let name = get_or_insert_global_string(name);
 
         let mut pointer = LocalPointer::from(parse_local_type(json, registry, channel, monitor)?);
@@ -59,6 +67,8 @@ pub fn parse_struct_interface(
           pointer.set_read_only(true);
         };
 
+        pointer.set_retain(retain);
+
         // Set path
         pointer.set_name(name);
 
@@ -8,7 +8,7 @@ use crate::kernel::plc::types::primitives::binaries::plc_binary::PlcBinary;
 use crate::kernel::plc::types::primitives::binaries::word::Word;
 use crate::kernel::plc::types::primitives::boolean::bool::Bool;
 use crate::kernel::plc::types::primitives::boolean::plc_bool::PlcBool;
-use crate::kernel::plc::types::primitives::traits::meta_data::{MetaData};
+use crate::kernel::plc::types::primitives::traits::meta_data::{MetaData, SetMetaData};
 use crate::kernel::plc::types::primitives::floats::lreal::LReal;
 use crate::kernel::plc::types::primitives::floats::plc_float::PlcFloat;
 use crate::kernel::plc::types::primitives::floats::real::Real;
@@ -32,6 +32,12 @@ use crate::kernel::plc::types::primitives::timers::time::Time;
 use crate::kernel::plc::types::primitives::tod::ltod::LTod;
 use crate::kernel::plc::types::primitives::tod::plc_tod::PlcTod;
 use crate::kernel::plc::types::primitives::tod::tod::Tod;
+use crate::kernel::plc::types::primitives::timers::s5Time::S5Time;
+use crate::kernel::plc::types::primitives::date::date::Date;
+use crate::kernel::plc::types::primitives::date::dt::Dt;
+use crate::kernel::plc::types::primitives::date::dtl::Dtl;
+use crate::kernel::plc::types::primitives::date::ldt::Ldt;
+use crate::kernel::plc::types::primitives::date::plc_date::PlcDate;
 use crate::kernel::arch::constant::r#type::ConstantType;
 use crate::kernel::arch::local::r#type::{IntoLocalType, LocalType};
 use crate::kernel::plc::types::primitives::traits::primitive_traits::PrimitiveTrait;
@@ -52,6 +58,20 @@ pub fn parse_constant_type(
 
     match ty {
         "Implicit" => {
+            // A symbol or a value of the enumeration of the context
+            if let Some(plc_enum) = force_constant_type.as_ref().and_then(|a| registry.get_enum_of(a)) {
+                key_reader!(
+                    format!("Parse enumeration constant"),
+                    src {
+                        id => as_u64,
+                        value,
+                    }
+                );
+                let mut constant = ConstantType::PlcInteger(plc_enum.create(id as u32, Some(value))?);
+                constant.set_alias(plc_enum.get_name(), registry);
+                return Ok(constant);
+            }
+
             match force_constant_type {
                 None => Err(error!("Implicit constant but vif could not determine the context. Maybe wrap your value in a primitive ?".to_string())),
                 Some(a) => {
@@ -77,9 +97,9 @@ pub fn parse_constant_type(
                         }
                         LocalType::PlcBinary(b) => {
                             match b {
-                                PlcBinary::Byte(_) => Ok(ConstantType::PlcBinary(PlcBinary::Byte(Byte::try_from(src)?))),
-                                PlcBinary::Word(_) => Ok(ConstantType::PlcBinary(PlcBinary::Word(Word::try_from(src)?))),
-                                PlcBinary::DWord(_) => Ok(ConstantType::PlcBinary(PlcBinary::DWord(DWord::try_from(src)?))),
+                                PlcBinary::Byte(_) | PlcBinary::ByteAccess(_) => Ok(ConstantType::PlcBinary(PlcBinary::Byte(Byte::try_from(src)?))),
+                                PlcBinary::Word(_) | PlcBinary::WordAccess(_) => Ok(ConstantType::PlcBinary(PlcBinary::Word(Word::try_from(src)?))),
+                                PlcBinary::DWord(_) | PlcBinary::DWordAccess(_) => Ok(ConstantType::PlcBinary(PlcBinary::DWord(DWord::try_from(src)?))),
                                 PlcBinary::LWord(_) => Ok(ConstantType::PlcBinary(PlcBinary::LWord(LWord::try_from(src)?))),
                             }
                         }
@@ -87,6 +107,7 @@ pub fn parse_constant_type(
                             match b {
                                 PlcTime::Time(_) => Ok(ConstantType::PlcTime(PlcTime::Time(Time::try_from(src)?))),
                                 PlcTime::LTime(_) => Ok(ConstantType::PlcTime(PlcTime::LTime(LTime::try_from(src)?))),
+                                PlcTime::S5Time(_) => Ok(ConstantType::PlcTime(PlcTime::S5Time(S5Time::try_from(src)?))),
                             }
                         }
                         LocalType::PlcTod(b) => {
@@ -95,6 +116,14 @@ pub fn parse_constant_type(
                                 PlcTod::LTod(_) => Ok(ConstantType::PlcTod(PlcTod::LTod(LTod::try_from(src)?))),
                             }
                         }
+                        LocalType::PlcDate(b) => {
+                            match b {
+                                PlcDate::Date(_) => Ok(ConstantType::PlcDate(PlcDate::Date(Date::try_from(src)?))),
+                                PlcDate::Dt(_) => Ok(ConstantType::PlcDate(PlcDate::Dt(Dt::try_from(src)?))),
+                                PlcDate::Ldt(_) => Ok(ConstantType::PlcDate(PlcDate::Ldt(Ldt::try_from(src)?))),
+                                PlcDate::Dtl(_) => Ok(ConstantType::PlcDate(PlcDate::Dtl(Dtl::try_from(src)?))),
+                            }
+                        }
                         LocalType::PlcString(b) => {
                             match b {
                                 PlcString::_Char(_) => Ok(ConstantType::PlcString(PlcString::_Char(_Char::try_from(src)?))),
@@ -130,6 +159,22 @@ pub fn parse_constant_type(
             }
         },
 
+        // Enumeration
+        "enum" => {
+            key_reader!(
+                format!("Parse enumeration constant"),
+                src {
+                    of => as_str,
+                    id => as_u64,
+                    value,
+                }
+            );
+            let plc_enum = registry.get_enum(of).ok_or_else(|| error!(format!("Enumeration {} is not registered", of)))?;
+            let mut constant = ConstantType::PlcInteger(plc_enum.create(id as u32, Some(value))?);
+            constant.set_alias(of, registry);
+            Ok(constant)
+        },
+
         // Bool
         "Bool" => Ok(ConstantType::PlcBool(PlcBool::try_from(json)?)),
 
@@ -144,11 +189,14 @@ pub fn parse_constant_type(
         "Real" | "LReal" => Ok(ConstantType::PlcFloat(PlcFloat::try_from(json)?)),
 
         // Time
-        "Time" | "LTime" => Ok(ConstantType::PlcTime(PlcTime::try_from(json)?)),
+        "Time" | "LTime" | "S5Time" => Ok(ConstantType::PlcTime(PlcTime::try_from(json)?)),
 
         // TOD
         "Tod" | "LTod" => Ok(ConstantType::PlcTod(PlcTod::try_from(json)?)),
 
+        // Date
+        "Date" | "Dt" | "Ldt" | "Dtl" => Ok(ConstantType::PlcDate(PlcDate::try_from(json)?)),
+
         //String
         "String" | "Char" | "WString" | "WChar" => Ok(ConstantType::PlcString(PlcString::try_from(json)?)),
         _ => Err(error!(format!("Unknown constant type: {}", ty))),
@@ -174,8 +222,13 @@ pub fn create_default_constant_from_str(identifier: &str) -> Result<ConstantType
         "LReal" => Ok(ConstantType::PlcFloat(PlcFloat::LReal(LReal::new_default(0)))),
         "Time" => Ok(ConstantType::PlcTime(PlcTime::Time(Time::new_default(0)))),
         "LTime" => Ok(ConstantType::PlcTime(PlcTime::LTime(LTime::new_default(0)))),
+        "S5Time" => Ok(ConstantType::PlcTime(PlcTime::S5Time(S5Time::new_default(0)))),
         "Tod" => Ok(ConstantType::PlcTod(PlcTod::Tod(Tod::new_default(0)))),
         "LTod" => Ok(ConstantType::PlcTod(PlcTod::LTod(LTod::new_default(0)))),
+        "Date" => Ok(ConstantType::PlcDate(PlcDate::Date(Date::new_default(0)))),
+        "Dt" => Ok(ConstantType::PlcDate(PlcDate::Dt(Dt::new_default(0)))),
+        "Ldt" => Ok(ConstantType::PlcDate(PlcDate::Ldt(Ldt::new_default(0)))),
+        "Dtl" => Ok(ConstantType::PlcDate(PlcDate::Dtl(Dtl::new_default(0)))),
         _ => Err(error!(format!("Invalid type {}", identifier)))
     }
 }
\ No newline at end of file
@@ -18,8 +18,10 @@ use crate::kernel::plc::types::primitives::integers::plc_integer::PlcInteger;
 use crate::kernel::plc::types::primitives::string::plc_string::PlcString;
 use crate::kernel::plc::types::primitives::timers::plc_time::PlcTime;
 use crate::kernel::plc::types::primitives::tod::plc_tod::PlcTod;
+use crate::kernel::plc::types::primitives::date::plc_date::PlcDate;
 use crate::kernel::arch::local::r#type::{IntoLocalType, LocalType};
 use crate::kernel::plc::types::primitives::traits::primitive_traits::ToggleMonitor;
+use crate::kernel::plc::types::primitives::traits::meta_data::SetMetaData;
 use crate::kernel::registry::{get_or_insert_global_string, Kernel};
 
 pub fn parse_local_type(
@@ -59,6 +61,23 @@ pub fn parse_local_type(
             }
         },
 
+        // Enumeration
+        "enum" => {
+            key_reader!(
+                format!("Parse enumeration"),
+                src {
+                    of => as_str,
+                    id => as_u64,
+                    value?,
+                }
+            );
+            let plc_enum = registry.get_enum(of).ok_or_else(|| error!(format!("Enumeration {} is not registered", of)))?;
+            let mut local = LocalType::PlcInteger(plc_enum.create(id as u32, value)?);
+            local.set_alias(of, registry);
+            registry.monitor_enums.borrow_mut().insert(id as u32, plc_enum);
+            Ok(local)
+        },
+
         // Bool
         "Bool" => { 
             let mut ab = LocalType::PlcBool(PlcBool::try_from(json)?);
@@ -77,11 +96,14 @@ pub fn parse_local_type(
         "Real" | "LReal" => Ok(LocalType::PlcFloat(PlcFloat::try_from(json)?)),
 
         // Time
-        "Time" | "LTime" => Ok(LocalType::PlcTime(PlcTime::try_from(json)?)),
+        "Time" | "LTime" | "S5Time" => Ok(LocalType::PlcTime(PlcTime::try_from(json)?)),
 
         // TOD
         "Tod" | "LTod" => Ok(LocalType::PlcTod(PlcTod::try_from(json)?)),
 
+        // Date
+        "Date" | "Dt" | "Ldt" | "Dtl" => Ok(LocalType::PlcDate(PlcDate::try_from(json)?)),
+
         //String
         "String" | "Char" | "WString" | "WChar" => Ok(LocalType::PlcString(PlcString::try_from(json)?)),
 
@@ -5,6 +5,9 @@ use crate::container::error::error::Stop;
 use crate::error;
 use crate::parser::local_type::constant_type::{create_default_constant_from_str};
 use crate::kernel::plc::interface::section::Section;
+use crate::kernel::plc::types::enumeration::PlcEnum;
+use crate::kernel::rust::overflow::Overflow;
+use crate::kernel::rust::float_exception::FloatPolicy;
 
 fn forbidden_alias(alias: &str) -> bool {
     matches!(alias, "Ob" | "Fb" | "Fc" |
@@ -14,8 +17,9 @@ fn forbidden_alias(alias: &str) -> bool {
         "Byte" | "Word" | "DWord" | "LWord" |
         "Real" | "LReal" |
         "String" | "WString" | "Char" | "WChar" |
-        "Time" | "LTime" |
-        "Tod" | "LTod")
+        "Time" | "LTime" | "S5Time" |
+        "Tod" | "LTod" |
+        "Date" | "Dt" | "Ldt" | "Dtl")
 }
 
 fn turn_family_into_types(identifier: &str) -> Option<HashSet<String>> {
@@ -49,6 +53,46 @@ pub fn parse_type_aliases(type_aliases: Option<&Map<String, Value>>, registry: &
     } else { Ok(()) }
 }
 
+pub fn parse_enums(enums: Option<&Map<String, Value>>, registry: &mut Kernel, in_program: bool) -> Result<(), Stop> {
+    if let Some(a) = enums {
+        a
+            .iter()
+            .try_for_each(|(name, data)| {
+                if forbidden_alias(name) {
+                    Err(error!(format!("Enumeration name {} is forbidden", name)))
+                } else {
+                    registry.add_enum(PlcEnum::from_json(name, data)?, in_program)
+                }
+            })
+    } else { Ok(()) }
+}
+
+pub fn parse_overflow(overflow: Option<&Map<String, Value>>, registry: &mut Kernel) -> Result<(), Stop> {
+    if let Some(a) = overflow {
+        a
+            .iter()
+            .try_for_each(|(of, policy)| {
+                let policy = match policy.as_str() {
+                    Some(a) => Overflow::try_from(a),
+                    None => Err(error!(format!("[Overflow] Invalid entry for {}, expected string got {:?}", of, policy)))
+                }?;
+
+                match turn_family_into_types(of) {
+                    None => registry.set_overflow(of, policy),
+                    Some(types) => types.iter().for_each(|a_type| registry.set_overflow(a_type, policy))
+                };
+                Ok(())
+            })
+    } else { Ok(()) }
+}
+
+pub fn parse_float_exceptions(float_exceptions: Option<&str>, registry: &mut Kernel) -> Result<(), Stop> {
+    if let Some(a) = float_exceptions {
+        registry.floats.set_policy(FloatPolicy::try_from(a)?);
+    }
+    Ok(())
+}
+
 pub fn parse_exclude_sections(exclude_sections: Option<&Map<String, Value>>, registry: &mut Kernel) -> Result<(), Stop> {
     if let Some(a) = exclude_sections {
         a
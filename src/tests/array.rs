@@ -0,0 +1,71 @@
+#[cfg(test)]
+mod tests {
+    use crate::tests::common::fixtures::load;
+
+    fn program(path: &str) -> String {
+        let values = (0..6)
+            .map(|i| format!(r#"{{ "ty": "Int", "src": {{ "id": {}, "value": {} }} }}"#, 10 + i, i))
+            .collect::<Vec<String>>()
+            .join(", ");
+        r#"
+        {
+            "file:///Data": {
+                "ty": "global_db",
+                "src": {
+                    "id": 1,
+                    "interface": {
+                        "ty": "interface",
+                        "src": {
+                            "static": {
+                                "grid": {
+                                    "ty": "array",
+                                    "src": {
+                                        "bounds": [[1, 3], [0, 1]],
+                                        "of": { "ty": "Int", "src": { "id": 2 } },
+                                        "values": [VALUES]
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            },
+            "file:///Main": {
+                "ty": "ob",
+                "src": {
+                    "id": 3,
+                    "interface": { "ty": "interface", "src": {} },
+                    "body": [{
+                        "ty": "asg",
+                        "src": {
+                            "id": 4,
+                            "assign": { "ty": "local_out", "src": { "path": ["Data", "grid", "PATH"] } },
+                            "to": { "ty": "local_out", "src": { "path": ["Data", "grid", "[1,0]"] } }
+                        }
+                    }]
+                }
+            }
+        }"#.replace("VALUES", &values).replace("PATH", path)
+    }
+
+    #[test]
+    fn multi_dimensional_array_bounds() {
+        let mut container = load("{}", &program("[2,1]")).unwrap();
+        pollster::block_on(container.step("Main", 1)).unwrap();
+        let snapshot: serde_json::Value = serde_json::from_str(&container.snapshot().unwrap()).unwrap();
+        // Row-major order, [2,1] is the 4th element and [1,0] the first one
+        assert_eq!(snapshot["memory"]["Data"]["grid"], serde_json::json!([0, 1, 2, 0, 4, 5]));
+        container.stop().unwrap();
+
+        assert!(load("{}", &program("[4,0]")).err().unwrap().contains("Index [4,0] is out of the bounds of Array[1..3, 0..1]"));
+        assert!(load("{}", &program("[0,1]")).err().unwrap().contains("out of the bounds"));
+        assert!(load("{}", &program("[2]")).err().unwrap().contains("does not match the 2 dimension(s) of Array[1..3, 0..1]"));
+
+        // Lengths past i64 or usize are rejected with the declared bounds
+        let declared = |bounds: &str| program("[2,1]").replace("[[1, 3], [0, 1]]", bounds);
+        assert!(load("{}", &declared("[[-9223372036854775808, 9223372036854775807]]")).err().unwrap()
+            .contains("Array[-9223372036854775808..9223372036854775807] has too many elements"));
+        assert!(load("{}", &declared("[[1, 4294967296], [1, 4294967296], [1, 4294967296]]")).err().unwrap()
+            .contains("Array[1..4294967296, 1..4294967296, 1..4294967296] has too many elements"));
+    }
+}
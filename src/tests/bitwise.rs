@@ -0,0 +1,70 @@
+#[cfg(test)]
+mod tests {
+    use crate::tests::common::fixtures::{assign, load, program, read, variable};
+
+    const STATICS: &str = r#"{
+        "mask": { "ty": "Byte", "src": { "id": 2, "value": 15 } },
+        "status": { "ty": "Word", "src": { "id": 3, "value": 4660 } },
+        "flag": { "ty": "Bool", "src": { "id": 4, "value": true } },
+        "and": { "ty": "Word", "src": { "id": 5 } },
+        "or": { "ty": "Word", "src": { "id": 6 } },
+        "xor": { "ty": "Byte", "src": { "id": 7 } },
+        "not": { "ty": "Word", "src": { "id": 8 } },
+        "not_flag": { "ty": "Bool", "src": { "id": 9 } },
+        "raw": { "ty": "Int", "src": { "id": 10 } }
+    }"#;
+
+    #[test]
+    fn bitwise_operations() {
+        let body = format!("[{}, {}, {}, {}, {}]",
+            assign(20, "and", &format!(r#"{{ "ty": "and", "src": {{ "id": 21, "and": {}, "and_with": {} }} }}"#, variable("mask"), variable("status"))),
+            assign(22, "or", &format!(r#"{{ "ty": "or", "src": {{ "id": 23, "or": {}, "or_with": {} }} }}"#, variable("status"), variable("mask"))),
+            assign(24, "xor", &format!(r#"{{ "ty": "xor", "src": {{ "id": 25, "xor": {}, "xor_with": {{ "ty": "Byte", "src": {{ "id": 26, "value": 255 }} }} }} }}"#, variable("mask"))),
+            assign(27, "not", &format!(r#"{{ "ty": "not", "src": {{ "id": 28, "not": {} }} }}"#, variable("status"))),
+            assign(29, "not_flag", &format!(r#"{{ "ty": "not", "src": {{ "id": 30, "not": {} }} }}"#, variable("flag"))),
+        );
+
+        let mut container = load("{}", &program(STATICS, &body)).unwrap();
+        pollster::block_on(container.step("Main", 1)).unwrap();
+        // The Byte is extended to a Word whatever the order of the operands
+        assert_eq!(read(&container, "and").as_u64(), Some(0x0004));
+        assert_eq!(read(&container, "or").as_u64(), Some(0x123F));
+        assert_eq!(read(&container, "xor").as_u64(), Some(0xF0));
+        assert_eq!(read(&container, "not").as_u64(), Some(0xEDCB));
+        assert_eq!(read(&container, "not_flag").as_bool(), Some(false));
+        container.stop().unwrap();
+
+        let mixed = format!("[{}]", assign(20, "and", &format!(r#"{{ "ty": "and", "src": {{ "id": 21, "and": {}, "and_with": {} }} }}"#, variable("mask"), variable("flag"))));
+        assert!(load("{}", &program(STATICS, &mixed)).err().unwrap().contains("expected two Bools or two binaries"));
+        let integer = format!("[{}]", assign(20, "raw", &format!(r#"{{ "ty": "not", "src": {{ "id": 21, "not": {} }} }}"#, variable("raw"))));
+        assert!(load("{}", &program(STATICS, &integer)).err().unwrap().contains("expected a Bool or a binary"));
+
+        // NOT follows the filtered operations like the binary ones
+        let filtered = r#"{ "filter_operations": { "not": { "Word": ["Byte"] } } }"#;
+        let not = format!("[{}]", assign(20, "not", &format!(r#"{{ "ty": "not", "src": {{ "id": 21, "not": {} }} }}"#, variable("status"))));
+        assert!(load(filtered, &program(STATICS, &not)).err().unwrap().contains("Operation not is forbidden with types Word and Word"));
+    }
+
+    #[test]
+    fn implicit_masks_take_the_type_of_the_other_operand() {
+        let implicit = |id: u32, value: u64| format!(r#"{{ "ty": "Implicit", "src": {{ "id": {id}, "value": {value} }} }}"#);
+        let body = format!("[{}, {}, {}]",
+            // status AND 16#00FF
+            assign(20, "and", &format!(r#"{{ "ty": "and", "src": {{ "id": 21, "and": {}, "and_with": {} }} }}"#, variable("status"), implicit(22, 0x00FF))),
+            // 16#F000 OR status
+            assign(23, "or", &format!(r#"{{ "ty": "or", "src": {{ "id": 24, "or": {}, "or_with": {} }} }}"#, implicit(25, 0xF000), variable("status"))),
+            assign(26, "xor", &format!(r#"{{ "ty": "xor", "src": {{ "id": 27, "xor": {}, "xor_with": {} }} }}"#, variable("mask"), implicit(28, 0xFF))),
+        );
+
+        let mut container = load("{}", &program(STATICS, &body)).unwrap();
+        pollster::block_on(container.step("Main", 1)).unwrap();
+        assert_eq!(read(&container, "and").as_u64(), Some(0x0034));
+        assert_eq!(read(&container, "or").as_u64(), Some(0xF234));
+        assert_eq!(read(&container, "xor").as_u64(), Some(0xF0));
+        container.stop().unwrap();
+
+        // 16#1FF does not fit in the Byte of the other operand
+        let overflow = format!("[{}]", assign(26, "xor", &format!(r#"{{ "ty": "xor", "src": {{ "id": 27, "xor": {}, "xor_with": {} }} }}"#, variable("mask"), implicit(28, 0x1FF))));
+        assert!(load("{}", &program(STATICS, &overflow)).is_err());
+    }
+}
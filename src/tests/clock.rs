@@ -0,0 +1,76 @@
+#[cfg(test)]
+mod tests {
+    use crate::container::container::Container;
+    use crate::tests::common::fixtures::{assign, load_with_params, program, read};
+
+    const STATICS: &str = r#"{
+        "utc": { "ty": "Dtl", "src": { "id": 2 } },
+        "local": { "ty": "Dt", "src": { "id": 3 } },
+        "day": { "ty": "Date", "src": { "id": 4 } },
+        "ret": { "ty": "Int", "src": { "id": 5, "value": -1 } }
+    }"#;
+
+    /// RD_SYS_T, RD_LOC_T as a DT and RD_SYS_T as a DATE, after WR_SYS_T(DTL#2024-05-01-06:00:00) when `write`.
+    fn body(write: bool) -> String {
+        let mut body = vec![
+            assign(11, "utc", r#"{ "ty": "rd_sys_t", "src": { "id": 12 } }"#),
+            assign(13, "local", r#"{ "ty": "rd_loc_t", "src": { "id": 14, "to": "Dt" } }"#),
+            assign(15, "day", r#"{ "ty": "rd_sys_t", "src": { "id": 16, "to": "Date" } }"#),
+        ];
+        if write {
+            body.insert(0, assign(17, "ret",
+                r#"{ "ty": "wr_sys_t", "src": { "id": 18, "wr_sys_t": { "ty": "Dtl", "src": { "id": 19, "value": "2024-05-01T06:00:00" } } } }"#));
+        }
+        format!("[{}]", body.join(", "))
+    }
+
+    fn load(params: &str, body: &str) -> Container {
+        load_with_params(params, "{}", &program(STATICS, body)).unwrap()
+    }
+
+    #[test]
+    fn system_clock_follows_simulated_time() {
+        let mut container = load(r#"{ "stopOn": 0, "realTime": false, "cycleTime": 5, "startTime": "2024-05-01T23:59:59.990", "timeZone": 120 }"#, &body(false));
+        pollster::block_on(container.step("Main", 1)).unwrap();
+        assert_eq!(read(&container, "utc").as_i64(), Some(1_714_607_999_990_000_000));
+        // DT#2024-05-02-01:59:59.990, in ns since 1990-01-01
+        assert_eq!(read(&container, "local").as_i64(), Some(1_083_463_199_990_000_000));
+        assert_eq!(read(&container, "day").as_u64(), Some(12539));
+
+        // Midnight rollover after 2 more cycles of 5 ms
+        pollster::block_on(container.step("Main", 2)).unwrap();
+        assert_eq!(read(&container, "utc").as_i64(), Some(1_714_608_000_000_000_000));
+        assert_eq!(read(&container, "day").as_u64(), Some(12540));
+        container.stop().unwrap();
+
+        // The clock starts over with the next simulation
+        pollster::block_on(container.step("Main", 1)).unwrap();
+        assert_eq!(read(&container, "utc").as_i64(), Some(1_714_607_999_990_000_000));
+        container.stop().unwrap();
+
+        // WR_SYS_T moves the system time of the running simulation
+        let mut container = load(r#"{ "stopOn": 0, "realTime": false, "cycleTime": 5 }"#, &body(true));
+        pollster::block_on(container.step("Main", 3)).unwrap();
+        assert_eq!(read(&container, "ret").as_i64(), Some(0));
+        assert_eq!(read(&container, "utc").as_i64(), Some(1_714_543_200_000_000_000));
+        assert_eq!(read(&container, "day").as_u64(), Some(12539));
+        container.stop().unwrap();
+    }
+
+    #[test]
+    fn snapshot_keeps_system_clock() {
+        let mut written = load(r#"{ "stopOn": 0, "realTime": false, "cycleTime": 5, "timeZone": 120 }"#, &body(true));
+        pollster::block_on(written.step("Main", 3)).unwrap();
+        let checkpoint = written.snapshot().unwrap();
+        written.stop().unwrap();
+
+        // The time written by WR_SYS_T and the time zone go on in another container
+        let mut container = load(r#"{ "stopOn": 0, "realTime": false, "cycleTime": 5 }"#, &body(false));
+        pollster::block_on(container.step("Main", 1)).unwrap();
+        container.restore(&checkpoint).unwrap();
+        pollster::block_on(container.step("Main", 1)).unwrap();
+        assert_eq!(read(&container, "utc").as_i64(), Some(1_714_543_200_005_000_000));
+        assert_eq!(read(&container, "local").as_i64(), Some(1_083_398_400_005_000_000));
+        container.stop().unwrap();
+    }
+}
@@ -0,0 +1,60 @@
+#[cfg(test)]
+pub mod fixtures {
+    use crate::container::container::{boot_container, Container};
+
+    /// Program made of a `Data` global Db with the `statics` variables and a `Main` Ob running `body`.
+    pub fn program(statics: &str, body: &str) -> String {
+        r#"
+        {
+            "file:///Data": {
+                "ty": "global_db",
+                "src": {
+                    "id": 1,
+                    "interface": { "ty": "interface", "src": { "static": STATICS } }
+                }
+            },
+            "file:///Main": {
+                "ty": "ob",
+                "src": {
+                    "id": 1000,
+                    "interface": { "ty": "interface", "src": {} },
+                    "body": BODY
+                }
+            }
+        }"#.replace("STATICS", statics).replace("BODY", body)
+    }
+
+    /// Reference to the variable `name` of the `Data` Db.
+    pub fn variable(name: &str) -> String {
+        format!(r#"{{ "ty": "local_out", "src": {{ "path": ["Data", "{name}"] }} }}"#)
+    }
+
+    pub fn assign_to(id: u32, target: &str, operation: &str) -> String {
+        format!(r#"{{ "ty": "asg", "src": {{ "id": {id}, "assign": {target}, "to": {operation} }} }}"#)
+    }
+
+    /// Assignment of the variable `name` of the `Data` Db.
+    pub fn assign(id: u32, name: &str, operation: &str) -> String {
+        assign_to(id, &variable(name), operation)
+    }
+
+    /// Container with the provider and the program loaded, ready to step without real time.
+    pub fn load(provider: &str, program: &str) -> Result<Container, String> {
+        load_with_params(r#"{ "stopOn": 0, "realTime": false }"#, provider, program)
+    }
+
+    /// Like [`load`], with the given server params.
+    pub fn load_with_params(params: &str, provider: &str, program: &str) -> Result<Container, String> {
+        let mut container = boot_container(None);
+        container.load_server_params(params).map_err(|e| format!("{}", e))?;
+        container.load_provider(provider).map_err(|e| format!("{}", e))?;
+        container.load_program(program).map_err(|e| format!("{}", e))?;
+        Ok(container)
+    }
+
+    /// Value of the variable `name` of the `Data` Db in a snapshot.
+    pub fn read(container: &Container, name: &str) -> serde_json::Value {
+        let snapshot: serde_json::Value = serde_json::from_str(&container.snapshot().unwrap()).unwrap();
+        snapshot["memory"]["Data"][name].clone()
+    }
+}
@@ -0,0 +1,347 @@
+#[cfg(test)]
+mod tests {
+    use crate::container::container::{boot_container, Container, ContainerParams, RunUntil};
+    use crate::tests::common::fixtures;
+    use crate::kernel::plc::operations::unit::test::UnitTestStatus;
+
+    const PROGRAM: &str = r#"
+    {
+        "file:///Data": {
+            "ty": "global_db",
+            "src": {
+                "id": 1,
+                "interface": {
+                    "ty": "interface",
+                    "src": {
+                        "static": {
+                            "counter": { "ty": "Int", "src": { "id": 2, "value": 0 } }
+                        }
+                    }
+                }
+            }
+        },
+        "file:///Main": {
+            "ty": "ob",
+            "src": {
+                "id": 3,
+                "interface": { "ty": "interface", "src": {} },
+                "body": [
+                    {
+                        "ty": "asg",
+                        "src": {
+                            "id": 4,
+                            "assign": { "ty": "local_out", "src": { "path": ["Data", "counter"] } },
+                            "to": {
+                                "ty": "calc",
+                                "src": {
+                                    "id": 5,
+                                    "operator": "+",
+                                    "calc": { "ty": "local_out", "src": { "path": ["Data", "counter"] } },
+                                    "with": { "ty": "Int", "src": { "id": 6, "value": 1 } }
+                                }
+                            }
+                        }
+                    },
+                    {
+                        "ty": "unit_test",
+                        "src": {
+                            "id": 7,
+                            "description": "counter is incremented",
+                            "operator": ">",
+                            "expect": { "ty": "local_out", "src": { "path": ["Data", "counter"] } },
+                            "with": { "ty": "Int", "src": { "id": 8, "value": 0 } }
+                        }
+                    }
+                ]
+            }
+        }
+    }"#;
+
+    fn load_with(container: &mut Container, params: &str, program: &str) {
+        container.load_server_params(params).unwrap();
+        container.load_provider("{}").unwrap();
+        container.load_program(program).unwrap();
+    }
+
+    fn load(container: &mut Container) {
+        load_with(container, r#"{ "stopOn": 1, "realTime": false }"#, PROGRAM);
+    }
+
+    fn run(container: &mut Container) {
+        pollster::block_on(container.start("Main")).unwrap();
+        let tests = container.get_unit_tests();
+        assert_eq!(tests.len(), 1);
+        assert!(matches!(tests[0].get_status(), UnitTestStatus::Succeed));
+    }
+
+    #[test]
+    fn containers_do_not_share_state() {
+        let mut first = boot_container(None);
+        let mut second = boot_container(None);
+        load(&mut first);
+        load(&mut second);
+
+        // A full reset of the first container restarts its id counter,
+        // which must not affect the interned paths of the second one
+        first.clear_provider();
+        load(&mut first);
+
+        run(&mut second);
+        run(&mut first);
+        assert!(!first.is_running());
+        assert!(!second.is_running());
+    }
+
+    #[test]
+    fn step_runs_exact_cycles() {
+        let mut container = boot_container(None);
+        load_with(&mut container, r#"{ "stopOn": 0, "cycleTime": 10, "realTime": false }"#, PROGRAM);
+
+        assert!(pollster::block_on(container.step("Main", 3)).unwrap());
+        assert!(container.is_running());
+        assert_eq!(container.get_cycle(), 3);
+        assert_eq!(container.get_simulated_time(), 30);
+
+        // Resumes the same simulation
+        assert!(pollster::block_on(container.step("Main", 2)).unwrap());
+        assert_eq!(container.get_cycle(), 5);
+
+        let outcome = pollster::block_on(container.run_until("Main", 10, |c| c.get_cycle() == 8)).unwrap();
+        assert_eq!(outcome, RunUntil::Reached);
+        assert_eq!(container.get_cycle(), 8);
+
+        let outcome = pollster::block_on(container.run_until("Main", 2, |_| false)).unwrap();
+        assert_eq!(outcome, RunUntil::MaxCycles);
+        assert_eq!(container.get_cycle(), 10);
+
+        container.stop().unwrap();
+        assert!(!container.is_running());
+    }
+
+    #[test]
+    fn forced_value_overrides_program() {
+        let mut container = boot_container(None);
+        load_with(&mut container, r#"{ "stopOn": 0, "realTime": false }"#, PROGRAM);
+        let path = || vec!["Data".to_string(), "counter".to_string()];
+
+        assert!(container.force(vec!["Data".into(), "unknown".into()], r#"{ "ty": "Int", "src": { "value": 1 } }"#).is_err());
+        assert!(container.force(path(), r#"{ "ty": "Bool", "src": { "value": true } }"#).is_err());
+
+        // The program increments the counter but the force brings it back every cycle
+        container.force(path(), r#"{ "ty": "Implicit", "src": { "value": -5 } }"#).unwrap();
+        assert!(container.is_forced(path()));
+        assert!(pollster::block_on(container.step("Main", 3)).unwrap());
+        assert!(matches!(container.get_unit_tests()[0].get_status(), UnitTestStatus::Failed));
+
+        assert!(container.unforce(path()));
+        assert!(!container.is_forced(path()));
+        container.write(path(), r#"{ "ty": "Int", "src": { "value": 10 } }"#).unwrap();
+        assert!(pollster::block_on(container.step("Main", 1)).unwrap());
+        assert!(matches!(container.get_unit_tests()[0].get_status(), UnitTestStatus::Succeed));
+        container.stop().unwrap();
+    }
+
+    #[test]
+    fn snapshot_and_restore() {
+        let mut container = boot_container(None);
+        load_with(&mut container, r#"{ "stopOn": 0, "cycleTime": 10, "realTime": false }"#, PROGRAM);
+        let counter = |data: &str| {
+            let snapshot: serde_json::Value = serde_json::from_str(data).unwrap();
+            snapshot["memory"]["Data"]["counter"].as_i64().unwrap()
+        };
+
+        pollster::block_on(container.step("Main", 3)).unwrap();
+        let checkpoint = container.snapshot().unwrap();
+        assert_eq!(counter(&checkpoint), 3);
+
+        pollster::block_on(container.step("Main", 2)).unwrap();
+        assert_eq!(counter(&container.snapshot().unwrap()), 5);
+
+        container.restore(&checkpoint).unwrap();
+        assert_eq!(container.get_cycle(), 3);
+        assert_eq!(container.get_simulated_time(), 30);
+        assert_eq!(counter(&container.snapshot().unwrap()), 3);
+
+        assert!(container.restore("{ \"memory\": { \"Data\": { \"counter\": true } } }").is_err());
+        container.stop().unwrap();
+    }
+
+    #[test]
+    fn stop_resets_every_variable() {
+        let program = PROGRAM.replace(
+            r#""counter": { "ty": "Int", "src": { "id": 2, "value": 0 } }"#,
+            r#""first": { "ty": "Int", "src": { "id": 20, "value": 0 } },
+               "counter": { "ty": "Int", "src": { "id": 2, "value": 0 } },
+               "last": { "ty": "Int", "src": { "id": 21, "value": 0 } }"#,
+        );
+        let mut container = boot_container(None);
+        container.load_server_params(r#"{ "stopOn": 0, "realTime": false }"#).unwrap();
+        container.load_provider("{}").unwrap();
+        container.load_program(&program).unwrap();
+
+        let memory = |container: &Container| {
+            let snapshot: serde_json::Value = serde_json::from_str(&container.snapshot().unwrap()).unwrap();
+            let data = &snapshot["memory"]["Data"];
+            (data["first"].as_i64().unwrap(), data["counter"].as_i64().unwrap(), data["last"].as_i64().unwrap())
+        };
+
+        pollster::block_on(container.step("Main", 2)).unwrap();
+        container.write(vec!["Data".into(), "first".into()], r#"{ "ty": "Int", "src": { "value": 4 } }"#).unwrap();
+        container.write(vec!["Data".into(), "last".into()], r#"{ "ty": "Int", "src": { "value": 5 } }"#).unwrap();
+        assert_eq!(memory(&container), (4, 2, 5));
+
+        container.stop().unwrap();
+        assert_eq!(memory(&container), (0, 0, 0));
+    }
+
+    #[test]
+    fn warm_restart_keeps_retained_values() {
+        let program = PROGRAM.replace(
+            r#""counter": { "ty": "Int", "src": { "id": 2, "value": 0 } }"#,
+            r#""counter": { "ty": "Int", "src": { "id": 2, "value": 0 } },
+               "total": { "ty": "Int", "retain": true, "src": { "id": 20, "value": 0 } }"#,
+        );
+        let mut container = boot_container(None);
+        load_with(&mut container, r#"{ "stopOn": 0, "realTime": false }"#, &program);
+
+        let memory = |container: &Container| {
+            let snapshot: serde_json::Value = serde_json::from_str(&container.snapshot().unwrap()).unwrap();
+            let data = &snapshot["memory"]["Data"];
+            (data["counter"].as_i64().unwrap(), data["total"].as_i64().unwrap())
+        };
+
+        pollster::block_on(container.step("Main", 2)).unwrap();
+        container.write(vec!["Data".into(), "total".into()], r#"{ "ty": "Int", "src": { "value": 7 } }"#).unwrap();
+        assert_eq!(memory(&container), (2, 7));
+
+        container.warm_restart().unwrap();
+        assert!(!container.is_running());
+        assert_eq!(memory(&container), (0, 7));
+
+        container.cold_restart().unwrap();
+        assert_eq!(memory(&container), (0, 0));
+    }
+
+    /// Counter program with a startup Ob setting it to 100 and a 10 ms cyclic Ob counting ticks
+    fn scheduled_program() -> String {
+        PROGRAM.replace(
+            r#""counter": { "ty": "Int", "src": { "id": 2, "value": 0 } }"#,
+            r#""counter": { "ty": "Int", "src": { "id": 2, "value": 0 } },
+               "ticks": { "ty": "Int", "src": { "id": 30, "value": 0 } }"#,
+        ).replacen('{', r#"{
+        "file:///Startup": {
+            "ty": "ob",
+            "src": {
+                "id": 31,
+                "kind": "startup",
+                "interface": { "ty": "interface", "src": {} },
+                "body": [{
+                    "ty": "asg",
+                    "src": {
+                        "id": 32,
+                        "assign": { "ty": "local_out", "src": { "path": ["Data", "counter"] } },
+                        "to": { "ty": "Int", "src": { "id": 33, "value": 100 } }
+                    }
+                }]
+            }
+        },
+        "file:///Tick": {
+            "ty": "ob",
+            "src": {
+                "id": 34,
+                "kind": "cyclic",
+                "interval": 10,
+                "interface": { "ty": "interface", "src": {} },
+                "body": [{
+                    "ty": "asg",
+                    "src": {
+                        "id": 35,
+                        "assign": { "ty": "local_out", "src": { "path": ["Data", "ticks"] } },
+                        "to": {
+                            "ty": "calc",
+                            "src": {
+                                "id": 36,
+                                "operator": "+",
+                                "calc": { "ty": "local_out", "src": { "path": ["Data", "ticks"] } },
+                                "with": { "ty": "Int", "src": { "id": 37, "value": 1 } }
+                            }
+                        }
+                    }
+                }]
+            }
+        },"#, 1)
+    }
+
+    fn scheduled_memory(container: &Container) -> (i64, i64) {
+        let snapshot: serde_json::Value = serde_json::from_str(&container.snapshot().unwrap()).unwrap();
+        let data = &snapshot["memory"]["Data"];
+        (data["counter"].as_i64().unwrap(), data["ticks"].as_i64().unwrap())
+    }
+
+    #[test]
+    fn startup_and_cyclic_obs() {
+        let mut container = boot_container(None);
+        load_with(&mut container, r#"{ "stopOn": 0, "cycleTime": 5, "realTime": false }"#, &scheduled_program());
+
+        // Cycles start at 0, 5, 10, 15 and 20 ms, the cyclic Ob runs at 10 and 20 ms
+        pollster::block_on(container.step("Main", 5)).unwrap();
+        assert_eq!(scheduled_memory(&container), (105, 2));
+
+        assert!(pollster::block_on(container.step("Tick", 1)).is_err());
+        container.stop().unwrap();
+
+        // The startup Ob runs again for a new simulation
+        pollster::block_on(container.step("Main", 1)).unwrap();
+        assert_eq!(scheduled_memory(&container), (101, 0));
+        container.stop().unwrap();
+    }
+
+    #[test]
+    fn cyclic_obs_snapshot_and_restore() {
+        let mut container = boot_container(None);
+        load_with(&mut container, r#"{ "stopOn": 0, "cycleTime": 5, "realTime": false }"#, &scheduled_program());
+
+        pollster::block_on(container.step("Main", 3)).unwrap();
+        assert_eq!(scheduled_memory(&container), (103, 1));
+        let checkpoint = container.snapshot().unwrap();
+        container.stop().unwrap();
+
+        // Neither the startup Ob nor an early tick run after the restore
+        container.restore(&checkpoint).unwrap();
+        pollster::block_on(container.step("Main", 1)).unwrap();
+        assert_eq!(scheduled_memory(&container), (104, 1));
+        pollster::block_on(container.step("Main", 1)).unwrap();
+        assert_eq!(scheduled_memory(&container), (105, 2));
+        container.stop().unwrap();
+    }
+
+    #[test]
+    fn params_fields_are_optional() {
+        let params: ContainerParams = serde_json::from_str(r#"{ "stopAfter": 10 }"#).unwrap();
+        let default = ContainerParams::default();
+        assert_eq!(params.stopAfter, 10);
+        assert_eq!(params.cycleTime, default.cycleTime);
+        assert_eq!(params.realTime, default.realTime);
+        assert_eq!(params.startTime, default.startTime);
+        assert_eq!(params.timeZone, default.timeZone);
+    }
+
+    #[test]
+    fn run_until_stops() {
+        // The unit test is reached on the first cycle
+        let mut container = boot_container(None);
+        load_with(&mut container, r#"{ "stopOn": 1, "realTime": false }"#, PROGRAM);
+        let outcome = pollster::block_on(container.run_until("Main", 10, |_| false)).unwrap();
+        assert_eq!(outcome, RunUntil::Stopped);
+        assert_eq!(container.get_cycle(), 1);
+
+        // Int overflow stops the cycle with an error and ends the simulation
+        let program = fixtures::program(
+            r#"{ "big": { "ty": "Int", "src": { "id": 2, "value": 32767 } } }"#,
+            &format!("[{}]", fixtures::assign(3, "big", r#"{ "ty": "calc", "src": { "id": 4, "operator": "+", "calc": { "ty": "local_out", "src": { "path": ["Data", "big"] } }, "with": { "ty": "Int", "src": { "id": 5, "value": 1 } } } }"#)),
+        );
+        let mut container = fixtures::load("{}", &program).unwrap();
+        assert!(pollster::block_on(container.run_until("Main", 10, |_| false)).is_err());
+        assert!(!container.is_running());
+    }
+}
@@ -0,0 +1,67 @@
+#[cfg(test)]
+mod tests {
+    use crate::tests::common::fixtures::{assign, load, program, read, variable};
+    use crate::kernel::rust::convert::{convert_value, ConvertKind, ConvertValue, Rounding};
+
+    const STATICS: &str = r#"{
+        "big": { "ty": "DInt", "src": { "id": 2, "value": 70000 } },
+        "half": { "ty": "Real", "src": { "id": 3, "value": 2.5 } },
+        "mask": { "ty": "Word", "src": { "id": 4, "value": 65535 } },
+        "as_real": { "ty": "Real", "src": { "id": 5 } },
+        "as_dint": { "ty": "DInt", "src": { "id": 6 } },
+        "as_int": { "ty": "Int", "src": { "id": 7 } },
+        "as_time": { "ty": "Time", "src": { "id": 8 } }
+    }"#;
+
+    fn convert(id: u32, to: &str, name: &str) -> String {
+        format!(r#"{{ "ty": "convert", "src": {{ "id": {}, "to": {}, "convert": {} }} }}"#, id, to, variable(name))
+    }
+
+    /// Converts the families into each other, `big` is converted to `time_to`
+    fn body(time_to: &str) -> String {
+        format!("[{}, {}, {}, {}]",
+            assign(11, "as_real", &convert(12, r#""Real""#, "big")),
+            assign(13, "as_dint", &convert(14, r#""DInt""#, "half")),
+            assign(15, "as_int", &convert(16, r#""Int""#, "mask")),
+            assign(17, "as_time", &convert(18, time_to, "big")),
+        )
+    }
+
+    #[test]
+    fn converts_between_families() {
+        let mut container = load("{}", &program(STATICS, &body(r#""Time""#))).unwrap();
+        pollster::block_on(container.step("Main", 1)).unwrap();
+
+        assert_eq!(read(&container, "as_real").as_f64(), Some(70000.0));
+        // Halfway cases are rounded to the nearest even integer
+        assert_eq!(read(&container, "as_dint").as_i64(), Some(2));
+        // Bit strings are transferred as is
+        assert_eq!(read(&container, "as_int").as_i64(), Some(-1));
+        assert_eq!(read(&container, "as_time").as_i64(), Some(70000));
+        container.stop().unwrap();
+    }
+
+    #[test]
+    fn lossy_conversion_stops_with_operation_id() {
+        let mut container = load("{}", &program(STATICS, &body(r#""Int""#))).unwrap();
+        let error = pollster::block_on(container.step("Main", 1)).unwrap_err();
+        let error = serde_json::to_value(&error).unwrap();
+        assert!(error["id_stack"].as_array().unwrap().contains(&serde_json::Value::from(18)));
+
+        // Unchecked conversions keep the lowest bits, 70000 = 0x11170
+        let statics = STATICS.replace(r#""as_time": { "ty": "Time""#, r#""as_time": { "ty": "Int""#);
+        let mut container = load("{}", &program(&statics, &body(r#""Int", "checked": false"#))).unwrap();
+        pollster::block_on(container.step("Main", 1)).unwrap();
+        assert_eq!(read(&container, "as_time").as_i64(), Some(4464));
+        container.stop().unwrap();
+    }
+
+    #[test]
+    fn time_conversion_drops_sub_millisecond() {
+        let ltime = ConvertKind::Time { bits: 64, unit: 1 };
+        let time = ConvertKind::Time { bits: 32, unit: 1_000_000 };
+        assert_eq!(convert_value(ConvertValue::Time(2_000_000), ltime, time, Rounding::Round), (ConvertValue::Time(2), true));
+        assert_eq!(convert_value(ConvertValue::Time(1_500_000), ltime, time, Rounding::Round), (ConvertValue::Time(1), false));
+        assert_eq!(convert_value(ConvertValue::Time(3), time, ltime, Rounding::Round), (ConvertValue::Time(3_000_000), true));
+    }
+}
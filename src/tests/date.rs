@@ -0,0 +1,129 @@
+#[cfg(test)]
+mod tests {
+    use crate::tests::common::fixtures::{assign, assign_to, load, program, read, variable};
+
+    #[test]
+    fn date_and_time_arithmetic() {
+        let statics = r#"{
+            "dt": { "ty": "Dt", "src": { "id": 2, "value": "DT#2024-05-01-12:00:00" } },
+            "dt2": { "ty": "Dt", "src": { "id": 3 } },
+            "elapsed": { "ty": "Time", "src": { "id": 4 } },
+            "day": { "ty": "Date", "src": { "id": 5, "value": "D#2024-05-02" } },
+            "days": { "ty": "Time", "src": { "id": 6 } },
+            "later": { "ty": "Bool", "src": { "id": 7 } },
+            "delay": { "ty": "S5Time", "src": { "id": 9, "value": 10000 } }
+        }"#;
+        let body = format!("[{}, {}, {}, {}]",
+            assign(10, "dt2", &format!(r#"{{ "ty": "calc", "src": {{ "id": 11, "calc": {}, "with": {{ "ty": "Time", "src": {{ "id": 12, "value": 90000 }} }}, "operator": "+" }} }}"#,
+                variable("dt"))),
+            assign(20, "elapsed", &format!(r#"{{ "ty": "calc", "src": {{ "id": 21, "calc": {}, "with": {}, "operator": "-" }} }}"#,
+                variable("dt2"), variable("dt"))),
+            assign(30, "days", &format!(r#"{{ "ty": "calc", "src": {{ "id": 31, "calc": {}, "with": {{ "ty": "Date", "src": {{ "id": 32, "value": "2024-05-01" }} }}, "operator": "-" }} }}"#,
+                variable("day"))),
+            assign(40, "later", &format!(r#"{{ "ty": "compare", "src": {{ "id": 41, "compare": {}, "with": {}, "operator": ">" }} }}"#,
+                variable("dt2"), variable("dt"))),
+        );
+
+        let mut container = load("{}", &program(statics, &body)).unwrap();
+        pollster::block_on(container.step("Main", 1)).unwrap();
+        assert_eq!(read(&container, "elapsed").as_i64(), Some(90000));
+        assert_eq!(read(&container, "days").as_i64(), Some(86_400_000));
+        assert_eq!(read(&container, "later").as_bool(), Some(true));
+        assert_eq!(read(&container, "delay").as_u64(), Some(10000));
+        container.stop().unwrap();
+
+        let s5time = r#"{ "delay": { "ty": "S5Time", "src": { "id": 2, "value": 9990001 } } }"#;
+        assert!(load("{}", &program(s5time, "[]")).is_err());
+        let date = r#"{ "day": { "ty": "Date", "src": { "id": 2, "value": "2024-02-30" } } }"#;
+        assert!(load("{}", &program(date, "[]")).is_err());
+    }
+
+    #[test]
+    fn s5time_range_and_mixing() {
+        let statics = r#"{
+            "delay": { "ty": "S5Time", "src": { "id": 2, "value": 9900000 } },
+            "extra": { "ty": "Time", "src": { "id": 3, "value": 100000 } },
+            "sum": { "ty": "S5Time", "src": { "id": 4 } },
+            "longer": { "ty": "Bool", "src": { "id": 5 } },
+            "longest": { "ty": "Time", "src": { "id": 6 } },
+            "as_time": { "ty": "Time", "src": { "id": 7 } }
+        }"#;
+        let body = format!("[{}, {}, {}]",
+            assign(10, "longer", &format!(r#"{{ "ty": "compare", "src": {{ "id": 11, "compare": {}, "with": {}, "operator": ">" }} }}"#,
+                variable("delay"), variable("extra"))),
+            assign(20, "longest", &format!(r#"{{ "ty": "max", "src": {{ "id": 21, "max": [{}, {}] }} }}"#,
+                variable("extra"), variable("delay"))),
+            assign(30, "as_time", &format!(r#"{{ "ty": "convert", "src": {{ "id": 31, "to": "Time", "convert": {} }} }}"#,
+                variable("delay"))),
+        );
+
+        let mut container = load("{}", &program(statics, &body)).unwrap();
+        pollster::block_on(container.step("Main", 1)).unwrap();
+        assert_eq!(read(&container, "longer").as_bool(), Some(true));
+        assert_eq!(read(&container, "longest").as_i64(), Some(9_900_000));
+        assert_eq!(read(&container, "as_time").as_i64(), Some(9_900_000));
+        container.stop().unwrap();
+
+        // S5T#2h45m + T#100s is past S5T#2h46m30s
+        let body = format!("[{}]",
+            assign(10, "sum", &format!(r#"{{ "ty": "calc", "src": {{ "id": 11, "calc": {}, "with": {}, "operator": "+" }} }}"#,
+                variable("delay"), variable("extra"))));
+        let mut container = load("{}", &program(statics, &body)).unwrap();
+        let error = pollster::block_on(container.step("Main", 1)).unwrap_err();
+        assert!(format!("{}", error).contains("out of range"));
+        container.stop().unwrap();
+    }
+
+    #[test]
+    fn date_ranges_and_resolution() {
+        let date = |value: &str| format!(r#"{{ "day": {{ "ty": "Date", "src": {{ "id": 2, "value": {value} }} }} }}"#);
+        assert!(load("{}", &program(&date(r#""D#2168-12-31""#), "[]")).is_ok());
+        assert!(load("{}", &program(&date("65378"), "[]")).is_ok());
+        assert!(load("{}", &program(&date(r#""D#2169-01-01""#), "[]")).err().unwrap().contains("D#1990-01-01..D#2168-12-31"));
+        assert!(load("{}", &program(&date("65379"), "[]")).is_err());
+
+        // A DT has no digit below the ms
+        let dt = |value: &str| format!(r#"{{ "dt": {{ "ty": "Dt", "src": {{ "id": 2, "value": {value} }} }} }}"#);
+        assert!(load("{}", &program(&dt(r#""DT#2024-05-01-12:00:00.250""#), "[]")).is_ok());
+        assert!(load("{}", &program(&dt(r#""DT#2024-05-01-12:00:00.2505""#), "[]")).err().unwrap().contains("more precise than the ms"));
+        assert!(load("{}", &program(&dt("1"), "[]")).is_err());
+
+        // Past D#2168-12-31 and below the ms, results are out of range or truncated
+        let statics = r#"{
+            "dt": { "ty": "Dt", "src": { "id": 2, "value": "DT#2024-05-01-12:00:00" } },
+            "dt2": { "ty": "Dt", "src": { "id": 3 } },
+            "day": { "ty": "Date", "src": { "id": 5, "value": "D#2168-12-31" } },
+            "next": { "ty": "Date", "src": { "id": 6 } }
+        }"#;
+        let body = format!("[{}]",
+            assign(10, "dt2", &format!(r#"{{ "ty": "calc", "src": {{ "id": 11, "calc": {}, "with": {{ "ty": "LTime", "src": {{ "id": 12, "value": 1500000 }} }}, "operator": "+" }} }}"#,
+                variable("dt"))));
+        let mut container = load("{}", &program(statics, &body)).unwrap();
+        pollster::block_on(container.step("Main", 1)).unwrap();
+        assert_eq!(read(&container, "dt2").as_i64().unwrap() - read(&container, "dt").as_i64().unwrap(), 1_000_000);
+        container.stop().unwrap();
+
+        let body = format!("[{}]",
+            assign(10, "next", &format!(r#"{{ "ty": "calc", "src": {{ "id": 11, "calc": {}, "with": {{ "ty": "Time", "src": {{ "id": 12, "value": 86400000 }} }}, "operator": "+" }} }}"#,
+                variable("day"))));
+        let mut container = load("{}", &program(statics, &body)).unwrap();
+        assert!(pollster::block_on(container.step("Main", 1)).is_err());
+        container.stop().unwrap();
+    }
+
+    #[test]
+    fn dtl_members_are_rejected() {
+        let statics = r#"{
+            "stamp": { "ty": "Dtl", "src": { "id": 2, "value": "DTL#2024-05-01-12:00:00" } },
+            "hour": { "ty": "USInt", "src": { "id": 3 } }
+        }"#;
+        let body = format!("[{}]",
+            assign(10, "hour", r#"{ "ty": "local_out", "src": { "path": ["Data", "stamp", "HOUR"] } }"#));
+        let error = load("{}", &program(statics, &body)).err().unwrap();
+        assert!(error.contains("HOUR of Data.stamp is not supported"), "{}", error);
+
+        let body = format!("[{}]",
+            assign_to(10, r#"{ "ty": "local_out", "src": { "path": ["Data", "stamp", "HOUR"] } }"#, &variable("hour")));
+        assert!(load("{}", &program(statics, &body)).err().unwrap().contains("a DTL is a single date and time"));
+    }
+}
@@ -0,0 +1,112 @@
+#[cfg(test)]
+mod tests {
+    use crate::tests::common::fixtures::{assign, load, program, read, variable};
+    use crate::kernel::plc::types::enumeration::PlcEnum;
+
+    const PROVIDER: &str = r#"{ "enums": { "Fruit": { "values": { "Apple": 0, "Pear": 1 } } } }"#;
+
+    const STATICS: &str = r#"{
+        "color": { "ty": "enum", "src": { "of": "Color", "id": 2 } },
+        "raw": { "ty": "Int", "src": { "id": 3, "value": 4 } },
+        "picked": { "ty": "Int", "src": { "id": 4 } },
+        "same": { "ty": "Bool", "src": { "id": 5 } }
+    }"#;
+
+    /// Common program with the `Color` enumeration declared next to the blocks.
+    fn with_color(body: &str) -> String {
+        program(STATICS, body).replacen('{', r#"{ "enums": { "Color": { "of": "USInt", "values": { "Red": 0, "Green": 1, "Blue": 4 } } },"#, 1)
+    }
+
+    #[test]
+    fn enumerated_types() {
+        let color = PlcEnum::from_json("Color", &serde_json::json!({ "values": { "Red": 0, "Green": 1 }, "default": "Green" })).unwrap();
+        assert_eq!(color.value_of("Color#Green"), Some(1));
+        assert_eq!(color.value_of("Fruit#Green"), None);
+        assert_eq!(color.name_of(0), Some("Red"));
+        assert!(color.parse_value(&serde_json::json!(3)).is_err());
+        assert!(PlcEnum::from_json("Color", &serde_json::json!({ "values": { "Red": 0, "Green": 0 } })).is_err());
+        assert!(PlcEnum::from_json("Color", &serde_json::json!({ "of": "USInt", "values": { "Red": -1 } })).is_err());
+
+        let body = format!(r#"[{}, {}, {{
+            "ty": "case",
+            "src": {{
+                "id": 20,
+                "case": {},
+                "of": [
+                    {{ "values": ["Red"], "then": [{}] }},
+                    {{ "values": ["Color#Green"], "then": [{}] }}
+                ]
+            }}
+        }}, {}]"#,
+            assign(10, "same", &format!(r#"{{ "ty": "compare", "src": {{ "id": 11, "compare": {}, "with": {{ "ty": "enum", "src": {{ "of": "Color", "id": 12, "value": "Red" }} }}, "operator": "=" }} }}"#,
+                variable("color"))),
+            assign(13, "color", r#"{ "ty": "Implicit", "src": { "id": 14, "value": "Green" } }"#),
+            variable("color"),
+            assign(21, "picked", r#"{ "ty": "Int", "src": { "id": 22, "value": 1 } }"#),
+            assign(23, "picked", r#"{ "ty": "Int", "src": { "id": 24, "value": 2 } }"#),
+            assign(30, "color", &variable("raw")),
+        );
+
+        let mut container = load(PROVIDER, &with_color(&body)).unwrap();
+        assert_eq!(read(&container, "color").as_u64(), Some(0));
+        pollster::block_on(container.step("Main", 1)).unwrap();
+        assert_eq!(read(&container, "same").as_bool(), Some(true));
+        assert_eq!(read(&container, "picked").as_i64(), Some(2));
+        assert_eq!(read(&container, "color").as_u64(), Some(4));
+
+        container.write(vec!["Data".into(), "raw".into()], r#"{ "ty": "Int", "src": { "value": 3 } }"#).unwrap();
+        let error = pollster::block_on(container.step("Main", 1)).unwrap_err();
+        assert!(format!("{}", error).contains("3 is not a value of Color"));
+        container.stop().unwrap();
+
+        // Values of another enumeration can not be assigned
+        let fruit = format!("[{}]", assign(10, "color", r#"{ "ty": "enum", "src": { "of": "Fruit", "id": 11, "value": "Pear" } }"#));
+        assert!(load(PROVIDER, &with_color(&fruit)).err().unwrap().contains("Can not mix"));
+        let unknown = format!("[{}]", assign(10, "color", r#"{ "ty": "Implicit", "src": { "id": 11, "value": "Purple" } }"#));
+        assert!(load(PROVIDER, &with_color(&unknown)).is_err());
+    }
+
+    #[test]
+    fn writes_and_parameters_are_checked() {
+        let mut container = load(PROVIDER, &with_color("[]")).unwrap();
+        let path = || vec!["Data".to_string(), "color".to_string()];
+        let error = container.write(path(), r#"{ "ty": "USInt", "src": { "value": 3 } }"#).unwrap_err();
+        assert!(format!("{}", error).contains("3 is not a value of Color"));
+        assert!(container.force(path(), r#"{ "ty": "USInt", "src": { "value": 3 } }"#).is_err());
+        assert!(!container.is_forced(path()));
+
+        container.force(path(), r#"{ "ty": "USInt", "src": { "value": 4 } }"#).unwrap();
+        assert_eq!(read(&container, "color").as_u64(), Some(4));
+
+        // The input of a call is an assignment as well
+        let paint = r#""file:///Paint": {
+            "ty": "fc",
+            "src": {
+                "id": 50,
+                "interface": { "ty": "interface", "src": { "input": { "c": { "ty": "enum", "src": { "of": "Color", "id": 51 } } } } },
+                "body": []
+            }
+        },
+        "file:///Data""#;
+        let call = |value: &str| format!(r#"[{{
+            "ty": "call",
+            "src": {{
+                "id": 10,
+                "interface": {{ "ty": "call_interface", "src": {{ "input": {{ "c": {} }} }} }},
+                "call": {{ "ty": "global", "src": {{ "path": ["Paint"] }} }}
+            }}
+        }}]"#, value);
+
+        let mut container = load(PROVIDER, &with_color(&call(&variable("raw"))).replacen(r#""file:///Data""#, paint, 1)).unwrap();
+        pollster::block_on(container.step("Main", 1)).unwrap();
+        container.write(vec!["Data".into(), "raw".into()], r#"{ "ty": "Int", "src": { "value": 3 } }"#).unwrap();
+        let error = pollster::block_on(container.step("Main", 1)).unwrap_err();
+        assert!(format!("{}", error).contains("3 is not a value of Color"));
+        container.stop().unwrap();
+
+        let green = r#"{ "ty": "Implicit", "src": { "id": 11, "value": "Green" } }"#;
+        let mut container = load(PROVIDER, &with_color(&call(green)).replacen(r#""file:///Data""#, paint, 1)).unwrap();
+        pollster::block_on(container.step("Main", 1)).unwrap();
+        container.stop().unwrap();
+    }
+}
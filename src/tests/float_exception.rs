@@ -0,0 +1,160 @@
+#[cfg(test)]
+mod tests {
+    use crate::tests::common::fixtures::{assign, load, program, read, variable};
+    use crate::kernel::rust::float_exception::{FloatException, FloatSpecial};
+
+    const PROGRAM: &str = r#"
+    {
+        "file:///Data": {
+            "ty": "global_db",
+            "src": {
+                "id": 1,
+                "interface": {
+                    "ty": "interface",
+                    "src": {
+                        "static": {
+                            "zero": { "ty": "Real", "src": { "id": 2, "value": 0.0 } },
+                            "one": { "ty": "Real", "src": { "id": 3, "value": 1.0 } },
+                            "ratio": { "ty": "Real", "src": { "id": 4 } },
+                            "shifted": { "ty": "Real", "src": { "id": 5 } },
+                            "root": { "ty": "LReal", "src": { "id": 6 } },
+                            "minus": { "ty": "LReal", "src": { "id": 7, "value": -1.0 } }
+                        }
+                    }
+                }
+            }
+        },
+        "file:///Main": {
+            "ty": "ob",
+            "src": {
+                "id": 10,
+                "interface": { "ty": "interface", "src": {} },
+                "body": [
+                    {
+                        "ty": "asg",
+                        "src": {
+                            "id": 11,
+                            "assign": { "ty": "local_out", "src": { "path": ["Data", "ratio"] } },
+                            "to": {
+                                "ty": "calc",
+                                "src": {
+                                    "id": 12,
+                                    "calc": { "ty": "local_out", "src": { "path": ["Data", "one"] } },
+                                    "with": { "ty": "local_out", "src": { "path": ["Data", "zero"] } },
+                                    "operator": "/"
+                                }
+                            }
+                        }
+                    },
+                    {
+                        "ty": "asg",
+                        "src": {
+                            "id": 13,
+                            "assign": { "ty": "local_out", "src": { "path": ["Data", "shifted"] } },
+                            "to": {
+                                "ty": "calc",
+                                "src": {
+                                    "id": 14,
+                                    "calc": { "ty": "local_out", "src": { "path": ["Data", "ratio"] } },
+                                    "with": { "ty": "local_out", "src": { "path": ["Data", "one"] } },
+                                    "operator": "-"
+                                }
+                            }
+                        }
+                    },
+                    {
+                        "ty": "asg",
+                        "src": {
+                            "id": 15,
+                            "assign": { "ty": "local_out", "src": { "path": ["Data", "root"] } },
+                            "to": { "ty": "sqrt", "src": { "id": 16, "sqrt": { "ty": "local_out", "src": { "path": ["Data", "minus"] } } } }
+                        }
+                    }
+                ]
+            }
+        }
+    }"#;
+
+    #[test]
+    fn float_exceptions_policy() {
+        // The infinity propagated by 14 is only reported where it appeared
+        let mut container = load(r#"{ "float_exceptions": "report" }"#, PROGRAM).unwrap();
+        pollster::block_on(container.step("Main", 1)).unwrap();
+        assert_eq!(container.get_float_report(), vec![
+            FloatException { value: FloatSpecial::Infinity, id: 12 },
+            FloatException { value: FloatSpecial::NaN, id: 16 },
+        ]);
+        container.stop().unwrap();
+
+        let mut container = load(r#"{ "float_exceptions": "propagate" }"#, PROGRAM).unwrap();
+        pollster::block_on(container.step("Main", 1)).unwrap();
+        assert!(container.get_float_report().is_empty());
+        container.stop().unwrap();
+
+        // Stops on the first special value by default
+        let mut container = load("{}", PROGRAM).unwrap();
+        let error = pollster::block_on(container.step("Main", 1)).unwrap_err();
+        let error = serde_json::to_value(&error).unwrap();
+        assert!(error["id_stack"].as_array().unwrap().contains(&serde_json::Value::from(12)));
+        container.stop().unwrap();
+
+        assert!(load(r#"{ "float_exceptions": "ignore" }"#, PROGRAM).err().unwrap().contains("Invalid float exceptions ignore"));
+    }
+
+    #[test]
+    fn propagated_nan_comparisons() {
+        let statics = r#"{
+            "zero": { "ty": "Real", "src": { "id": 2, "value": 0.0 } },
+            "nan": { "ty": "Real", "src": { "id": 3 } },
+            "isnan": { "ty": "Bool", "src": { "id": 4 } },
+            "equal": { "ty": "Bool", "src": { "id": 5 } },
+            "less": { "ty": "Bool", "src": { "id": 6 } },
+            "greater_or_equal": { "ty": "Bool", "src": { "id": 7, "value": true } }
+        }"#;
+        let compare = |id: u32, with: &str, operator: &str| format!(r#"{{ "ty": "compare", "src": {{ "id": {}, "compare": {}, "with": {}, "operator": "{}" }} }}"#,
+            id, variable("nan"), variable(with), operator);
+        let body = format!("[{}, {}, {}, {}, {}]",
+            assign(10, "nan", &format!(r#"{{ "ty": "calc", "src": {{ "id": 11, "calc": {}, "with": {}, "operator": "/" }} }}"#,
+                variable("zero"), variable("zero"))),
+            assign(12, "isnan", &compare(13, "nan", "<>")),
+            assign(14, "equal", &compare(15, "nan", "=")),
+            assign(16, "less", &compare(17, "zero", "<")),
+            assign(18, "greater_or_equal", &compare(19, "zero", ">=")),
+        );
+
+        // An unordered comparison is false, except <> which is true
+        let mut container = load(r#"{ "float_exceptions": "propagate" }"#, &program(statics, &body)).unwrap();
+        pollster::block_on(container.step("Main", 1)).unwrap();
+        assert_eq!(read(&container, "isnan").as_bool(), Some(true));
+        assert_eq!(read(&container, "equal").as_bool(), Some(false));
+        assert_eq!(read(&container, "less").as_bool(), Some(false));
+        assert_eq!(read(&container, "greater_or_equal").as_bool(), Some(false));
+        container.stop().unwrap();
+    }
+
+    #[test]
+    fn snapshot_keeps_infinities() {
+        let statics = r#"{
+            "zero": { "ty": "Real", "src": { "id": 2, "value": 0.0 } },
+            "one": { "ty": "Real", "src": { "id": 3, "value": 1.0 } },
+            "positive": { "ty": "Real", "src": { "id": 4 } },
+            "negative": { "ty": "LReal", "src": { "id": 5 } }
+        }"#;
+        let divide = |id: u32, calc: &str| format!(r#"{{ "ty": "calc", "src": {{ "id": {}, "calc": {}, "with": {}, "operator": "/" }} }}"#,
+            id, calc, variable("zero"));
+        let body = format!("[{}, {}]",
+            assign(10, "positive", &divide(11, &variable("one"))),
+            assign(12, "negative", &divide(13, r#"{ "ty": "LReal", "src": { "id": 14, "value": -1.0 } }"#)),
+        );
+
+        let mut container = load(r#"{ "float_exceptions": "propagate" }"#, &program(statics, &body)).unwrap();
+        pollster::block_on(container.step("Main", 1)).unwrap();
+        let checkpoint = container.snapshot().unwrap();
+        container.stop().unwrap();
+        assert_eq!(read(&container, "positive"), serde_json::json!(0.0));
+
+        container.restore(&checkpoint).unwrap();
+        assert_eq!(read(&container, "positive").as_str(), Some("Inf"));
+        assert_eq!(read(&container, "negative").as_str(), Some("-Inf"));
+    }
+}
@@ -1,6 +1,23 @@
+mod common;
 mod meta;
 mod pou;
 mod exclude;
 mod operations;
 mod monitor;
-mod reset;
\ No newline at end of file
+mod reset;
+mod report;
+mod container;
+mod convert;
+mod program_control;
+mod string;
+mod select;
+mod scale;
+mod date;
+mod clock;
+mod enumeration;
+mod array;
+mod bitwise;
+mod slice_access;
+mod timer;
+mod overflow;
+mod float_exception;
@@ -0,0 +1,136 @@
+#[cfg(test)]
+mod tests {
+    use crate::tests::common::fixtures::{assign, load, program, read, variable};
+
+    const PROGRAM: &str = r##"
+    {
+        "file:///Data": {
+            "ty": "global_db",
+            "src": {
+                "id": 1,
+                "interface": {
+                    "ty": "interface",
+                    "src": {
+                        "static": {
+                            "big": { "ty": "Int", "src": { "id": 2, "value": 32767 } },
+                            "sum": { "ty": "Int", "src": { "id": 3 } },
+                            "mask": { "ty": "Byte", "src": { "id": 4, "value": 250 } },
+                            "masked": { "ty": "Byte", "src": { "id": 5 } },
+                            "count": { "ty": "SInt", "src": { "id": 6, "value": 127 } },
+                            "preset": { "ty": "SInt", "src": { "id": 7 } },
+                            "idle": { "ty": "Bool", "src": { "id": 8 } },
+                            "small": { "ty": "USInt", "src": { "id": 9 } }
+                        }
+                    }
+                }
+            }
+        },
+        "file:///Main": {
+            "ty": "ob",
+            "src": {
+                "id": 10,
+                "interface": { "ty": "interface", "src": {} },
+                "body": [
+                    {
+                        "ty": "asg",
+                        "src": {
+                            "id": 11,
+                            "assign": { "ty": "local_out", "src": { "path": ["Data", "sum"] } },
+                            "to": {
+                                "ty": "calc",
+                                "src": {
+                                    "id": 12,
+                                    "calc": { "ty": "local_out", "src": { "path": ["Data", "big"] } },
+                                    "with": { "ty": "Int", "src": { "id": 13, "value": 1 } },
+                                    "operator": "+"
+                                }
+                            }
+                        }
+                    },
+                    {
+                        "ty": "asg",
+                        "src": {
+                            "id": 14,
+                            "assign": { "ty": "local_out", "src": { "path": ["Data", "masked"] } },
+                            "to": {
+                                "ty": "calc",
+                                "src": {
+                                    "id": 15,
+                                    "calc": { "ty": "local_out", "src": { "path": ["Data", "mask"] } },
+                                    "with": { "ty": "Byte", "src": { "id": 16, "value": 10 } },
+                                    "operator": "+"
+                                }
+                            }
+                        }
+                    },
+                    {
+                        "ty": "#counter_sm",
+                        "src": {
+                            "id": 17,
+                            "increment": { "ty": "not", "src": { "id": 20, "not": { "ty": "local_out", "src": { "path": ["Data", "idle"] } } } },
+                            "preset_var": { "ty": "local_out", "src": { "path": ["Data", "preset"] } },
+                            "counter_var": { "ty": "local_out", "src": { "path": ["Data", "count"] } },
+                            "on_counter_up": [],
+                            "on_counter_down": [],
+                            "on_counter_reset": []
+                        }
+                    },
+                    {
+                        "ty": "asg",
+                        "src": {
+                            "id": 18,
+                            "assign": { "ty": "local_out", "src": { "path": ["Data", "small"] } },
+                            "to": { "ty": "convert", "src": { "id": 19, "to": "USInt", "convert": { "ty": "local_out", "src": { "path": ["Data", "big"] } } } }
+                        }
+                    }
+                ]
+            }
+        }
+    }"##;
+
+    #[test]
+    fn overflow_follows_the_provider() {
+        let mut container = load(r#"{ "overflow": { "AnySignedInteger": "wrap", "AnyBinary": "saturate", "USInt": "saturate" } }"#, PROGRAM).unwrap();
+        pollster::block_on(container.step("Main", 1)).unwrap();
+        assert_eq!(read(&container, "sum").as_i64(), Some(-32768));
+        assert_eq!(read(&container, "masked").as_u64(), Some(255));
+        assert_eq!(read(&container, "count").as_i64(), Some(-128));
+        assert_eq!(read(&container, "small").as_u64(), Some(255));
+        assert_eq!(container.get_overflow_report(), vec![12, 15, 17, 19]);
+        container.stop().unwrap();
+
+        // Without overflow the simulation stops on the operation
+        let mut container = load("{}", PROGRAM).unwrap();
+        let error = pollster::block_on(container.step("Main", 1)).unwrap_err();
+        let error = serde_json::to_value(&error).unwrap();
+        assert!(error["id_stack"].as_array().unwrap().contains(&serde_json::Value::from(12)));
+        container.stop().unwrap();
+
+        assert!(load(r#"{ "overflow": { "Int": "clamp" } }"#, PROGRAM).err().unwrap().contains("Invalid overflow clamp"));
+    }
+
+    #[test]
+    fn overflow_follows_the_result_type() {
+        let statics = r#"{
+            "big": { "ty": "Int", "src": { "id": 2, "value": 32767 } },
+            "wide": { "ty": "DInt", "src": { "id": 3 } },
+            "narrow": { "ty": "SInt", "src": { "id": 4 } }
+        }"#;
+        let add = |id: u32| format!(r#"{{ "ty": "calc", "src": {{ "id": {}, "calc": {}, "with": {{ "ty": "Int", "src": {{ "id": {}, "value": 1 }} }}, "operator": "+" }} }}"#, id, variable("big"), id + 1);
+
+        // INT + INT is computed in the type of the result
+        let provider = r#"{ "override_return": { "add": { "DInt": [["Int", "Int"]] } }, "overflow": { "DInt": "saturate" } }"#;
+        let mut container = load(provider, &program(statics, &format!("[{}]", assign(11, "wide", &add(12))))).unwrap();
+        pollster::block_on(container.step("Main", 1)).unwrap();
+        assert_eq!(read(&container, "wide").as_i64(), Some(32768));
+        assert!(container.get_overflow_report().is_empty());
+        container.stop().unwrap();
+
+        let provider = r#"{ "override_return": { "add": { "SInt": [["Int", "Int"]] } }, "overflow": { "SInt": "saturate" } }"#;
+        let mut container = load(provider, &program(statics, &format!("[{}]", assign(14, "narrow", &add(15))))).unwrap();
+        pollster::block_on(container.step("Main", 1)).unwrap();
+        assert_eq!(read(&container, "narrow").as_i64(), Some(127));
+        assert_eq!(container.get_overflow_report(), vec![15]);
+        container.stop().unwrap();
+    }
+}
@@ -0,0 +1,156 @@
+#[cfg(test)]
+mod tests {
+    use crate::container::container::{boot_container, Container};
+
+    fn program(body: &str) -> String {
+        r#"
+        {
+            "file:///Data": {
+                "ty": "global_db",
+                "src": {
+                    "id": 1,
+                    "interface": {
+                        "ty": "interface",
+                        "src": {
+                            "static": {
+                                "selector": { "ty": "Int", "src": { "id": 2, "value": 0 } },
+                                "result": { "ty": "Int", "src": { "id": 3, "value": 0 } }
+                            }
+                        }
+                    }
+                }
+            },
+            "file:///Main": {
+                "ty": "ob",
+                "src": {
+                    "id": 4,
+                    "interface": { "ty": "interface", "src": {} },
+                    "body": BODY
+                }
+            }
+        }"#.replace("BODY", body)
+    }
+
+    fn set_result(id: u32, value: i64) -> String {
+        format!(r#"{{
+            "ty": "asg",
+            "src": {{
+                "id": {},
+                "assign": {{ "ty": "local_out", "src": {{ "path": ["Data", "result"] }} }},
+                "to": {{ "ty": "Int", "src": {{ "id": {}, "value": {} }} }}
+            }}
+        }}"#, id, id + 1, value)
+    }
+
+    fn load(program: &str) -> Container {
+        let mut container = boot_container(None);
+        container.load_server_params(r#"{ "stopOn": 0, "realTime": false }"#).unwrap();
+        container.load_provider("{}").unwrap();
+        container.load_program(program).unwrap();
+        container
+    }
+
+    fn read(container: &Container, name: &str) -> i64 {
+        let snapshot: serde_json::Value = serde_json::from_str(&container.snapshot().unwrap()).unwrap();
+        snapshot["memory"]["Data"][name].as_i64().unwrap()
+    }
+
+    #[test]
+    fn case_selects_branch() {
+        let body = format!(r#"[{{
+            "ty": "case",
+            "src": {{
+                "id": 10,
+                "case": {{ "ty": "local_out", "src": {{ "path": ["Data", "selector"] }} }},
+                "of": [
+                    {{ "values": [1], "then": [{}] }},
+                    {{ "values": [2, {{ "from": 5, "to": 7 }}], "then": [{}] }}
+                ],
+                "_else": [{}]
+            }}
+        }}]"#, set_result(20, 10), set_result(30, 20), set_result(40, 99));
+        let mut container = load(&program(&body));
+
+        for (selector, expected) in [(1, 10), (2, 20), (6, 20), (7, 20), (3, 99), (-1, 99)] {
+            container.write(vec!["Data".into(), "selector".into()], &format!(r#"{{ "ty": "Int", "src": {{ "value": {} }} }}"#, selector)).unwrap();
+            pollster::block_on(container.step("Main", 1)).unwrap();
+            assert_eq!(read(&container, "result"), expected, "selector {}", selector);
+        }
+        container.stop().unwrap();
+
+        let invalid = body.replace(r#"{ "from": 5, "to": 7 }"#, r#"{ "from": 7, "to": 5 }"#);
+        assert!(boot_container(None).load_program(&program(&invalid)).is_err());
+    }
+
+    fn increment(id: u32, name: &str) -> String {
+        format!(r#"{{
+            "ty": "asg",
+            "src": {{
+                "id": {id},
+                "assign": {{ "ty": "local_out", "src": {{ "path": ["Data", "{name}"] }} }},
+                "to": {{
+                    "ty": "calc",
+                    "src": {{
+                        "id": {},
+                        "operator": "+",
+                        "calc": {{ "ty": "local_out", "src": {{ "path": ["Data", "{name}"] }} }},
+                        "with": {{ "ty": "Int", "src": {{ "id": {}, "value": 1 }} }}
+                    }}
+                }}
+            }}
+        }}"#, id + 1, id + 2)
+    }
+
+    fn when_selector(id: u32, operator: &str, value: i64, then: &str) -> String {
+        format!(r#"{{
+            "ty": "if",
+            "src": {{
+                "id": {id},
+                "_if": {{
+                    "ty": "compare",
+                    "src": {{
+                        "id": {},
+                        "operator": "{operator}",
+                        "compare": {{ "ty": "local_out", "src": {{ "path": ["Data", "selector"] }} }},
+                        "with": {{ "ty": "Int", "src": {{ "id": {}, "value": {value} }} }}
+                    }}
+                }},
+                "then": [{then}]
+            }}
+        }}"#, id + 1, id + 2)
+    }
+
+    #[test]
+    fn repeat_with_exit_and_continue() {
+        let body = format!(r#"[{{
+            "ty": "repeat",
+            "src": {{
+                "id": 10,
+                "_do": [{}, {}, {}, {}],
+                "until": {{
+                    "ty": "compare",
+                    "src": {{
+                        "id": 11,
+                        "operator": ">=",
+                        "compare": {{ "ty": "local_out", "src": {{ "path": ["Data", "selector"] }} }},
+                        "with": {{ "ty": "Int", "src": {{ "id": 12, "value": 100 }} }}
+                    }}
+                }}
+            }}
+        }}]"#,
+            increment(20, "selector"),
+            when_selector(30, "=", 3, r#"{ "ty": "continue", "src": { "id": 33 } }"#),
+            when_selector(40, ">", 5, r#"{ "ty": "exit", "src": { "id": 43 } }"#),
+            increment(50, "result"),
+        );
+        let mut container = load(&program(&body));
+        pollster::block_on(container.step("Main", 1)).unwrap();
+        // 3 is skipped and the loop is left at 6
+        assert_eq!(read(&container, "selector"), 6);
+        assert_eq!(read(&container, "result"), 4);
+        container.stop().unwrap();
+
+        let outside = format!("[{}]", when_selector(30, "=", 3, r#"{ "ty": "exit", "src": { "id": 33 } }"#));
+        assert!(boot_container(None).load_program(&program(&outside)).is_err());
+    }
+}
@@ -0,0 +1,52 @@
+#[cfg(test)]
+mod tests {
+    use crate::container::broadcast::report::{build_report, ReportFormat};
+    use crate::kernel::plc::operations::unit::test::{UnitTest, UnitTestStatus, UnitTestUpdateStatus};
+
+    fn tests() -> Vec<UnitTest> {
+        let mut passed = UnitTest::new(1, "Motor starts".into());
+        passed.update(&UnitTestUpdateStatus::new(1, UnitTestStatus::Succeed, None), 3, Some("Main".into()));
+        let mut failed = UnitTest::new(2, "Valve <closed>".into());
+        failed.update(&UnitTestUpdateStatus::new(2, UnitTestStatus::Failed, Some("Expected a to be = b".into())), 7, Some("Valve".into()));
+        let unreached = UnitTest::new(3, "Alarm".into());
+        vec![unreached, failed, passed]
+    }
+
+    #[test]
+    fn junit_report() {
+        let report = build_report(ReportFormat::Junit, "Main", &tests());
+        assert!(report.contains("<testsuite name=\"Main\" tests=\"3\" failures=\"2\">"));
+        assert!(report.contains("<testcase name=\"Valve &lt;closed&gt;\" classname=\"Valve\">"));
+        assert!(report.contains("<property name=\"cycle\" value=\"7\"/>"));
+        assert!(report.contains("<failure message=\"Expected a to be = b\"/>"));
+        assert!(report.contains("<failure message=\"Unit test was never reached\"/>"));
+    }
+
+    #[test]
+    fn tap_report() {
+        let report = build_report(ReportFormat::Tap, "Main", &tests());
+        let lines = report.lines().collect::<Vec<_>>();
+        assert_eq!(lines[0], "TAP version 13");
+        assert_eq!(lines[1], "1..3");
+        assert_eq!(lines[2], "ok 1 - Motor starts");
+        assert!(report.contains("not ok 2 - Valve <closed>\n  ---\n  id: 2\n  block: \"Valve\"\n  cycle: 7\n  message: \"Expected a to be = b\"\n  ...\n"));
+        assert!(report.contains("not ok 3 - Alarm"));
+    }
+
+    #[test]
+    fn tap_yaml_strings() {
+        let mut failed = UnitTest::new(1, "Colors".into());
+        failed.update(&UnitTestUpdateStatus::new(1, UnitTestStatus::Failed, Some("Expected \"red\"\n\u{1b}[31m \\ é".into())), 2, Some("Main".into()));
+        let report = build_report(ReportFormat::Tap, "Main", &[failed]);
+        assert!(report.contains("  message: \"Expected \\\"red\\\"\\n\\u001B[31m \\\\ é\"\n"));
+    }
+
+    #[test]
+    fn junit_control_characters() {
+        let mut failed = UnitTest::new(1, "Colors".into());
+        failed.update(&UnitTestUpdateStatus::new(1, UnitTestStatus::Failed, Some("\u{1b}[1;31mred\u{1b}[0m <\u{7}>\n".into())), 2, Some("Main".into()));
+        let report = build_report(ReportFormat::Junit, "Main", &[failed]);
+        assert!(report.contains("red &lt;\u{FFFD}&gt;\n"));
+        assert!(!report.chars().any(|c| c < ' ' && !matches!(c, '\t' | '\n' | '\r')));
+    }
+}
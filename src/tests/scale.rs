@@ -0,0 +1,60 @@
+#[cfg(test)]
+mod tests {
+    use crate::tests::common::fixtures::{assign, load, program, read, variable};
+    use crate::kernel::rust::scale::{scale, OutOfRange, Scaling};
+    use crate::container::container::Container;
+
+    const STATICS: &str = r#"{
+        "raw": { "ty": "Int", "src": { "id": 2, "value": 13824 } },
+        "normalized": { "ty": "Real", "src": { "id": 3 } },
+        "scaled": { "ty": "Int", "src": { "id": 4 } },
+        "percent": { "ty": "Real", "src": { "id": 5 } }
+    }"#;
+
+    /// Normalizes the raw analog value and scales it to -100..100 and 0.0..100.0,
+    /// the bounds are Implicit but for MIN of the scaling to -100..100
+    fn scaling_program(range: &str) -> String {
+        let implicit = |id: u32, value: &str| format!(r#"{{ "ty": "Implicit", "src": {{ "id": {}, "value": {} }} }}"#, id, value);
+        let body = format!("[{}, {}, {}]",
+            assign(11, "normalized", &format!(r#"{{ "ty": "norm_x", "src": {{ "id": 12, "norm_x": {}, "min": {}, "max": {} }} }}"#,
+                variable("raw"), implicit(13, "0"), implicit(14, "27648"))),
+            assign(15, "scaled", &format!(r#"{{ "ty": "scale_x", "src": {{ "id": 16, "range": "{}", "scale_x": {}, "min": {}, "max": {} }} }}"#,
+                range, variable("normalized"), r#"{ "ty": "Int", "src": { "id": 17, "value": -100 } }"#, implicit(18, "100"))),
+            assign(19, "percent", &format!(r#"{{ "ty": "scale_x", "src": {{ "id": 20, "scale_x": {}, "min": {}, "max": {} }} }}"#,
+                variable("normalized"), implicit(21, "0.0"), implicit(22, "100.0"))),
+        );
+        program(STATICS, &body)
+    }
+
+    fn step(container: &mut Container, raw: i64) -> Result<(), String> {
+        container.write(vec!["Data".into(), "raw".into()], &format!(r#"{{ "ty": "Int", "src": {{ "value": {} }} }}"#, raw)).unwrap();
+        pollster::block_on(container.step("Main", 1)).map_err(|e| format!("{}", e))?;
+        Ok(())
+    }
+
+    #[test]
+    fn scaling_functions() {
+        assert_eq!(scale(Scaling::Normalize, 0.0, 13824.0, 27648.0, OutOfRange::Error).unwrap(), 0.5);
+        assert_eq!(scale(Scaling::Normalize, 0.0, 30000.0, 27648.0, OutOfRange::Clamp).unwrap(), 1.0);
+        assert_eq!(scale(Scaling::Scale, 4.0, 0.25, 20.0, OutOfRange::Error).unwrap(), 8.0);
+        assert_eq!(scale(Scaling::Scale, 4.0, -0.25, 20.0, OutOfRange::Extrapolate).unwrap(), 0.0);
+        assert!(scale(Scaling::Scale, 4.0, 1.5, 20.0, OutOfRange::Error).is_err());
+        assert!(scale(Scaling::Normalize, 5.0, 5.0, 5.0, OutOfRange::Extrapolate).is_err());
+
+        let mut container = load("{}", &scaling_program("clamp")).unwrap();
+        step(&mut container, 13824).unwrap();
+        assert_eq!(read(&container, "normalized").as_f64(), Some(0.5));
+        assert_eq!(read(&container, "scaled").as_i64(), Some(0));
+        assert_eq!(read(&container, "percent").as_f64(), Some(50.0));
+        // Overrange of the analog input
+        step(&mut container, 32511).unwrap();
+        assert_eq!(read(&container, "scaled").as_i64(), Some(100));
+        container.stop().unwrap();
+
+        let mut container = load("{}", &scaling_program("error")).unwrap();
+        step(&mut container, 6912).unwrap();
+        assert_eq!(read(&container, "scaled").as_i64(), Some(-50));
+        assert!(step(&mut container, 32511).unwrap_err().contains("out of range"));
+        container.stop().unwrap();
+    }
+}
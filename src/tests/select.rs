@@ -0,0 +1,93 @@
+#[cfg(test)]
+mod tests {
+    use crate::container::container::boot_container;
+    use crate::tests::common::fixtures::{assign, load, program, read, variable};
+
+    const STATICS: &str = r#"{
+        "k": { "ty": "Int", "src": { "id": 2, "value": 2 } },
+        "int": { "ty": "Int", "src": { "id": 3 } },
+        "dint": { "ty": "DInt", "src": { "id": 4 } },
+        "real": { "ty": "Real", "src": { "id": 5 } },
+        "time": { "ty": "Time", "src": { "id": 6 } },
+        "limited": { "ty": "Int", "src": { "id": 7 } },
+        "picked": { "ty": "Int", "src": { "id": 9 } }
+    }"#;
+
+    fn constant(id: u32, ty: &str, value: &str) -> String {
+        format!(r#"{{ "ty": "{ty}", "src": {{ "id": {id}, "value": {value} }} }}"#)
+    }
+
+    #[test]
+    fn selection_functions() {
+        let body = format!("[{}, {}, {}, {}, {}, {}]",
+            assign(10, "int", &format!(r#"{{ "ty": "max", "src": {{ "id": 11, "max": [{}, {}, {}] }} }}"#,
+                constant(101, "Int", "-4"), constant(102, "Int", "7"), constant(103, "Int", "2"))),
+            assign(20, "real", &format!(r#"{{ "ty": "min", "src": {{ "id": 21, "min": [{}, {}] }} }}"#,
+                constant(104, "Real", "2.5"), constant(105, "Real", "-1.5"))),
+            assign(30, "limited", &format!(r#"{{ "ty": "limit", "src": {{ "id": 31, "limit": {}, "min": {}, "max": {} }} }}"#,
+                constant(106, "Int", "120"), constant(107, "Int", "0"), constant(108, "Int", "100"))),
+            // T#1s or LT#2s, the result keeps the type of IN0
+            assign(40, "time", &format!(r#"{{ "ty": "sel", "src": {{ "id": 41, "sel": {}, "in0": {}, "in1": {} }} }}"#,
+                constant(109, "Bool", "true"), constant(110, "Time", "1000"), constant(111, "LTime", "2000000000"))),
+            assign(50, "picked", &format!(r#"{{ "ty": "mux", "src": {{ "id": 51, "mux": {}, "inputs": [{}, {}, {}] }} }}"#,
+                variable("k"), constant(112, "Int", "10"), constant(113, "Int", "20"), constant(114, "Int", "30"))),
+            assign(60, "dint", &format!(r#"{{ "ty": "max", "src": {{ "id": 61, "max": [{}, {}] }} }}"#,
+                constant(115, "Int", "1"), constant(116, "DInt", "70000"))),
+        );
+
+        let provider = r#"{ "override_return": { "max": { "DInt": [["Int", "DInt"]] } } }"#;
+        let mut container = load(provider, &program(STATICS, &body)).unwrap();
+        pollster::block_on(container.step("Main", 1)).unwrap();
+        assert_eq!(read(&container, "real").as_f64(), Some(-1.5));
+        assert_eq!(read(&container, "limited").as_i64(), Some(100));
+        assert_eq!(read(&container, "time").as_i64(), Some(2000));
+        assert_eq!(read(&container, "int").as_i64(), Some(7));
+        assert_eq!(read(&container, "picked").as_i64(), Some(30));
+        assert_eq!(read(&container, "dint").as_i64(), Some(70000));
+
+        container.write(vec!["Data".into(), "k".into()], r#"{ "ty": "Int", "src": { "value": 3 } }"#).unwrap();
+        let error = pollster::block_on(container.step("Main", 1)).unwrap_err();
+        assert!(format!("{}", error).contains("Mux selector 3 is out of range 0..2"));
+        container.stop().unwrap();
+
+        // Without the override, 70000 does not fit in the Int of the first input
+        let mut container = load("{}", &program(STATICS, &body)).unwrap();
+        assert!(pollster::block_on(container.step("Main", 1)).is_err());
+        container.stop().unwrap();
+
+        let mixed = format!("[{}]", assign(10, "int", &format!(r#"{{ "ty": "max", "src": {{ "id": 11, "max": [{}, {}] }} }}"#,
+            constant(117, "Int", "1"), constant(118, "Real", "2.0"))));
+        assert!(boot_container(None).load_program(&program(STATICS, &mixed)).is_err());
+    }
+
+    #[test]
+    fn implicit_inputs_take_the_type_of_the_others() {
+        let body = format!("[{}, {}, {}, {}]",
+            // MIN(10.5, real)
+            assign(10, "real", &format!(r#"{{ "ty": "min", "src": {{ "id": 11, "min": [{}, {}] }} }}"#,
+                constant(101, "Implicit", "10.5"), variable("real"))),
+            // LIMIT(5, k, 100)
+            assign(20, "limited", &format!(r#"{{ "ty": "limit", "src": {{ "id": 21, "limit": {}, "min": {}, "max": {} }} }}"#,
+                variable("k"), constant(102, "Implicit", "5"), constant(103, "Implicit", "100"))),
+            // SEL(TRUE, 1, k)
+            assign(30, "int", &format!(r#"{{ "ty": "sel", "src": {{ "id": 31, "sel": {}, "in0": {}, "in1": {} }} }}"#,
+                constant(104, "Bool", "true"), constant(105, "Implicit", "1"), variable("k"))),
+            // MUX(k, limited, 20, 30)
+            assign(40, "picked", &format!(r#"{{ "ty": "mux", "src": {{ "id": 41, "mux": {}, "inputs": [{}, {}, {}] }} }}"#,
+                variable("k"), variable("limited"), constant(106, "Implicit", "20"), constant(107, "Implicit", "30"))),
+        );
+
+        let mut container = load("{}", &program(STATICS, &body)).unwrap();
+        pollster::block_on(container.step("Main", 1)).unwrap();
+        assert_eq!(read(&container, "real").as_f64(), Some(0.0));
+        assert_eq!(read(&container, "limited").as_i64(), Some(5));
+        assert_eq!(read(&container, "int").as_i64(), Some(2));
+        assert_eq!(read(&container, "picked").as_i64(), Some(30));
+        container.stop().unwrap();
+
+        // Without a typed input there is no context
+        let implicit = format!("[{}]", assign(10, "int", &format!(r#"{{ "ty": "max", "src": {{ "id": 11, "max": [{}, {}] }} }}"#,
+            constant(101, "Implicit", "1"), constant(102, "Implicit", "2"))));
+        assert!(load("{}", &program(STATICS, &implicit)).err().unwrap().contains("Implicit constant"));
+    }
+}
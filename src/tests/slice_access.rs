@@ -0,0 +1,38 @@
+#[cfg(test)]
+mod tests {
+    use crate::tests::common::fixtures::{assign_to, load, program, read, variable};
+
+    const STATICS: &str = r#"{
+        "telegram": { "ty": "DWord", "src": { "id": 2, "value": 287454020 } },
+        "raw": { "ty": "Int", "src": { "id": 3, "value": -1 } },
+        "byte": { "ty": "Byte", "src": { "id": 4 } },
+        "low": { "ty": "Word", "src": { "id": 5 } }
+    }"#;
+
+    fn access(id: u32, name: &str, size: &str, at: u64) -> String {
+        format!(r#"{{ "ty": "access", "src": {{ "id": {id}, "size": "{size}", "at": {at}, "of": {{ "ty": "local_out", "src": {{ "path": ["Data", "{name}"] }} }} }} }}"#)
+    }
+
+    #[test]
+    fn byte_word_and_dword_slices() {
+        let body = format!("[{}, {}, {}, {}]",
+            assign_to(10, &variable("byte"), &access(11, "telegram", "B", 1)),
+            assign_to(12, &access(13, "telegram", "W", 1), r#"{ "ty": "Word", "src": { "id": 14, "value": 43981 } }"#),
+            assign_to(15, &access(16, "raw", "B", 0), r#"{ "ty": "Byte", "src": { "id": 17, "value": 0 } }"#),
+            assign_to(18, &variable("low"), &format!(r#"{{ "ty": "and", "src": {{ "id": 19, "and": {}, "and_with": {{ "ty": "Word", "src": {{ "id": 20, "value": 255 }} }} }} }}"#,
+                access(21, "telegram", "W", 0))),
+        );
+
+        let mut container = load("{}", &program(STATICS, &body)).unwrap();
+        pollster::block_on(container.step("Main", 1)).unwrap();
+        assert_eq!(read(&container, "byte").as_u64(), Some(0x33));
+        // Writes go through the variable, the result of an operation does not
+        assert_eq!(read(&container, "telegram").as_u64(), Some(0xABCD_3344));
+        assert_eq!(read(&container, "raw").as_i64(), Some(-256));
+        assert_eq!(read(&container, "low").as_u64(), Some(0x44));
+        container.stop().unwrap();
+
+        let out_of_bounds = format!("[{}]", assign_to(10, &variable("byte"), &access(11, "telegram", "D", 1)));
+        assert!(load("{}", &program(STATICS, &out_of_bounds)).err().unwrap().contains("%D1 is out of the 32 bits"));
+    }
+}
@@ -0,0 +1,61 @@
+#[cfg(test)]
+mod tests {
+    use crate::container::container::boot_container;
+    use crate::tests::common::fixtures::{assign, load, program, read, variable};
+    use crate::kernel::rust::string::{delete, find, insert, left, mid, replace, right};
+
+    const STATICS: &str = r#"{
+        "name": { "ty": "String", "src": { "id": 2, "value": "Motor", "length": 8 } },
+        "message": { "ty": "String", "src": { "id": 3, "value": "", "length": 10 } },
+        "position": { "ty": "Int", "src": { "id": 4, "value": 0 } },
+        "part": { "ty": "String", "src": { "id": 5, "value": "" } }
+    }"#;
+
+    #[test]
+    fn string_functions() {
+        assert_eq!(left("Motor", 2).unwrap(), "Mo");
+        assert_eq!(left("Motor", 20).unwrap(), "Motor");
+        assert_eq!(right("Motor", 3).unwrap(), "tor");
+        assert_eq!(mid("Motor", 2, 2).unwrap(), "ot");
+        assert_eq!(find("Motor on", "o"), 2);
+        assert_eq!(find("Motor on", "x"), 0);
+        assert_eq!(insert("Motr", "o", 3).unwrap(), "Motor");
+        assert_eq!(insert("otor", "M", 0).unwrap(), "Motor");
+        assert_eq!(delete("Motoor", 1, 4).unwrap(), "Motor");
+        assert_eq!(replace("Motor", "Pump", 5, 1).unwrap(), "Pump");
+        assert_eq!(replace("Motor", "s", 0, 6).unwrap(), "Motors");
+        assert_eq!(mid("Wärme", 2, 2).unwrap(), "är");
+
+        assert!(left("Motor", -1).is_err());
+        assert!(mid("Motor", 1, 0).is_err());
+        assert!(mid("Motor", 1, 6).is_err());
+        assert!(delete("", 1, 1).is_err());
+        assert!(insert("Motor", "s", 6).is_err());
+    }
+
+    #[test]
+    fn string_operations_respect_length() {
+        let concat = format!(r#"{{ "ty": "concat", "src": {{ "id": 11, "concat": {}, "with": {{ "ty": "String", "src": {{ "id": 12, "value": " overheated" }} }} }} }}"#, variable("name"));
+        let find = format!(r#"{{ "ty": "find", "src": {{ "id": 21, "find": {}, "with": {{ "ty": "String", "src": {{ "id": 22, "value": "over" }} }} }} }}"#, variable("message"));
+        let mid = format!(r#"{{ "ty": "mid", "src": {{ "id": 31, "mid": {}, "length": {{ "ty": "Implicit", "src": {{ "id": 32, "value": 3 }} }}, "position": {} }} }}"#, variable("message"), variable("position"));
+        let body = format!("[{}, {}, {}]", assign(10, "message", &concat), assign(20, "position", &find), assign(30, "part", &mid));
+
+        let mut container = load("{}", &program(STATICS, &body)).unwrap();
+        pollster::block_on(container.step("Main", 1)).unwrap();
+        // String[10] keeps the first 10 characters
+        assert_eq!(read(&container, "message").as_str(), Some("Motor over"));
+        assert_eq!(read(&container, "position").as_i64(), Some(7));
+        assert_eq!(read(&container, "part").as_str(), Some("ove"));
+        container.stop().unwrap();
+
+        // Out of range position
+        let find = r#"{ "ty": "Int", "src": { "id": 22, "value": 11 } }"#;
+        let body = format!("[{}]", assign(30, "part", &mid.replace(&variable("position"), find)));
+        let mut container = load("{}", &program(STATICS, &body)).unwrap();
+        let error = pollster::block_on(container.step("Main", 1)).unwrap_err();
+        assert!(format!("{}", error).contains("Position 11 is out of range"));
+
+        let too_long = program(STATICS, "[]").replace(r#""value": "Motor", "length": 8"#, r#""value": "Motor", "length": 3"#);
+        assert!(boot_container(None).load_program(&too_long).is_err());
+    }
+}
@@ -0,0 +1,86 @@
+#[cfg(test)]
+mod tests {
+    use crate::container::container::Container;
+    use crate::tests::common::fixtures::{load_with_params, program, read, variable};
+
+    const STATICS: &str = r#"{
+        "in": { "ty": "Bool", "src": { "id": 2 } },
+        "r": { "ty": "Bool", "src": { "id": 3 } },
+        "q": { "ty": "Bool", "src": { "id": 4 } },
+        "et": { "ty": "Time", "src": { "id": 5 } }
+    }"#;
+
+    fn set(container: &mut Container, name: &str, value: bool) {
+        container.write(vec!["Data".into(), name.into()], &format!(r#"{{ "ty": "Bool", "src": {{ "value": {} }} }}"#, value)).unwrap();
+    }
+
+    /// Runs a timer for one cycle of 10 ms per row of (IN, R, expected Q, expected ET in ms).
+    fn check(timer: &str, table: &[(bool, bool, bool, i64)]) {
+        let body = format!(r#"[{{ "ty": "{}", "src": {{ "id": 7, "input": {}, "preset": {{ "ty": "Time", "src": {{ "id": 8, "value": 30 }} }}, "reset": {}, "q": {}, "et": {} }} }}]"#,
+            timer, variable("in"), variable("r"), variable("q"), variable("et"));
+        let mut container = load_with_params(r#"{ "stopOn": 0, "realTime": false, "cycleTime": 10 }"#, "{}", &program(STATICS, &body)).unwrap();
+
+        for (cycle, (input, reset, q, et)) in table.iter().enumerate() {
+            set(&mut container, "in", *input);
+            set(&mut container, "r", *reset);
+            pollster::block_on(container.step("Main", 1)).unwrap();
+            assert_eq!((read(&container, "q").as_bool(), read(&container, "et").as_i64()), (Some(*q), Some(*et)), "{} at cycle {}", timer, cycle);
+        }
+        container.stop().unwrap();
+    }
+
+    #[test]
+    fn iec_timers_per_cycle() {
+        check("ton", &[
+            (true, false, false, 0),
+            (true, false, false, 10),
+            (true, false, false, 20),
+            (true, false, true, 30),
+            (true, false, true, 30),
+            (false, false, false, 0),
+            (true, false, false, 0),
+            (false, false, false, 0),
+        ]);
+
+        // Re-triggering during the pulse is ignored, ET holds PT until IN falls
+        check("tp", &[
+            (true, false, true, 0),
+            (false, false, true, 10),
+            (true, false, true, 20),
+            (true, false, false, 30),
+            (true, false, false, 30),
+            (false, false, false, 0),
+            (true, false, true, 0),
+            (false, false, true, 10),
+        ]);
+
+        // A new rising edge during the delay resets ET
+        check("tof", &[
+            (false, false, false, 0),
+            (true, false, true, 0),
+            (false, false, true, 0),
+            (false, false, true, 10),
+            (true, false, true, 0),
+            (false, false, true, 0),
+            (false, false, true, 10),
+            (false, false, true, 20),
+            (false, false, false, 30),
+            (false, false, false, 30),
+        ]);
+
+        // ET holds while IN is FALSE, R has priority over IN
+        check("tonr", &[
+            (true, false, false, 0),
+            (true, false, false, 10),
+            (false, false, false, 10),
+            (false, false, false, 10),
+            (true, false, false, 10),
+            (true, false, false, 20),
+            (true, false, true, 30),
+            (false, false, true, 30),
+            (false, true, false, 0),
+            (true, true, false, 0),
+            (true, false, false, 10),
+        ]);
+    }
+}
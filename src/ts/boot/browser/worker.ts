@@ -1,4 +1,4 @@
-import init, {boot_container, is_running, Container} from "../../vifsimlib.js";
+import init, {boot_container, Container} from "../../vifsimlib.js";
 import {ToCommand, From, Send} from "./types.js";
 
 let container: Container
@@ -8,25 +8,25 @@ self.addEventListener("message", async (e: MessageEvent<ToCommand>) => {
             await checkServer(e.data.pause_sab, e.data.command_lock_sab)
             break;
         case Send.LoadContainerParams:
-            if (!is_running())
+            if (!container.is_running())
                 container.load_server_params(JSON.stringify(e.data.params))
             break;
         case Send.LoadPlugin:
-            if (!is_running())
+            if (!container.is_running())
                 self.postMessage({
                     command: From.LoadPlugin,
                     status: container.add_plugin(e.data.name, e.data.interval)
                 })
             break;
         case Send.LoadProvider:
-            if (!is_running())
+            if (!container.is_running())
                 self.postMessage({
                     command: From.LoadProviderStatus,
                     status: container.load_provider(JSON.stringify(e.data.pack))
                 })
             break;
         case Send.LoadProgram:
-            if (!is_running()) {
+            if (!container.is_running()) {
                 self.postMessage({
                     command: From.LoadProgramStatus,
                     sab: container.load_program(JSON.stringify(e.data.program))
@@ -38,15 +38,15 @@ self.addEventListener("message", async (e: MessageEvent<ToCommand>) => {
             }
             break;
         case Send.Start:
-            if (!is_running())
+            if (!container.is_running())
                 await container.start(e.data.main)
             break;
         case Send.ClearProgram:
-            if (!is_running())
+            if (!container.is_running())
                 container.clear_program()
             break;
         case Send.ClearProvider:
-            if (!is_running())
+            if (!container.is_running())
                 container.clear_provider()
             break;
     }
@@ -57,7 +57,7 @@ const checkServer = async (pause_sab: SharedArrayBuffer, command_lock_sab: Share
         container = boot_container(undefined, pause_sab, command_lock_sab)
         self.postMessage({command: From.ContainerReady, id: container.get_id()})
         setInterval(() => {
-            if (!is_running())
+            if (!container.is_running())
                 container.read_sab_commands();
         }, 100)
     }